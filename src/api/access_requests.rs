@@ -0,0 +1,113 @@
+use derive_builder::Builder;
+use gitlab::api::common::{AccessLevel, NameOrId};
+use gitlab::api::endpoint_prelude::*;
+use std::fmt;
+
+/// A project or a group.
+#[derive(Debug, Clone)]
+pub enum Source<'a> {
+	/// A project.
+	Project(NameOrId<'a>),
+	/// A group.
+	Group(NameOrId<'a>),
+}
+
+impl<'a> fmt::Display for Source<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Source::Project(project) => write!(f, "projects/{}", project),
+			Source::Group(group) => write!(f, "groups/{}", group),
+		}
+	}
+}
+
+/// Query the pending access requests of a project or a group.
+#[derive(Debug, Builder)]
+pub struct AccessRequests<'a> {
+	/// The project or group to query for access requests.
+	source: Source<'a>,
+}
+
+impl<'a> AccessRequests<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> AccessRequestsBuilder<'a> {
+		AccessRequestsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for AccessRequests<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("{}/access_requests", self.source).into()
+	}
+}
+
+impl<'a> Pageable for AccessRequests<'a> {}
+
+/// Approve an access request.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct ApproveAccessRequest<'a> {
+	/// The project or group of the access request.
+	source: Source<'a>,
+	/// The id of the user requesting access.
+	user: u64,
+	/// The access level to grant.
+	#[builder(default)]
+	access_level: Option<AccessLevel>,
+}
+
+impl<'a> ApproveAccessRequest<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> ApproveAccessRequestBuilder<'a> {
+		ApproveAccessRequestBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for ApproveAccessRequest<'a> {
+	fn method(&self) -> Method {
+		Method::PUT
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("{}/access_requests/{}/approve", self.source, self.user).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params.push_opt(
+			"access_level",
+			self.access_level.map(|level| level.as_u64()),
+		);
+		params.into_body()
+	}
+}
+
+/// Deny an access request.
+#[derive(Debug, Builder)]
+pub struct DenyAccessRequest<'a> {
+	/// The project or group of the access request.
+	source: Source<'a>,
+	/// The id of the user requesting access.
+	user: u64,
+}
+
+impl<'a> DenyAccessRequest<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DenyAccessRequestBuilder<'a> {
+		DenyAccessRequestBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DenyAccessRequest<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("{}/access_requests/{}", self.source, self.user).into()
+	}
+}
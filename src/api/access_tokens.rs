@@ -0,0 +1,117 @@
+use crate::api::access_requests::Source;
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use gitlab::api::common::AccessLevel;
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the access tokens of a project or a group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct AccessTokens<'a> {
+	/// The project or group to query for access tokens.
+	source: Source<'a>,
+	/// Only return the tokens in a state (active or inactive).
+	#[builder(setter(into), default)]
+	state: Option<Cow<'a, str>>,
+}
+
+impl<'a> AccessTokens<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> AccessTokensBuilder<'a> {
+		AccessTokensBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for AccessTokens<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("{}/access_tokens", self.source).into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params.push_opt("state", self.state.as_ref());
+		params
+	}
+}
+
+impl<'a> Pageable for AccessTokens<'a> {}
+
+/// Create an access token on a project or a group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CreateAccessToken<'a> {
+	/// The project or group to create the token on.
+	source: Source<'a>,
+	/// The name of the token.
+	#[builder(setter(into))]
+	name: Cow<'a, str>,
+	/// The scopes of the token.
+	scopes: Vec<Cow<'a, str>>,
+	/// The access level of the bot user of the token.
+	#[builder(default)]
+	access_level: Option<AccessLevel>,
+	/// The expiration date of the token.
+	#[builder(default)]
+	expires_at: Option<NaiveDate>,
+}
+
+impl<'a> CreateAccessToken<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> CreateAccessTokenBuilder<'a> {
+		CreateAccessTokenBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for CreateAccessToken<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("{}/access_tokens", self.source).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params
+			.push("name", &self.name)
+			.extend(self.scopes.iter().map(|scope| ("scopes[]", scope)))
+			.push_opt(
+				"access_level",
+				self.access_level.map(|level| level.as_u64()),
+			)
+			.push_opt("expires_at", self.expires_at);
+		params.into_body()
+	}
+}
+
+/// Revoke an access token of a project or a group.
+#[derive(Debug, Builder)]
+pub struct RevokeAccessToken<'a> {
+	/// The project or group of the token.
+	source: Source<'a>,
+	/// The id of the token.
+	token: u64,
+}
+
+impl<'a> RevokeAccessToken<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> RevokeAccessTokenBuilder<'a> {
+		RevokeAccessTokenBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for RevokeAccessToken<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("{}/access_tokens/{}", self.source, self.token).into()
+	}
+}
@@ -0,0 +1,41 @@
+use crate::api::access_requests::Source;
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the audit events of a project or a group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct AuditEvents<'a> {
+	/// The project or group to query for audit events.
+	source: Source<'a>,
+	/// Only return events created after this date.
+	#[builder(default)]
+	created_after: Option<NaiveDate>,
+}
+
+impl<'a> AuditEvents<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> AuditEventsBuilder<'a> {
+		AuditEventsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for AuditEvents<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("{}/audit_events", self.source).into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params.push_opt("created_after", self.created_after);
+		params
+	}
+}
+
+impl<'a> Pageable for AuditEvents<'a> {}
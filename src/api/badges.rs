@@ -0,0 +1,101 @@
+use derive_builder::Builder;
+use gitlab::api::common::NameOrId;
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the badges of a project.
+#[derive(Debug, Builder)]
+pub struct Badges<'a> {
+	/// The project to query for badges.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> Badges<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> BadgesBuilder<'a> {
+		BadgesBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for Badges<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/badges", self.project).into()
+	}
+}
+
+impl<'a> Pageable for Badges<'a> {}
+
+/// Add a badge to a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CreateBadge<'a> {
+	/// The project to add the badge to.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The url of the badge link.
+	#[builder(setter(into))]
+	link_url: Cow<'a, str>,
+	/// The url of the badge image.
+	#[builder(setter(into))]
+	image_url: Cow<'a, str>,
+	/// The name of the badge.
+	#[builder(setter(into), default)]
+	name: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateBadge<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> CreateBadgeBuilder<'a> {
+		CreateBadgeBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for CreateBadge<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/badges", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params
+			.push("link_url", &self.link_url)
+			.push("image_url", &self.image_url)
+			.push_opt("name", self.name.as_ref());
+		params.into_body()
+	}
+}
+
+/// Remove a badge from a project.
+#[derive(Debug, Builder)]
+pub struct DeleteBadge<'a> {
+	/// The project to remove the badge from.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The badge id.
+	badge: u64,
+}
+
+impl<'a> DeleteBadge<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeleteBadgeBuilder<'a> {
+		DeleteBadgeBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DeleteBadge<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/badges/{}", self.project, self.badge).into()
+	}
+}
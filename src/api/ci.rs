@@ -0,0 +1,48 @@
+use derive_builder::Builder;
+use gitlab::api::common::NameOrId;
+use gitlab::api::endpoint_prelude::*;
+
+/// Validate a CI configuration in the context of a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct LintCi<'a> {
+	/// The project to validate the configuration for.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The content of the CI configuration.
+	#[builder(setter(into))]
+	content: Cow<'a, str>,
+	/// Simulate a pipeline creation on a reference.
+	#[builder(setter(into), default)]
+	ref_: Option<Cow<'a, str>>,
+	/// Return the configuration with all includes and extends resolved.
+	#[builder(default)]
+	include_merged_yaml: Option<bool>,
+}
+
+impl<'a> LintCi<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> LintCiBuilder<'a> {
+		LintCiBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for LintCi<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/ci/lint", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params
+			.push("content", &self.content)
+			.push_opt("dry_run", self.ref_.as_ref().map(|_| true))
+			.push_opt("ref", self.ref_.as_ref())
+			.push_opt("include_merged_yaml", self.include_merged_yaml);
+		params.into_body()
+	}
+}
@@ -0,0 +1,89 @@
+use derive_builder::Builder;
+use gitlab::api::common::NameOrId;
+use gitlab::api::endpoint_prelude::*;
+use graphql_client::{GraphQLQuery, QueryBody};
+use serde::{Deserialize, Serialize};
+
+/// Purge the dependency proxy cache of a group.
+#[derive(Debug, Builder)]
+pub struct PurgeDependencyProxy<'a> {
+	/// The group to purge the cache of.
+	#[builder(setter(into))]
+	group: NameOrId<'a>,
+}
+
+impl<'a> PurgeDependencyProxy<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> PurgeDependencyProxyBuilder<'a> {
+		PurgeDependencyProxyBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for PurgeDependencyProxy<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("groups/{}/dependency_proxy/cache", self.group).into()
+	}
+}
+
+/// Query the dependency proxy cache statistics of a group (only exposed by the GraphQL api).
+pub struct DependencyProxyQuery;
+
+/// Variables of the dependency proxy query.
+#[derive(Debug, Serialize)]
+pub struct DependencyProxyVariables {
+	/// The full path of the group.
+	#[serde(rename = "fullPath")]
+	pub full_path: String,
+}
+
+/// Response of the dependency proxy query.
+#[derive(Debug, Deserialize)]
+pub struct DependencyProxyData {
+	/// The group, if found.
+	pub group: Option<DependencyProxyGroup>,
+}
+
+/// Dependency proxy statistics of a group.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyProxyGroup {
+	/// The settings of the dependency proxy.
+	pub dependency_proxy_setting: Option<DependencyProxySetting>,
+	/// The number of cached images.
+	pub dependency_proxy_image_count: u64,
+	/// The number of cached blobs.
+	pub dependency_proxy_blob_count: u64,
+	/// The human readable size of the cache.
+	pub dependency_proxy_total_size: String,
+}
+
+/// Settings of the dependency proxy of a group.
+#[derive(Debug, Deserialize)]
+pub struct DependencyProxySetting {
+	/// Whether the dependency proxy is enabled.
+	pub enabled: bool,
+}
+
+impl GraphQLQuery for DependencyProxyQuery {
+	type Variables = DependencyProxyVariables;
+	type ResponseData = DependencyProxyData;
+
+	fn build_query(variables: Self::Variables) -> QueryBody<Self::Variables> {
+		QueryBody {
+			variables,
+			query: "query DependencyProxy($fullPath: ID!) {
+	group(fullPath: $fullPath) {
+		dependencyProxySetting { enabled }
+		dependencyProxyImageCount
+		dependencyProxyBlobCount
+		dependencyProxyTotalSize
+	}
+}",
+			operation_name: "DependencyProxy",
+		}
+	}
+}
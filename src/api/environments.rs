@@ -0,0 +1,189 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use gitlab::api::common::{self, NameOrId};
+use gitlab::api::endpoint_prelude::*;
+use gitlab::api::projects::protected_branches::ProtectedAccess;
+
+/// Stop an environment of a project.
+#[derive(Debug, Builder)]
+pub struct StopEnvironment<'a> {
+	/// The project of the environment.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the environment.
+	environment: u64,
+}
+
+impl<'a> StopEnvironment<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> StopEnvironmentBuilder<'a> {
+		StopEnvironmentBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for StopEnvironment<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/environments/{}/stop",
+			self.project, self.environment
+		)
+		.into()
+	}
+}
+
+/// Delete a stopped environment of a project.
+#[derive(Debug, Builder)]
+pub struct DeleteEnvironment<'a> {
+	/// The project of the environment.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the environment.
+	environment: u64,
+}
+
+impl<'a> DeleteEnvironment<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeleteEnvironmentBuilder<'a> {
+		DeleteEnvironmentBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DeleteEnvironment<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/environments/{}",
+			self.project, self.environment
+		)
+		.into()
+	}
+}
+
+/// Delete the stopped review apps of a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct DeleteReviewApps<'a> {
+	/// The project of the review apps.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// Only delete review apps last updated before this date.
+	#[builder(default)]
+	before: Option<NaiveDate>,
+	/// Only list the review apps that would be deleted.
+	#[builder(default)]
+	dry_run: Option<bool>,
+}
+
+impl<'a> DeleteReviewApps<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeleteReviewAppsBuilder<'a> {
+		DeleteReviewAppsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DeleteReviewApps<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/environments/review_apps", self.project).into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params
+			.push_opt("before", self.before)
+			.push_opt("dry_run", self.dry_run);
+		params
+	}
+}
+
+/// Protect an environment of a project.
+#[derive(Debug, Builder)]
+pub struct ProtectEnvironment<'a> {
+	/// The project of the environment.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The name of the environment.
+	#[builder(setter(into))]
+	name: Cow<'a, str>,
+	/// The accesses allowed to deploy to the environment.
+	deploy_access_levels: Vec<ProtectedAccess>,
+}
+
+impl<'a> ProtectEnvironment<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> ProtectEnvironmentBuilder<'a> {
+		ProtectEnvironmentBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for ProtectEnvironment<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/protected_environments", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params.push("name", &self.name);
+		for access in self.deploy_access_levels.iter() {
+			match access {
+				ProtectedAccess::User(user) => {
+					params.push("deploy_access_levels[][user_id]", *user);
+				}
+				ProtectedAccess::Group(group) => {
+					params.push("deploy_access_levels[][group_id]", *group);
+				}
+				ProtectedAccess::Level(level) => {
+					params.push("deploy_access_levels[][access_level]", *level);
+				}
+			}
+		}
+		params.into_body()
+	}
+}
+
+/// Unprotect an environment of a project.
+#[derive(Debug, Builder)]
+pub struct UnprotectEnvironment<'a> {
+	/// The project of the environment.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The name of the environment.
+	#[builder(setter(into))]
+	name: Cow<'a, str>,
+}
+
+impl<'a> UnprotectEnvironment<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> UnprotectEnvironmentBuilder<'a> {
+		UnprotectEnvironmentBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for UnprotectEnvironment<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/protected_environments/{}",
+			self.project,
+			common::path_escaped(&self.name)
+		)
+		.into()
+	}
+}
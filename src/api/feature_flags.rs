@@ -0,0 +1,155 @@
+use derive_builder::Builder;
+use gitlab::api::common::NameOrId;
+use gitlab::api::endpoint_prelude::*;
+use serde_json::{json, Map, Value};
+
+/// Query the feature flags of a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FeatureFlags<'a> {
+	/// The project to query for feature flags.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// Only return enabled or disabled feature flags.
+	#[builder(setter(into), default)]
+	scope: Option<Cow<'a, str>>,
+}
+
+impl<'a> FeatureFlags<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> FeatureFlagsBuilder<'a> {
+		FeatureFlagsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for FeatureFlags<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/feature_flags", self.project).into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params.push_opt("scope", self.scope.as_ref());
+		params
+	}
+}
+
+impl<'a> Pageable for FeatureFlags<'a> {}
+
+/// Query a single feature flag of a project.
+#[derive(Debug, Builder)]
+pub struct FeatureFlag<'a> {
+	/// The project of the feature flag.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The name of the feature flag.
+	#[builder(setter(into))]
+	name: Cow<'a, str>,
+}
+
+impl<'a> FeatureFlag<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> FeatureFlagBuilder<'a> {
+		FeatureFlagBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for FeatureFlag<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/feature_flags/{}", self.project, self.name).into()
+	}
+}
+
+/// A strategy of a feature flag.
+#[derive(Debug, Clone)]
+pub struct FeatureFlagStrategy {
+	/// The id of an existing strategy.
+	pub id: Option<u64>,
+	/// The name of the strategy (default, gradualRolloutUserId, userWithId, flexibleRollout...).
+	pub name: String,
+	/// The parameters of the strategy.
+	pub parameters: Map<String, Value>,
+	/// The environment scopes the strategy applies to.
+	pub scopes: Vec<String>,
+	/// Delete the existing strategy (only the id is sent).
+	pub destroy: bool,
+}
+
+/// Edit a feature flag of a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct EditFeatureFlag<'a> {
+	/// The project of the feature flag.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The name of the feature flag.
+	#[builder(setter(into))]
+	name: Cow<'a, str>,
+	/// Enable or disable the feature flag.
+	#[builder(default)]
+	active: Option<bool>,
+	/// The strategies to create, update or delete.
+	#[builder(default)]
+	strategies: Option<Vec<FeatureFlagStrategy>>,
+}
+
+impl<'a> EditFeatureFlag<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> EditFeatureFlagBuilder<'a> {
+		EditFeatureFlagBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for EditFeatureFlag<'a> {
+	fn method(&self) -> Method {
+		Method::PUT
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/feature_flags/{}", self.project, self.name).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		// nested strategies can't be expressed reliably as form parameters
+		let mut body = Map::new();
+		if let Some(active) = self.active {
+			body.insert("active".into(), active.into());
+		}
+		if let Some(strategies) = &self.strategies {
+			let strategies: Vec<Value> = strategies
+				.iter()
+				.map(|strategy| {
+					if strategy.destroy {
+						return json!({ "id": strategy.id, "_destroy": true });
+					}
+					let mut value = json!({
+						"name": strategy.name,
+						"parameters": strategy.parameters,
+						"scopes": strategy
+							.scopes
+							.iter()
+							.map(|scope| json!({ "environment_scope": scope }))
+							.collect::<Vec<_>>(),
+					});
+					if let Some(id) = strategy.id {
+						value["id"] = id.into();
+					}
+					value
+				})
+				.collect();
+			body.insert("strategies".into(), strategies.into());
+		}
+		Ok(Some((
+			"application/json",
+			Value::Object(body).to_string().into_bytes(),
+		)))
+	}
+}
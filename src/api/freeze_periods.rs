@@ -0,0 +1,105 @@
+use derive_builder::Builder;
+use gitlab::api::common::NameOrId;
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the deploy freeze periods of a project.
+#[derive(Debug, Builder)]
+pub struct FreezePeriods<'a> {
+	/// The project to query for freeze periods.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> FreezePeriods<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> FreezePeriodsBuilder<'a> {
+		FreezePeriodsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for FreezePeriods<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/freeze_periods", self.project).into()
+	}
+}
+
+impl<'a> Pageable for FreezePeriods<'a> {}
+
+/// Create a deploy freeze period on a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CreateFreezePeriod<'a> {
+	/// The project to create the freeze period on.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The start of the freeze period in cron format.
+	#[builder(setter(into))]
+	freeze_start: Cow<'a, str>,
+	/// The end of the freeze period in cron format.
+	#[builder(setter(into))]
+	freeze_end: Cow<'a, str>,
+	/// The timezone of the cron expressions.
+	#[builder(setter(into), default)]
+	cron_timezone: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateFreezePeriod<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> CreateFreezePeriodBuilder<'a> {
+		CreateFreezePeriodBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for CreateFreezePeriod<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/freeze_periods", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params
+			.push("freeze_start", &self.freeze_start)
+			.push("freeze_end", &self.freeze_end)
+			.push_opt("cron_timezone", self.cron_timezone.as_ref());
+		params.into_body()
+	}
+}
+
+/// Delete a deploy freeze period of a project.
+#[derive(Debug, Builder)]
+pub struct DeleteFreezePeriod<'a> {
+	/// The project of the freeze period.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the freeze period.
+	freeze_period: u64,
+}
+
+impl<'a> DeleteFreezePeriod<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeleteFreezePeriodBuilder<'a> {
+		DeleteFreezePeriodBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DeleteFreezePeriod<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/freeze_periods/{}",
+			self.project, self.freeze_period
+		)
+		.into()
+	}
+}
@@ -0,0 +1,90 @@
+use derive_builder::Builder;
+use gitlab::api::common::NameOrId;
+use gitlab::api::endpoint_prelude::*;
+
+/// Delete a group.
+#[derive(Debug, Builder)]
+pub struct DeleteGroup<'a> {
+	/// The group to delete.
+	#[builder(setter(into))]
+	group: NameOrId<'a>,
+}
+
+impl<'a> DeleteGroup<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeleteGroupBuilder<'a> {
+		DeleteGroupBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DeleteGroup<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("groups/{}", self.group).into()
+	}
+}
+
+/// Query all the members of a group, including inherited ones.
+#[derive(Debug, Builder)]
+pub struct AllGroupMembers<'a> {
+	/// The group to query for membership.
+	#[builder(setter(into))]
+	group: NameOrId<'a>,
+}
+
+impl<'a> AllGroupMembers<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> AllGroupMembersBuilder<'a> {
+		AllGroupMembersBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for AllGroupMembers<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("groups/{}/members/all", self.group).into()
+	}
+}
+
+impl<'a> Pageable for AllGroupMembers<'a> {}
+
+/// Query the projects of a group and of its subgroups with their statistics.
+#[derive(Debug, Builder)]
+pub struct GroupProjectsStatistics<'a> {
+	/// The group to query for projects.
+	#[builder(setter(into))]
+	group: NameOrId<'a>,
+}
+
+impl<'a> GroupProjectsStatistics<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> GroupProjectsStatisticsBuilder<'a> {
+		GroupProjectsStatisticsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for GroupProjectsStatistics<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("groups/{}/projects", self.group).into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params
+			.push("include_subgroups", true)
+			.push("statistics", true);
+		params
+	}
+}
+
+impl<'a> Pageable for GroupProjectsStatistics<'a> {}
@@ -0,0 +1,94 @@
+use derive_builder::Builder;
+use gitlab::api::common::NameOrId;
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the projects allowed to access a project with their CI job token.
+#[derive(Debug, Builder)]
+pub struct JobTokenAllowlist<'a> {
+	/// The project to query the allowlist of.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> JobTokenAllowlist<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> JobTokenAllowlistBuilder<'a> {
+		JobTokenAllowlistBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for JobTokenAllowlist<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/job_token_scope/allowlist", self.project).into()
+	}
+}
+
+impl<'a> Pageable for JobTokenAllowlist<'a> {}
+
+/// Allow a project to access a project with its CI job token.
+#[derive(Debug, Builder)]
+pub struct AddJobTokenAllowlist<'a> {
+	/// The project to change the allowlist of.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the project to allow.
+	target_project: u64,
+}
+
+impl<'a> AddJobTokenAllowlist<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> AddJobTokenAllowlistBuilder<'a> {
+		AddJobTokenAllowlistBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for AddJobTokenAllowlist<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/job_token_scope/allowlist", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params.push("target_project_id", self.target_project);
+		params.into_body()
+	}
+}
+
+/// Remove a project from the CI job token allowlist of a project.
+#[derive(Debug, Builder)]
+pub struct RemoveJobTokenAllowlist<'a> {
+	/// The project to change the allowlist of.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the project to remove.
+	target_project: u64,
+}
+
+impl<'a> RemoveJobTokenAllowlist<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> RemoveJobTokenAllowlistBuilder<'a> {
+		RemoveJobTokenAllowlistBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for RemoveJobTokenAllowlist<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/job_token_scope/allowlist/{}",
+			self.project, self.target_project
+		)
+		.into()
+	}
+}
@@ -0,0 +1,124 @@
+use derive_builder::Builder;
+use gitlab::api::common::NameOrId;
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the push mirrors of a project.
+#[derive(Debug, Builder)]
+pub struct RemoteMirrors<'a> {
+	/// The project to query for mirrors.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> RemoteMirrors<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> RemoteMirrorsBuilder<'a> {
+		RemoteMirrorsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for RemoteMirrors<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/remote_mirrors", self.project).into()
+	}
+}
+
+impl<'a> Pageable for RemoteMirrors<'a> {}
+
+/// Create a push mirror on a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CreateRemoteMirror<'a> {
+	/// The project to create the mirror on.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The url of the mirror (with credentials if needed).
+	#[builder(setter(into))]
+	url: Cow<'a, str>,
+	/// Whether the mirror is enabled.
+	#[builder(default)]
+	enabled: Option<bool>,
+	/// Whether divergent refs are kept on the mirror.
+	#[builder(default)]
+	keep_divergent_refs: Option<bool>,
+	/// Whether only protected branches are mirrored.
+	#[builder(default)]
+	only_protected_branches: Option<bool>,
+}
+
+impl<'a> CreateRemoteMirror<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> CreateRemoteMirrorBuilder<'a> {
+		CreateRemoteMirrorBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for CreateRemoteMirror<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/remote_mirrors", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params
+			.push("url", &self.url)
+			.push_opt("enabled", self.enabled)
+			.push_opt("keep_divergent_refs", self.keep_divergent_refs)
+			.push_opt("only_protected_branches", self.only_protected_branches);
+		params.into_body()
+	}
+}
+
+/// Edit a push mirror of a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct EditRemoteMirror<'a> {
+	/// The project of the mirror.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the mirror.
+	mirror: u64,
+	/// Whether the mirror is enabled.
+	#[builder(default)]
+	enabled: Option<bool>,
+	/// Whether divergent refs are kept on the mirror.
+	#[builder(default)]
+	keep_divergent_refs: Option<bool>,
+	/// Whether only protected branches are mirrored.
+	#[builder(default)]
+	only_protected_branches: Option<bool>,
+}
+
+impl<'a> EditRemoteMirror<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> EditRemoteMirrorBuilder<'a> {
+		EditRemoteMirrorBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for EditRemoteMirror<'a> {
+	fn method(&self) -> Method {
+		Method::PUT
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/remote_mirrors/{}", self.project, self.mirror).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params
+			.push_opt("enabled", self.enabled)
+			.push_opt("keep_divergent_refs", self.keep_divergent_refs)
+			.push_opt("only_protected_branches", self.only_protected_branches);
+		params.into_body()
+	}
+}
@@ -0,0 +1,24 @@
+pub mod access_requests;
+pub mod access_tokens;
+pub mod audit_events;
+pub mod badges;
+pub mod ci;
+pub mod dependency_proxy;
+pub mod environments;
+pub mod feature_flags;
+pub mod freeze_periods;
+pub mod groups;
+pub mod job_token_scope;
+pub mod mirrors;
+pub mod multipart;
+pub mod packages;
+pub mod pages;
+pub mod personal_access_tokens;
+pub mod projects;
+pub mod registry;
+pub mod repository;
+pub mod runners;
+pub mod search;
+pub mod secure_files;
+pub mod terraform;
+pub mod todos;
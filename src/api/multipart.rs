@@ -0,0 +1,55 @@
+use gitlab::api::BodyError;
+
+/// Boundary used to separate the parts of a multipart body
+const BOUNDARY: &str = "glctl-5b1f0c8e2d7a4963";
+/// Content type of a multipart body (must use the same boundary)
+const CONTENT_TYPE: &str = "multipart/form-data; boundary=glctl-5b1f0c8e2d7a4963";
+
+/// Minimal multipart/form-data body builder for endpoints that upload files
+#[derive(Default)]
+pub struct Multipart {
+	body: Vec<u8>,
+}
+
+impl Multipart {
+	/// Add a text field
+	pub fn text(&mut self, name: &str, value: &str) -> &mut Self {
+		self.body.extend_from_slice(
+			format!(
+				"--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+				BOUNDARY, name, value
+			)
+			.as_bytes(),
+		);
+		self
+	}
+
+	/// Add an optional text field
+	pub fn text_opt(&mut self, name: &str, value: Option<&str>) -> &mut Self {
+		if let Some(value) = value {
+			self.text(name, value);
+		}
+		self
+	}
+
+	/// Add a file field
+	pub fn file(&mut self, name: &str, filename: &str, content: &[u8]) -> &mut Self {
+		self.body.extend_from_slice(
+			format!(
+				"--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+				BOUNDARY, name, filename
+			)
+			.as_bytes(),
+		);
+		self.body.extend_from_slice(content);
+		self.body.extend_from_slice(b"\r\n");
+		self
+	}
+
+	/// Close the body and return it with its content type
+	pub fn into_body(mut self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		self.body
+			.extend_from_slice(format!("--{}--\r\n", BOUNDARY).as_bytes());
+		Ok(Some((CONTENT_TYPE, self.body)))
+	}
+}
@@ -0,0 +1,216 @@
+use derive_builder::Builder;
+use gitlab::api::common::{self, NameOrId};
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the packages of a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Packages<'a> {
+	/// The project to query for packages.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// Only return packages of this type.
+	#[builder(setter(into), default)]
+	package_type: Option<Cow<'a, str>>,
+	/// Only return packages whose name contains this string.
+	#[builder(setter(into), default)]
+	package_name: Option<Cow<'a, str>>,
+}
+
+impl<'a> Packages<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> PackagesBuilder<'a> {
+		PackagesBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for Packages<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/packages", self.project).into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params
+			.push_opt("package_type", self.package_type.as_ref())
+			.push_opt("package_name", self.package_name.as_ref())
+			.push("order_by", "created_at")
+			.push("sort", "desc");
+		params
+	}
+}
+
+impl<'a> Pageable for Packages<'a> {}
+
+/// Query a single package of a project.
+#[derive(Debug, Builder)]
+pub struct Package<'a> {
+	/// The project of the package.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the package.
+	package: u64,
+}
+
+impl<'a> Package<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> PackageBuilder<'a> {
+		PackageBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for Package<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/packages/{}", self.project, self.package).into()
+	}
+}
+
+/// Query the files of a package.
+#[derive(Debug, Builder)]
+pub struct PackageFiles<'a> {
+	/// The project of the package.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the package.
+	package: u64,
+}
+
+impl<'a> PackageFiles<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> PackageFilesBuilder<'a> {
+		PackageFilesBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for PackageFiles<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/packages/{}/package_files",
+			self.project, self.package
+		)
+		.into()
+	}
+}
+
+impl<'a> Pageable for PackageFiles<'a> {}
+
+/// Download a package file through the api of its package type.
+#[derive(Debug, Builder)]
+pub struct DownloadPackageFile<'a> {
+	/// The project of the package.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The path of the file relative to the packages api (ex: generic/name/version/file).
+	#[builder(setter(into))]
+	path: Cow<'a, str>,
+}
+
+impl<'a> DownloadPackageFile<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DownloadPackageFileBuilder<'a> {
+		DownloadPackageFileBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DownloadPackageFile<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/packages/{}", self.project, self.path).into()
+	}
+}
+
+/// Upload a file to the generic package registry of a project.
+#[derive(Debug, Builder)]
+pub struct UploadGenericPackageFile<'a> {
+	/// The project to upload the file to.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The name of the package.
+	#[builder(setter(into))]
+	name: Cow<'a, str>,
+	/// The version of the package.
+	#[builder(setter(into))]
+	version: Cow<'a, str>,
+	/// The name of the file.
+	#[builder(setter(into))]
+	file_name: Cow<'a, str>,
+	/// The content of the file.
+	#[builder(setter(into))]
+	content: Cow<'a, [u8]>,
+}
+
+impl<'a> UploadGenericPackageFile<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> UploadGenericPackageFileBuilder<'a> {
+		UploadGenericPackageFileBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for UploadGenericPackageFile<'a> {
+	fn method(&self) -> Method {
+		Method::PUT
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/packages/generic/{}/{}/{}",
+			self.project,
+			common::path_escaped(&self.name),
+			common::path_escaped(&self.version),
+			common::path_escaped(&self.file_name)
+		)
+		.into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params.push("select", "package_file");
+		params
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		Ok(Some(("application/octet-stream", self.content.to_vec())))
+	}
+}
+
+/// Delete a package of a project.
+#[derive(Debug, Builder)]
+pub struct DeletePackage<'a> {
+	/// The project of the package.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the package.
+	package: u64,
+}
+
+impl<'a> DeletePackage<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeletePackageBuilder<'a> {
+		DeletePackageBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DeletePackage<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/packages/{}", self.project, self.package).into()
+	}
+}
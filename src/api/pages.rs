@@ -0,0 +1,142 @@
+use derive_builder::Builder;
+use gitlab::api::common::NameOrId;
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the pages domains of a project, or of all projects.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct PagesDomains<'a> {
+	/// The project to query for domains (all projects by default, administrators only).
+	#[builder(setter(into), default)]
+	project: Option<NameOrId<'a>>,
+}
+
+impl<'a> PagesDomains<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> PagesDomainsBuilder<'a> {
+		PagesDomainsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for PagesDomains<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		match &self.project {
+			Some(project) => format!("projects/{}/pages/domains", project).into(),
+			None => "pages/domains".into(),
+		}
+	}
+}
+
+impl<'a> Pageable for PagesDomains<'a> {}
+
+/// Add a pages domain to a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CreatePagesDomain<'a> {
+	/// The project to add the domain to.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The domain name.
+	#[builder(setter(into))]
+	domain: Cow<'a, str>,
+	/// Get a certificate from Let's Encrypt.
+	#[builder(default)]
+	auto_ssl_enabled: Option<bool>,
+	/// The PEM certificate of the domain.
+	#[builder(setter(into), default)]
+	certificate: Option<Cow<'a, str>>,
+	/// The PEM private key of the certificate.
+	#[builder(setter(into), default)]
+	key: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreatePagesDomain<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> CreatePagesDomainBuilder<'a> {
+		CreatePagesDomainBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for CreatePagesDomain<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/pages/domains", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params
+			.push("domain", &self.domain)
+			.push_opt("auto_ssl_enabled", self.auto_ssl_enabled)
+			.push_opt("certificate", self.certificate.as_ref())
+			.push_opt("key", self.key.as_ref());
+		params.into_body()
+	}
+}
+
+/// Remove a pages domain from a project.
+#[derive(Debug, Builder)]
+pub struct DeletePagesDomain<'a> {
+	/// The project of the domain.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The domain name.
+	#[builder(setter(into))]
+	domain: Cow<'a, str>,
+}
+
+impl<'a> DeletePagesDomain<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeletePagesDomainBuilder<'a> {
+		DeletePagesDomainBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DeletePagesDomain<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/pages/domains/{}", self.project, self.domain).into()
+	}
+}
+
+/// Check the verification code of a pages domain.
+#[derive(Debug, Builder)]
+pub struct VerifyPagesDomain<'a> {
+	/// The project of the domain.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The domain name.
+	#[builder(setter(into))]
+	domain: Cow<'a, str>,
+}
+
+impl<'a> VerifyPagesDomain<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> VerifyPagesDomainBuilder<'a> {
+		VerifyPagesDomainBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for VerifyPagesDomain<'a> {
+	fn method(&self) -> Method {
+		Method::PUT
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/pages/domains/{}/verify",
+			self.project, self.domain
+		)
+		.into()
+	}
+}
@@ -0,0 +1,180 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the personal access tokens visible to the current user.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct PersonalAccessTokens<'a> {
+	/// Only return the tokens of a user (admin only for other users).
+	#[builder(default)]
+	user_id: Option<u64>,
+	/// Only return the tokens in a state (active or inactive).
+	#[builder(setter(into), default)]
+	state: Option<Cow<'a, str>>,
+}
+
+impl<'a> PersonalAccessTokens<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> PersonalAccessTokensBuilder<'a> {
+		PersonalAccessTokensBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for PersonalAccessTokens<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		"personal_access_tokens".into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params
+			.push_opt("user_id", self.user_id)
+			.push_opt("state", self.state.as_ref());
+		params
+	}
+}
+
+impl<'a> Pageable for PersonalAccessTokens<'a> {}
+
+/// Create a personal access token for a user (admin only).
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CreatePersonalAccessToken<'a> {
+	/// The user to create the token for.
+	user: u64,
+	/// The name of the token.
+	#[builder(setter(into))]
+	name: Cow<'a, str>,
+	/// The scopes of the token.
+	scopes: Vec<Cow<'a, str>>,
+	/// The expiration date of the token.
+	#[builder(default)]
+	expires_at: Option<NaiveDate>,
+}
+
+impl<'a> CreatePersonalAccessToken<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> CreatePersonalAccessTokenBuilder<'a> {
+		CreatePersonalAccessTokenBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for CreatePersonalAccessToken<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("users/{}/personal_access_tokens", self.user).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params
+			.push("name", &self.name)
+			.extend(self.scopes.iter().map(|scope| ("scopes[]", scope)))
+			.push_opt("expires_at", self.expires_at);
+		params.into_body()
+	}
+}
+
+/// Rotate a personal access token, revoking it and returning a new one.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct RotatePersonalAccessToken {
+	/// The id of the token (the token used for the request by default).
+	#[builder(default)]
+	token: Option<u64>,
+	/// The expiration date of the new token.
+	#[builder(default)]
+	expires_at: Option<NaiveDate>,
+}
+
+impl RotatePersonalAccessToken {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> RotatePersonalAccessTokenBuilder {
+		RotatePersonalAccessTokenBuilder::default()
+	}
+}
+
+impl Endpoint for RotatePersonalAccessToken {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		match self.token {
+			Some(token) => format!("personal_access_tokens/{}/rotate", token).into(),
+			None => "personal_access_tokens/self/rotate".into(),
+		}
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params.push_opt("expires_at", self.expires_at);
+		params.into_body()
+	}
+}
+
+/// Get a single personal access token.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct PersonalAccessToken {
+	/// The id of the token (the token used for the request by default).
+	#[builder(default)]
+	token: Option<u64>,
+}
+
+impl PersonalAccessToken {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> PersonalAccessTokenBuilder {
+		PersonalAccessTokenBuilder::default()
+	}
+}
+
+impl Endpoint for PersonalAccessToken {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		match self.token {
+			Some(token) => format!("personal_access_tokens/{}", token).into(),
+			None => "personal_access_tokens/self".into(),
+		}
+	}
+}
+
+/// Revoke a personal access token.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct RevokePersonalAccessToken {
+	/// The id of the token (the token used for the request by default).
+	#[builder(default)]
+	token: Option<u64>,
+}
+
+impl RevokePersonalAccessToken {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> RevokePersonalAccessTokenBuilder {
+		RevokePersonalAccessTokenBuilder::default()
+	}
+}
+
+impl Endpoint for RevokePersonalAccessToken {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		match self.token {
+			Some(token) => format!("personal_access_tokens/{}", token).into(),
+			None => "personal_access_tokens/self".into(),
+		}
+	}
+}
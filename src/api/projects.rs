@@ -0,0 +1,840 @@
+use crate::api::multipart::Multipart;
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use gitlab::api::common::{self, AccessLevel, NameOrId};
+use gitlab::api::endpoint_prelude::*;
+
+/// Fork a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct ForkProject<'a> {
+	/// The project to fork.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The namespace (path) the fork is created in.
+	#[builder(setter(into), default)]
+	namespace_path: Option<Cow<'a, str>>,
+	/// The name of the fork.
+	#[builder(setter(into), default)]
+	name: Option<Cow<'a, str>>,
+	/// The path of the fork.
+	#[builder(setter(into), default)]
+	path: Option<Cow<'a, str>>,
+}
+
+impl<'a> ForkProject<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> ForkProjectBuilder<'a> {
+		ForkProjectBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for ForkProject<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/fork", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params
+			.push_opt("namespace_path", self.namespace_path.as_ref())
+			.push_opt("name", self.name.as_ref())
+			.push_opt("path", self.path.as_ref());
+		params.into_body()
+	}
+}
+
+/// Delete a project.
+#[derive(Debug, Builder)]
+pub struct DeleteProject<'a> {
+	/// The project to delete.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> DeleteProject<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeleteProjectBuilder<'a> {
+		DeleteProjectBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DeleteProject<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}", self.project).into()
+	}
+}
+
+/// Edit arbitrary settings of a project.
+#[derive(Debug, Builder)]
+pub struct EditProjectSettings<'a> {
+	/// The project to edit.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The settings to change.
+	settings: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> EditProjectSettings<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> EditProjectSettingsBuilder<'a> {
+		EditProjectSettingsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for EditProjectSettings<'a> {
+	fn method(&self) -> Method {
+		Method::PUT
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params.extend(self.settings.iter().map(|(k, v)| (k.as_ref(), v)));
+		params.into_body()
+	}
+}
+
+/// Schedule an export of a project.
+#[derive(Debug, Builder)]
+pub struct ScheduleExport<'a> {
+	/// The project to export.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> ScheduleExport<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> ScheduleExportBuilder<'a> {
+		ScheduleExportBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for ScheduleExport<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/export", self.project).into()
+	}
+}
+
+/// Query the export status of a project.
+#[derive(Debug, Builder)]
+pub struct ExportStatus<'a> {
+	/// The project being exported.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> ExportStatus<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> ExportStatusBuilder<'a> {
+		ExportStatusBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for ExportStatus<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/export", self.project).into()
+	}
+}
+
+/// Download the export archive of a project.
+#[derive(Debug, Builder)]
+pub struct ExportDownload<'a> {
+	/// The project exported.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> ExportDownload<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> ExportDownloadBuilder<'a> {
+		ExportDownloadBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for ExportDownload<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/export/download", self.project).into()
+	}
+}
+
+/// Import a project from an export archive.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct ImportProject<'a> {
+	/// The path of the new project.
+	#[builder(setter(into))]
+	path: Cow<'a, str>,
+	/// The namespace (id or path) of the new project.
+	#[builder(setter(into), default)]
+	namespace: Option<Cow<'a, str>>,
+	/// The name of the new project.
+	#[builder(setter(into), default)]
+	name: Option<Cow<'a, str>>,
+	/// The name of the archive file.
+	#[builder(setter(into))]
+	filename: Cow<'a, str>,
+	/// The content of the archive file.
+	#[builder(setter(into))]
+	file: Cow<'a, [u8]>,
+}
+
+impl<'a> ImportProject<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> ImportProjectBuilder<'a> {
+		ImportProjectBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for ImportProject<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		"projects/import".into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut body = Multipart::default();
+		body.text("path", &self.path)
+			.text_opt("namespace", self.namespace.as_deref())
+			.text_opt("name", self.name.as_deref())
+			.file("file", &self.filename, &self.file);
+		body.into_body()
+	}
+}
+
+/// Start the housekeeping task of a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Housekeeping<'a> {
+	/// The project to run housekeeping on.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The housekeeping task (eager or prune).
+	#[builder(setter(into), default)]
+	task: Option<Cow<'a, str>>,
+}
+
+impl<'a> Housekeeping<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> HousekeepingBuilder<'a> {
+		HousekeepingBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for Housekeeping<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/housekeeping", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params.push_opt("task", self.task.as_ref());
+		params.into_body()
+	}
+}
+
+/// Upload a new avatar for a project.
+#[derive(Debug, Builder)]
+pub struct UploadAvatar<'a> {
+	/// The project to change the avatar of.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The name of the image file.
+	#[builder(setter(into))]
+	filename: Cow<'a, str>,
+	/// The content of the image file.
+	#[builder(setter(into))]
+	file: Cow<'a, [u8]>,
+}
+
+impl<'a> UploadAvatar<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> UploadAvatarBuilder<'a> {
+		UploadAvatarBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for UploadAvatar<'a> {
+	fn method(&self) -> Method {
+		Method::PUT
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut body = Multipart::default();
+		body.file("avatar", &self.filename, &self.file);
+		body.into_body()
+	}
+}
+
+/// Download the avatar of a project.
+#[derive(Debug, Builder)]
+pub struct Avatar<'a> {
+	/// The project to get the avatar of.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> Avatar<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> AvatarBuilder<'a> {
+		AvatarBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for Avatar<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/avatar", self.project).into()
+	}
+}
+
+/// Query the languages used in a project repository.
+#[derive(Debug, Builder)]
+pub struct Languages<'a> {
+	/// The project to query for languages.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> Languages<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> LanguagesBuilder<'a> {
+		LanguagesBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for Languages<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/languages", self.project).into()
+	}
+}
+
+/// Query the contributors of a project repository.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Contributors<'a> {
+	/// The project to query for contributors.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// Order contributors by name, email or commits.
+	#[builder(setter(into), default)]
+	order_by: Option<Cow<'a, str>>,
+	/// Sort order (asc or desc).
+	#[builder(setter(into), default)]
+	sort: Option<Cow<'a, str>>,
+}
+
+impl<'a> Contributors<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> ContributorsBuilder<'a> {
+		ContributorsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for Contributors<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/repository/contributors", self.project).into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params
+			.push_opt("order_by", self.order_by.as_ref())
+			.push_opt("sort", self.sort.as_ref());
+		params
+	}
+}
+
+impl<'a> Pageable for Contributors<'a> {}
+
+/// Mark a project as forked from another one.
+#[derive(Debug, Builder)]
+pub struct CreateForkRelation<'a> {
+	/// The forked project.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the project it was forked from.
+	forked_from: u64,
+}
+
+impl<'a> CreateForkRelation<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> CreateForkRelationBuilder<'a> {
+		CreateForkRelationBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for CreateForkRelation<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/fork/{}", self.project, self.forked_from).into()
+	}
+}
+
+/// Remove the fork relationship of a project.
+#[derive(Debug, Builder)]
+pub struct DeleteForkRelation<'a> {
+	/// The forked project.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> DeleteForkRelation<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeleteForkRelationBuilder<'a> {
+		DeleteForkRelationBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DeleteForkRelation<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/fork", self.project).into()
+	}
+}
+
+/// Star a project.
+#[derive(Debug, Builder)]
+pub struct StarProject<'a> {
+	/// The project to star.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> StarProject<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> StarProjectBuilder<'a> {
+		StarProjectBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for StarProject<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/star", self.project).into()
+	}
+}
+
+/// Unstar a project.
+#[derive(Debug, Builder)]
+pub struct UnstarProject<'a> {
+	/// The project to unstar.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> UnstarProject<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> UnstarProjectBuilder<'a> {
+		UnstarProjectBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for UnstarProject<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/unstar", self.project).into()
+	}
+}
+
+/// Invite a user to a project by email.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct InviteProjectMember<'a> {
+	/// The project to invite the user to.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The email address of the user.
+	#[builder(setter(into))]
+	email: Cow<'a, str>,
+	/// The access level for the user in the project.
+	access_level: AccessLevel,
+	/// When the user's access expires.
+	#[builder(default)]
+	expires_at: Option<NaiveDate>,
+}
+
+impl<'a> InviteProjectMember<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> InviteProjectMemberBuilder<'a> {
+		InviteProjectMemberBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for InviteProjectMember<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/invitations", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params
+			.push("email", &self.email)
+			.push("access_level", self.access_level.as_u64())
+			.push_opt("expires_at", self.expires_at);
+		params.into_body()
+	}
+}
+
+/// Query the merge request approvals configuration of a project.
+#[derive(Debug, Builder)]
+pub struct ProjectApprovals<'a> {
+	/// The project to query for approvals configuration.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> ProjectApprovals<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> ProjectApprovalsBuilder<'a> {
+		ProjectApprovalsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for ProjectApprovals<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/approvals", self.project).into()
+	}
+}
+
+/// Edit arbitrary merge request approvals settings of a project.
+#[derive(Debug, Builder)]
+pub struct EditProjectApprovals<'a> {
+	/// The project to edit.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The settings to change.
+	settings: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> EditProjectApprovals<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> EditProjectApprovalsBuilder<'a> {
+		EditProjectApprovalsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for EditProjectApprovals<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/approvals", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params.extend(self.settings.iter().map(|(k, v)| (k.as_ref(), v)));
+		params.into_body()
+	}
+}
+
+/// Query the protected tags of a project.
+#[derive(Debug, Builder)]
+pub struct ProjectProtectedTags<'a> {
+	/// The project to query for protected tags.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> ProjectProtectedTags<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> ProjectProtectedTagsBuilder<'a> {
+		ProjectProtectedTagsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for ProjectProtectedTags<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/protected_tags", self.project).into()
+	}
+}
+
+impl<'a> Pageable for ProjectProtectedTags<'a> {}
+
+/// Query the CI/CD variables of a project.
+#[derive(Debug, Builder)]
+pub struct ProjectVariables<'a> {
+	/// The project to query for variables.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> ProjectVariables<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> ProjectVariablesBuilder<'a> {
+		ProjectVariablesBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for ProjectVariables<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/variables", self.project).into()
+	}
+}
+
+impl<'a> Pageable for ProjectVariables<'a> {}
+
+/// Query the push rules of a project.
+#[derive(Debug, Builder)]
+pub struct PushRule<'a> {
+	/// The project to query for push rules.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> PushRule<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> PushRuleBuilder<'a> {
+		PushRuleBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for PushRule<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/push_rule", self.project).into()
+	}
+}
+
+/// Add or edit the push rules of a project.
+#[derive(Debug, Builder)]
+pub struct EditPushRule<'a> {
+	/// The project to edit.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// Whether the project has no push rules yet.
+	#[builder(default)]
+	create: bool,
+	/// The rules to change.
+	rules: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> EditPushRule<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> EditPushRuleBuilder<'a> {
+		EditPushRuleBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for EditPushRule<'a> {
+	fn method(&self) -> Method {
+		if self.create {
+			Method::POST
+		} else {
+			Method::PUT
+		}
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/push_rule", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params.extend(self.rules.iter().map(|(k, v)| (k.as_ref(), v)));
+		params.into_body()
+	}
+}
+
+/// Delete a CI/CD variable of a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct DeleteProjectVariable<'a> {
+	/// The project to delete the variable from.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The name of the variable.
+	#[builder(setter(into))]
+	key: Cow<'a, str>,
+	/// The environment scope of the variable.
+	#[builder(setter(into), default)]
+	environment_scope: Option<Cow<'a, str>>,
+}
+
+impl<'a> DeleteProjectVariable<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeleteProjectVariableBuilder<'a> {
+		DeleteProjectVariableBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DeleteProjectVariable<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/variables/{}",
+			self.project,
+			common::path_escaped(&self.key)
+		)
+		.into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params.push_opt("filter[environment_scope]", self.environment_scope.as_ref());
+		params
+	}
+}
+
+/// Create or edit a CI/CD variable of a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct SetProjectVariable<'a> {
+	/// The project to set the variable on.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// Whether the variable doesn't exist yet.
+	#[builder(default)]
+	create: bool,
+	/// The name of the variable.
+	#[builder(setter(into))]
+	key: Cow<'a, str>,
+	/// The value of the variable.
+	#[builder(setter(into))]
+	value: Cow<'a, str>,
+	/// The type of the variable: env_var or file.
+	#[builder(setter(into), default)]
+	variable_type: Option<Cow<'a, str>>,
+	/// Whether the variable is only exposed to protected branches and tags.
+	#[builder(default)]
+	protected: Option<bool>,
+	/// Whether the variable is masked in job logs.
+	#[builder(default)]
+	masked: Option<bool>,
+	/// Whether variable references in the value are not expanded.
+	#[builder(default)]
+	raw: Option<bool>,
+	/// The environment scope of the variable.
+	#[builder(setter(into), default)]
+	environment_scope: Option<Cow<'a, str>>,
+}
+
+impl<'a> SetProjectVariable<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> SetProjectVariableBuilder<'a> {
+		SetProjectVariableBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for SetProjectVariable<'a> {
+	fn method(&self) -> Method {
+		if self.create {
+			Method::POST
+		} else {
+			Method::PUT
+		}
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		if self.create {
+			format!("projects/{}/variables", self.project).into()
+		} else {
+			format!(
+				"projects/{}/variables/{}",
+				self.project,
+				common::path_escaped(&self.key)
+			)
+			.into()
+		}
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params
+			.push("value", &self.value)
+			.push_opt("variable_type", self.variable_type.as_ref())
+			.push_opt("protected", self.protected)
+			.push_opt("masked", self.masked)
+			.push_opt("raw", self.raw);
+		if self.create {
+			params
+				.push("key", &self.key)
+				.push_opt("environment_scope", self.environment_scope.as_ref());
+		} else {
+			params.push_opt("filter[environment_scope]", self.environment_scope.as_ref());
+		}
+		params.into_body()
+	}
+}
@@ -0,0 +1,159 @@
+use derive_builder::Builder;
+use gitlab::api::common::{self, NameOrId};
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the container registry repositories of a project.
+#[derive(Debug, Builder)]
+pub struct RegistryRepositories<'a> {
+	/// The project to query for registry repositories.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> RegistryRepositories<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> RegistryRepositoriesBuilder<'a> {
+		RegistryRepositoriesBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for RegistryRepositories<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/registry/repositories", self.project).into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params.push("tags_count", true);
+		params
+	}
+}
+
+impl<'a> Pageable for RegistryRepositories<'a> {}
+
+/// Query the tags of a container registry repository.
+#[derive(Debug, Builder)]
+pub struct RegistryTags<'a> {
+	/// The project of the repository.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the repository.
+	repository: u64,
+}
+
+impl<'a> RegistryTags<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> RegistryTagsBuilder<'a> {
+		RegistryTagsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for RegistryTags<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/registry/repositories/{}/tags",
+			self.project, self.repository
+		)
+		.into()
+	}
+}
+
+impl<'a> Pageable for RegistryTags<'a> {}
+
+/// Query the details of a container registry tag.
+#[derive(Debug, Builder)]
+pub struct RegistryTag<'a> {
+	/// The project of the repository.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the repository.
+	repository: u64,
+	/// The name of the tag.
+	#[builder(setter(into))]
+	tag: Cow<'a, str>,
+}
+
+impl<'a> RegistryTag<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> RegistryTagBuilder<'a> {
+		RegistryTagBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for RegistryTag<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/registry/repositories/{}/tags/{}",
+			self.project,
+			self.repository,
+			common::path_escaped(&self.tag)
+		)
+		.into()
+	}
+}
+
+/// Delete the tags of a container registry repository in bulk.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct DeleteRegistryTags<'a> {
+	/// The project of the repository.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the repository.
+	repository: u64,
+	/// Delete the tags matching this regex.
+	#[builder(setter(into))]
+	name_regex_delete: Cow<'a, str>,
+	/// Keep the tags matching this regex.
+	#[builder(setter(into), default)]
+	name_regex_keep: Option<Cow<'a, str>>,
+	/// Keep the n latest tags.
+	#[builder(default)]
+	keep_n: Option<u64>,
+	/// Only delete tags older than this age (ex: 30d).
+	#[builder(setter(into), default)]
+	older_than: Option<Cow<'a, str>>,
+}
+
+impl<'a> DeleteRegistryTags<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeleteRegistryTagsBuilder<'a> {
+		DeleteRegistryTagsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DeleteRegistryTags<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/registry/repositories/{}/tags",
+			self.project, self.repository
+		)
+		.into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params
+			.push("name_regex_delete", &self.name_regex_delete)
+			.push_opt("name_regex_keep", self.name_regex_keep.as_ref())
+			.push_opt("keep_n", self.keep_n)
+			.push_opt("older_than", self.older_than.as_ref());
+		params
+	}
+}
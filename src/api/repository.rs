@@ -0,0 +1,292 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use gitlab::api::common::{self, NameOrId};
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the tree of a repository.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct RepositoryTree<'a> {
+	/// The project to query for its tree.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The path inside the repository (the root by default).
+	#[builder(setter(into), default)]
+	path: Option<Cow<'a, str>>,
+	/// The reference to get the tree of (the default branch by default).
+	#[builder(setter(into), default)]
+	ref_: Option<Cow<'a, str>>,
+	/// List the subdirectories recursively.
+	#[builder(default)]
+	recursive: Option<bool>,
+}
+
+impl<'a> RepositoryTree<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> RepositoryTreeBuilder<'a> {
+		RepositoryTreeBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for RepositoryTree<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/repository/tree", self.project).into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params
+			.push_opt("path", self.path.as_ref())
+			.push_opt("ref", self.ref_.as_ref())
+			.push_opt("recursive", self.recursive);
+		params
+	}
+}
+
+impl<'a> Pageable for RepositoryTree<'a> {
+	fn use_keyset_pagination(&self) -> bool {
+		true
+	}
+}
+
+/// Query the commits of a repository.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Commits<'a> {
+	/// The project to query for commits.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The reference to list the commits of (the default branch by default).
+	#[builder(setter(into), default)]
+	ref_name: Option<Cow<'a, str>>,
+	/// Only commits after or on this date.
+	#[builder(default)]
+	since: Option<NaiveDate>,
+	/// Only commits of this author (name or email).
+	#[builder(setter(into), default)]
+	author: Option<Cow<'a, str>>,
+	/// Only commits touching this path.
+	#[builder(setter(into), default)]
+	path: Option<Cow<'a, str>>,
+	/// Include the number of added and deleted lines of each commit.
+	#[builder(default)]
+	with_stats: Option<bool>,
+}
+
+impl<'a> Commits<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> CommitsBuilder<'a> {
+		CommitsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for Commits<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/repository/commits", self.project).into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params
+			.push_opt("ref_name", self.ref_name.as_ref())
+			.push_opt("since", self.since)
+			.push_opt("author", self.author.as_ref())
+			.push_opt("path", self.path.as_ref())
+			.push_opt("with_stats", self.with_stats);
+		params
+	}
+}
+
+impl<'a> Pageable for Commits<'a> {}
+
+/// Query the diff of a commit.
+#[derive(Debug, Builder)]
+pub struct CommitDiff<'a> {
+	/// The project of the commit.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The commit hash or the name of a branch or tag.
+	#[builder(setter(into))]
+	commit: Cow<'a, str>,
+}
+
+impl<'a> CommitDiff<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> CommitDiffBuilder<'a> {
+		CommitDiffBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for CommitDiff<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/repository/commits/{}/diff",
+			self.project,
+			common::path_escaped(&self.commit)
+		)
+		.into()
+	}
+}
+
+impl<'a> Pageable for CommitDiff<'a> {}
+
+/// Cherry-pick a commit on a branch.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CherryPickCommit<'a> {
+	/// The project of the commit.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The commit hash.
+	#[builder(setter(into))]
+	commit: Cow<'a, str>,
+	/// The branch to commit to.
+	#[builder(setter(into))]
+	branch: Cow<'a, str>,
+	/// Check that the commit applies without committing anything.
+	#[builder(default)]
+	dry_run: Option<bool>,
+	/// A custom commit message.
+	#[builder(setter(into), default)]
+	message: Option<Cow<'a, str>>,
+}
+
+impl<'a> CherryPickCommit<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> CherryPickCommitBuilder<'a> {
+		CherryPickCommitBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for CherryPickCommit<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/repository/commits/{}/cherry_pick",
+			self.project,
+			common::path_escaped(&self.commit)
+		)
+		.into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params
+			.push("branch", &self.branch)
+			.push_opt("dry_run", self.dry_run)
+			.push_opt("message", self.message.as_ref());
+		params.into_body()
+	}
+}
+
+/// Revert a commit on a branch.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct RevertCommit<'a> {
+	/// The project of the commit.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The commit hash.
+	#[builder(setter(into))]
+	commit: Cow<'a, str>,
+	/// The branch to commit to.
+	#[builder(setter(into))]
+	branch: Cow<'a, str>,
+	/// Check that the commit applies without committing anything.
+	#[builder(default)]
+	dry_run: Option<bool>,
+}
+
+impl<'a> RevertCommit<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> RevertCommitBuilder<'a> {
+		RevertCommitBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for RevertCommit<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/repository/commits/{}/revert",
+			self.project,
+			common::path_escaped(&self.commit)
+		)
+		.into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params
+			.push("branch", &self.branch)
+			.push_opt("dry_run", self.dry_run);
+		params.into_body()
+	}
+}
+
+/// Query the blame of a file.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct FileBlame<'a> {
+	/// The project of the file.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The path of the file in the repository.
+	#[builder(setter(into))]
+	file_path: Cow<'a, str>,
+	/// The reference to blame the file at.
+	#[builder(setter(into))]
+	ref_: Cow<'a, str>,
+	/// The first and last lines to blame (all lines by default).
+	#[builder(default)]
+	range: Option<(u64, u64)>,
+}
+
+impl<'a> FileBlame<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> FileBlameBuilder<'a> {
+		FileBlameBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for FileBlame<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/repository/files/{}/blame",
+			self.project,
+			common::path_escaped(&self.file_path)
+		)
+		.into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params.push("ref", &self.ref_);
+		if let Some((start, end)) = self.range {
+			params.push("range[start]", start).push("range[end]", end);
+		}
+		params
+	}
+}
@@ -0,0 +1,262 @@
+use crate::api::access_requests::Source;
+
+use derive_builder::Builder;
+use gitlab::api::common::CommaSeparatedList;
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the runners of a project, a group, the current user or the whole instance.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Runners<'a> {
+	/// The project or group to query for runners (runners of the current user by default).
+	#[builder(default)]
+	source: Option<Source<'a>>,
+	/// Query all the runners of the instance (administrators only).
+	#[builder(default)]
+	all: bool,
+	/// Only return runners with this status.
+	#[builder(setter(into), default)]
+	status: Option<Cow<'a, str>>,
+}
+
+impl<'a> Runners<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> RunnersBuilder<'a> {
+		RunnersBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for Runners<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		match &self.source {
+			Some(source) => format!("{}/runners", source).into(),
+			None if self.all => "runners/all".into(),
+			None => "runners".into(),
+		}
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params.push_opt("status", self.status.as_ref());
+		params
+	}
+}
+
+impl<'a> Pageable for Runners<'a> {}
+
+/// Query the details of a runner.
+#[derive(Debug, Builder)]
+pub struct Runner {
+	/// The id of the runner.
+	runner: u64,
+}
+
+impl Runner {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> RunnerBuilder {
+		RunnerBuilder::default()
+	}
+}
+
+impl Endpoint for Runner {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("runners/{}", self.runner).into()
+	}
+}
+
+/// Edit a runner.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct EditRunner {
+	/// The id of the runner.
+	runner: u64,
+	/// Pause or resume the runner.
+	#[builder(default)]
+	paused: Option<bool>,
+}
+
+impl EditRunner {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> EditRunnerBuilder {
+		EditRunnerBuilder::default()
+	}
+}
+
+impl Endpoint for EditRunner {
+	fn method(&self) -> Method {
+		Method::PUT
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("runners/{}", self.runner).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		params.push_opt("paused", self.paused);
+		params.into_body()
+	}
+}
+
+/// Delete a runner.
+#[derive(Debug, Builder)]
+pub struct DeleteRunner {
+	/// The id of the runner.
+	runner: u64,
+}
+
+impl DeleteRunner {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeleteRunnerBuilder {
+		DeleteRunnerBuilder::default()
+	}
+}
+
+impl Endpoint for DeleteRunner {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("runners/{}", self.runner).into()
+	}
+}
+
+/// The scope of a new runner.
+#[derive(Debug, Clone, Copy)]
+pub enum RunnerType {
+	/// A runner available to all the projects of the instance.
+	Instance,
+	/// A runner available to the projects of a group.
+	Group(u64),
+	/// A runner available to a single project.
+	Project(u64),
+}
+
+/// Create a runner and get its authentication token.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CreateRunner<'a> {
+	/// The scope of the runner.
+	runner_type: RunnerType,
+	/// The description of the runner.
+	#[builder(setter(into), default)]
+	description: Option<Cow<'a, str>>,
+	/// The tags of the runner.
+	#[builder(setter(name = "_tags"), default, private)]
+	tags: CommaSeparatedList<Cow<'a, str>>,
+	/// Whether the runner picks up jobs without tags.
+	#[builder(default)]
+	run_untagged: Option<bool>,
+	/// Whether the runner is locked to its current projects.
+	#[builder(default)]
+	locked: Option<bool>,
+	/// Whether the runner is created paused.
+	#[builder(default)]
+	paused: Option<bool>,
+}
+
+impl<'a> CreateRunner<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> CreateRunnerBuilder<'a> {
+		CreateRunnerBuilder::default()
+	}
+}
+
+impl<'a> CreateRunnerBuilder<'a> {
+	/// Add a tag to the runner.
+	pub fn tag<T>(&mut self, tag: T) -> &mut Self
+	where
+		T: Into<Cow<'a, str>>,
+	{
+		self.tags
+			.get_or_insert_with(CommaSeparatedList::new)
+			.push(tag.into());
+		self
+	}
+}
+
+impl<'a> Endpoint for CreateRunner<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		"user/runners".into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut params = FormParams::default();
+		match self.runner_type {
+			RunnerType::Instance => {
+				params.push("runner_type", "instance_type");
+			}
+			RunnerType::Group(group) => {
+				params
+					.push("runner_type", "group_type")
+					.push("group_id", group);
+			}
+			RunnerType::Project(project) => {
+				params
+					.push("runner_type", "project_type")
+					.push("project_id", project);
+			}
+		}
+		params
+			.push_opt("description", self.description.as_ref())
+			.push_opt("run_untagged", self.run_untagged)
+			.push_opt("locked", self.locked)
+			.push_opt("paused", self.paused);
+		if !self.tags.is_empty() {
+			params.push("tag_list", &self.tags);
+		}
+		params.into_body()
+	}
+}
+
+/// Query the jobs processed by a runner.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct RunnerJobs<'a> {
+	/// The id of the runner.
+	runner: u64,
+	/// Only return jobs with this status.
+	#[builder(setter(into), default)]
+	status: Option<Cow<'a, str>>,
+}
+
+impl<'a> RunnerJobs<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> RunnerJobsBuilder<'a> {
+		RunnerJobsBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for RunnerJobs<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("runners/{}/jobs", self.runner).into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params
+			.push_opt("status", self.status.as_ref())
+			.push("order_by", "id")
+			.push("sort", "desc");
+		params
+	}
+}
+
+impl<'a> Pageable for RunnerJobs<'a> {}
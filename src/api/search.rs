@@ -0,0 +1,53 @@
+use crate::api::access_requests::Source;
+
+use derive_builder::Builder;
+use gitlab::api::endpoint_prelude::*;
+
+/// Search a project, a group or the whole instance.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Search<'a> {
+	/// The project or group to search (the whole instance by default).
+	#[builder(default)]
+	source: Option<Source<'a>>,
+	/// The kind of objects to search (blobs, commits, issues...).
+	#[builder(setter(into))]
+	scope: Cow<'a, str>,
+	/// The search query.
+	#[builder(setter(into))]
+	search: Cow<'a, str>,
+	/// The reference to search in (projects only).
+	#[builder(setter(into), default)]
+	ref_: Option<Cow<'a, str>>,
+}
+
+impl<'a> Search<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> SearchBuilder<'a> {
+		SearchBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for Search<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		match &self.source {
+			Some(source) => format!("{}/search", source).into(),
+			None => "search".into(),
+		}
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params
+			.push("scope", &self.scope)
+			.push("search", &self.search)
+			.push_opt("ref", self.ref_.as_ref());
+		params
+	}
+}
+
+impl<'a> Pageable for Search<'a> {}
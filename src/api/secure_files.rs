@@ -0,0 +1,132 @@
+use crate::api::multipart::Multipart;
+
+use derive_builder::Builder;
+use gitlab::api::common::NameOrId;
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the secure files of a project.
+#[derive(Debug, Builder)]
+pub struct SecureFiles<'a> {
+	/// The project to query for secure files.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+}
+
+impl<'a> SecureFiles<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> SecureFilesBuilder<'a> {
+		SecureFilesBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for SecureFiles<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/secure_files", self.project).into()
+	}
+}
+
+impl<'a> Pageable for SecureFiles<'a> {}
+
+/// Upload a secure file to a project.
+#[derive(Debug, Builder)]
+pub struct UploadSecureFile<'a> {
+	/// The project to upload the secure file to.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The name of the secure file.
+	#[builder(setter(into))]
+	name: Cow<'a, str>,
+	/// The content of the secure file.
+	#[builder(setter(into))]
+	file: Cow<'a, [u8]>,
+}
+
+impl<'a> UploadSecureFile<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> UploadSecureFileBuilder<'a> {
+		UploadSecureFileBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for UploadSecureFile<'a> {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("projects/{}/secure_files", self.project).into()
+	}
+
+	fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+		let mut body = Multipart::default();
+		body.text("name", &self.name)
+			.file("file", &self.name, &self.file);
+		body.into_body()
+	}
+}
+
+/// Download a secure file of a project.
+#[derive(Debug, Builder)]
+pub struct DownloadSecureFile<'a> {
+	/// The project of the secure file.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the secure file.
+	secure_file: u64,
+}
+
+impl<'a> DownloadSecureFile<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DownloadSecureFileBuilder<'a> {
+		DownloadSecureFileBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DownloadSecureFile<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/secure_files/{}/download",
+			self.project, self.secure_file
+		)
+		.into()
+	}
+}
+
+/// Delete a secure file of a project.
+#[derive(Debug, Builder)]
+pub struct DeleteSecureFile<'a> {
+	/// The project of the secure file.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The id of the secure file.
+	secure_file: u64,
+}
+
+impl<'a> DeleteSecureFile<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeleteSecureFileBuilder<'a> {
+		DeleteSecureFileBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DeleteSecureFile<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/secure_files/{}",
+			self.project, self.secure_file
+		)
+		.into()
+	}
+}
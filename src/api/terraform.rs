@@ -0,0 +1,222 @@
+use derive_builder::Builder;
+use gitlab::api::common::{self, NameOrId};
+use gitlab::api::endpoint_prelude::*;
+use graphql_client::{GraphQLQuery, QueryBody};
+use serde::{Deserialize, Serialize};
+
+/// Download the latest version of a terraform state.
+#[derive(Debug, Builder)]
+pub struct TerraformState<'a> {
+	/// The project of the state.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The name of the state.
+	#[builder(setter(into))]
+	name: Cow<'a, str>,
+}
+
+impl<'a> TerraformState<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> TerraformStateBuilder<'a> {
+		TerraformStateBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for TerraformState<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/terraform/state/{}",
+			self.project,
+			common::path_escaped(&self.name)
+		)
+		.into()
+	}
+}
+
+/// Delete a terraform state and all its versions.
+#[derive(Debug, Builder)]
+pub struct DeleteTerraformState<'a> {
+	/// The project of the state.
+	#[builder(setter(into))]
+	project: NameOrId<'a>,
+	/// The name of the state.
+	#[builder(setter(into))]
+	name: Cow<'a, str>,
+}
+
+impl<'a> DeleteTerraformState<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> DeleteTerraformStateBuilder<'a> {
+		DeleteTerraformStateBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for DeleteTerraformState<'a> {
+	fn method(&self) -> Method {
+		Method::DELETE
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!(
+			"projects/{}/terraform/state/{}",
+			self.project,
+			common::path_escaped(&self.name)
+		)
+		.into()
+	}
+}
+
+/// Query the terraform states of a project (only exposed by the GraphQL api).
+pub struct TerraformStatesQuery;
+
+/// Variables of the terraform states query.
+#[derive(Debug, Serialize)]
+pub struct TerraformStatesVariables {
+	/// The full path of the project.
+	#[serde(rename = "fullPath")]
+	pub full_path: String,
+}
+
+/// Response of the terraform states query.
+#[derive(Debug, Deserialize)]
+pub struct TerraformStatesData {
+	/// The project, if found.
+	pub project: Option<TerraformStatesProject>,
+}
+
+/// Terraform states of a project.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TerraformStatesProject {
+	/// The terraform states.
+	pub terraform_states: Option<TerraformStateNodes>,
+}
+
+/// A list of terraform states.
+#[derive(Debug, Deserialize)]
+pub struct TerraformStateNodes {
+	/// The terraform states.
+	pub nodes: Vec<TerraformStateNode>,
+}
+
+/// A terraform state.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TerraformStateNode {
+	/// The global id of the state.
+	pub id: String,
+	/// The name of the state.
+	pub name: String,
+	/// When the state was locked.
+	pub locked_at: Option<String>,
+	/// Who locked the state.
+	pub locked_by_user: Option<TerraformStateUser>,
+	/// When the state was last updated.
+	pub updated_at: String,
+	/// The latest version of the state.
+	pub latest_version: Option<TerraformStateVersion>,
+}
+
+/// A user locking a terraform state.
+#[derive(Debug, Deserialize)]
+pub struct TerraformStateUser {
+	/// The username of the user.
+	pub username: String,
+}
+
+/// A version of a terraform state.
+#[derive(Debug, Deserialize)]
+pub struct TerraformStateVersion {
+	/// The serial number of the version.
+	pub serial: Option<u64>,
+}
+
+impl GraphQLQuery for TerraformStatesQuery {
+	type Variables = TerraformStatesVariables;
+	type ResponseData = TerraformStatesData;
+
+	fn build_query(variables: Self::Variables) -> QueryBody<Self::Variables> {
+		QueryBody {
+			variables,
+			query: "query TerraformStates($fullPath: ID!) {
+	project(fullPath: $fullPath) {
+		terraformStates {
+			nodes {
+				id
+				name
+				lockedAt
+				lockedByUser { username }
+				updatedAt
+				latestVersion { serial }
+			}
+		}
+	}
+}",
+			operation_name: "TerraformStates",
+		}
+	}
+}
+
+/// Variables of the terraform state lock and unlock mutations.
+#[derive(Debug, Serialize)]
+pub struct TerraformStateLockVariables {
+	/// The global id of the state.
+	pub id: String,
+}
+
+/// Response of the terraform state lock and unlock mutations.
+#[derive(Debug, Deserialize)]
+pub struct TerraformStateLockData {
+	/// The result of the mutation.
+	#[serde(alias = "terraformStateLock", alias = "terraformStateUnlock")]
+	pub result: Option<TerraformStateLockResult>,
+}
+
+/// Result of the terraform state lock and unlock mutations.
+#[derive(Debug, Deserialize)]
+pub struct TerraformStateLockResult {
+	/// The errors encountered during the mutation.
+	#[serde(default)]
+	pub errors: Vec<String>,
+}
+
+/// Lock a terraform state (only exposed by the GraphQL api).
+pub struct TerraformStateLockMutation;
+
+impl GraphQLQuery for TerraformStateLockMutation {
+	type Variables = TerraformStateLockVariables;
+	type ResponseData = TerraformStateLockData;
+
+	fn build_query(variables: Self::Variables) -> QueryBody<Self::Variables> {
+		QueryBody {
+			variables,
+			query: "mutation TerraformStateLock($id: TerraformStateID!) {
+	terraformStateLock(input: { id: $id }) { errors }
+}",
+			operation_name: "TerraformStateLock",
+		}
+	}
+}
+
+/// Unlock a terraform state, even if it was locked by someone else (only exposed by the
+/// GraphQL api).
+pub struct TerraformStateUnlockMutation;
+
+impl GraphQLQuery for TerraformStateUnlockMutation {
+	type Variables = TerraformStateLockVariables;
+	type ResponseData = TerraformStateLockData;
+
+	fn build_query(variables: Self::Variables) -> QueryBody<Self::Variables> {
+		QueryBody {
+			variables,
+			query: "mutation TerraformStateUnlock($id: TerraformStateID!) {
+	terraformStateUnlock(input: { id: $id }) { errors }
+}",
+			operation_name: "TerraformStateUnlock",
+		}
+	}
+}
@@ -0,0 +1,90 @@
+use derive_builder::Builder;
+use gitlab::api::endpoint_prelude::*;
+
+/// Query the todos of the current user.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Todos<'a> {
+	/// Filter todos by project id.
+	#[builder(default)]
+	project_id: Option<u64>,
+	/// Filter todos by target type (Issue, MergeRequest, ...).
+	#[builder(setter(into), default)]
+	type_: Option<Cow<'a, str>>,
+	/// Filter todos by state (pending or done).
+	#[builder(setter(into), default)]
+	state: Option<Cow<'a, str>>,
+}
+
+impl<'a> Todos<'a> {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> TodosBuilder<'a> {
+		TodosBuilder::default()
+	}
+}
+
+impl<'a> Endpoint for Todos<'a> {
+	fn method(&self) -> Method {
+		Method::GET
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		"todos".into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params
+			.push_opt("project_id", self.project_id)
+			.push_opt("type", self.type_.as_ref())
+			.push_opt("state", self.state.as_ref());
+		params
+	}
+}
+
+impl<'a> Pageable for Todos<'a> {}
+
+/// Mark a single todo as done.
+#[derive(Debug, Builder)]
+pub struct TodoDone {
+	/// The todo id.
+	todo: u64,
+}
+
+impl TodoDone {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> TodoDoneBuilder {
+		TodoDoneBuilder::default()
+	}
+}
+
+impl Endpoint for TodoDone {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		format!("todos/{}/mark_as_done", self.todo).into()
+	}
+}
+
+/// Mark all pending todos as done.
+#[derive(Debug, Builder)]
+pub struct TodosDone {}
+
+impl TodosDone {
+	/// Create a builder for the endpoint.
+	pub fn builder() -> TodosDoneBuilder {
+		TodosDoneBuilder::default()
+	}
+}
+
+impl Endpoint for TodosDone {
+	fn method(&self) -> Method {
+		Method::POST
+	}
+
+	fn endpoint(&self) -> Cow<'static, str> {
+		"todos/mark_as_done".into()
+	}
+}
@@ -1,3 +1,5 @@
+use crate::args::ArchiveFormat;
+
 use derive_builder::Builder;
 use gitlab::api::common::NameOrId;
 use gitlab::api::endpoint_prelude::*;
@@ -8,9 +10,13 @@ pub struct Archive<'a> {
 	/// The project to query for pipeline.
 	#[builder(setter(into))]
 	project: NameOrId<'a>,
-	#[allow(dead_code)]
 	#[builder(setter(into))]
 	sha: String,
+	/// The format of the archive.
+	format: ArchiveFormat,
+	/// Only archive this subdirectory of the repository.
+	#[builder(setter(into, strip_option), default)]
+	path: Option<String>,
 }
 
 impl<'a> Archive<'a> {
@@ -26,6 +32,19 @@ impl<'a> Endpoint for Archive<'a> {
 	}
 
 	fn endpoint(&self) -> Cow<'static, str> {
-		format!("projects/{}/repository/archive.tar.gz", self.project).into()
+		format!(
+			"projects/{}/repository/archive.{}",
+			self.project,
+			self.format.extension()
+		)
+		.into()
+	}
+
+	fn parameters(&self) -> QueryParams<'_> {
+		let mut params = QueryParams::default();
+		params
+			.push("sha", &self.sha)
+			.push_opt("path", self.path.as_ref());
+		params
 	}
 }
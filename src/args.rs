@@ -1,11 +1,13 @@
-#[cfg(feature = "color")]
-use argh::FromArgValue;
-use argh::{FromArgs, TopLevelCommand};
+use crate::config::{self, Settings};
+
+use argh::{FromArgValue, FromArgs, SubCommands, TopLevelCommand};
+use serde::{Deserialize, Serialize};
 use std::{env, path::Path};
 
 /// Color mode
 #[allow(dead_code)]
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ColorChoice {
 	Auto,
 	Always,
@@ -30,6 +32,90 @@ impl FromArgValue for ColorChoice {
 	}
 }
 
+/// Protocol used to clone repositories
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Protocol {
+	Ssh,
+	Https,
+}
+
+impl FromArgValue for Protocol {
+	fn from_arg_value(value: &str) -> Result<Self, String> {
+		if value == "ssh" {
+			Ok(Self::Ssh)
+		} else if value == "https" {
+			Ok(Self::Https)
+		} else {
+			Err(format!(
+				"{} not supported for --protocol. Use either \"ssh\" or \"https\"",
+				value
+			))
+		}
+	}
+}
+
+/// Output format
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Format {
+	Csv,
+	Json,
+	Yaml,
+}
+
+impl FromArgValue for Format {
+	fn from_arg_value(value: &str) -> Result<Self, String> {
+		if value == "csv" {
+			Ok(Self::Csv)
+		} else if value == "json" {
+			Ok(Self::Json)
+		} else if value == "yaml" {
+			Ok(Self::Yaml)
+		} else {
+			Err(format!(
+				"{} not supported for --format. Use either \"csv\", \"json\" or \"yaml\"",
+				value
+			))
+		}
+	}
+}
+
+/// Archive format
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ArchiveFormat {
+	TarGz,
+	TarBz2,
+	Zip,
+}
+
+impl ArchiveFormat {
+	/// Extension of the archive
+	pub fn extension(&self) -> &'static str {
+		match self {
+			Self::TarGz => "tar.gz",
+			Self::TarBz2 => "tar.bz2",
+			Self::Zip => "zip",
+		}
+	}
+}
+
+impl FromArgValue for ArchiveFormat {
+	fn from_arg_value(value: &str) -> Result<Self, String> {
+		if value == "tar.gz" {
+			Ok(Self::TarGz)
+		} else if value == "tar.bz2" {
+			Ok(Self::TarBz2)
+		} else if value == "zip" {
+			Ok(Self::Zip)
+		} else {
+			Err(format!(
+				"{} not supported for --format. Use either \"tar.gz\", \"tar.bz2\" or \"zip\"",
+				value
+			))
+		}
+	}
+}
+
 /// Interact with Gitlab API
 #[derive(FromArgs)]
 pub struct Opts {
@@ -37,6 +123,14 @@ pub struct Opts {
 	#[argh(option, short = 'c')]
 	pub config: Option<String>,
 
+	/// name of the gitlab host to use among the hosts of the configuration
+	#[argh(option)]
+	pub host: Option<String>,
+
+	/// name of the git remote to take the project from
+	#[argh(option)]
+	pub remote: Option<String>,
+
 	/// more detailed output
 	#[argh(switch, short = 'v')]
 	pub verbose: bool,
@@ -51,13 +145,45 @@ pub struct Opts {
 
 	#[cfg(feature = "color")]
 	/// color mode: auto (default), always or never
-	#[argh(option, default = "ColorChoice::Auto")]
-	pub color: ColorChoice,
+	#[argh(option)]
+	pub color: Option<ColorChoice>,
 
 	/// don't save oidc login to cache
 	#[argh(switch)]
 	pub no_cache: bool,
 
+	/// read the access token from stdin instead of the configuration
+	#[argh(switch)]
+	pub token_stdin: bool,
+
+	/// warn when the access token lacks the scope needed by the command
+	#[argh(switch)]
+	pub check_scopes: bool,
+
+	/// PEM file of the certificate authorities of the gitlab host
+	#[argh(option)]
+	pub ca_cert: Option<String>,
+
+	/// don't check the certificate of the gitlab host
+	#[argh(switch)]
+	pub insecure: bool,
+
+	/// timeout of requests in seconds (30 by default)
+	#[argh(option)]
+	pub timeout: Option<u64>,
+
+	/// timeout of the connection to the gitlab host in seconds
+	#[argh(option)]
+	pub connect_timeout: Option<u64>,
+
+	/// number of times a request is retried after a transient failure (5 by default)
+	#[argh(option)]
+	pub retries: Option<u32>,
+
+	/// run the command against every project of a group (and its subgroups)
+	#[argh(option)]
+	pub each_project_in: Option<String>,
+
 	#[argh(subcommand)]
 	pub cmd: SubCommand,
 }
@@ -70,6 +196,37 @@ pub enum SubCommand {
 	Build(Pipeline),
 	Archive(Archive),
 	Project(Project),
+	Todo(Todo),
+	Issue(Issue),
+	Mr(Mr),
+	Group(Group),
+	Badge(Badge),
+	AccessRequests(AccessRequests),
+	Audit(Audit),
+	Variables(Variables),
+	Ci(Ci),
+	Env(Env),
+	Freeze(Freeze),
+	FeatureFlags(FeatureFlags),
+	JobTokenAllowlist(JobTokenAllowlist),
+	SecureFiles(SecureFiles),
+	Runner(Runner),
+	Registry(Registry),
+	Package(Package),
+	DepProxy(DepProxy),
+	TfState(TfState),
+	Pages(Pages),
+	File(File),
+	Tree(Tree),
+	Commits(Commits),
+	Blame(Blame),
+	Search(Search),
+	Clone(GitClone),
+	Auth(Auth),
+	Token(Token),
+	Admin(Admin),
+	Config(Config),
+	Alias(Alias),
 }
 
 /// Get and extract archives
@@ -84,17 +241,18 @@ pub struct ArchiveExtract {
 	#[argh(option, short = 'b')]
 	pub batch: Option<String>,
 
-	/// strip first n path components of every entries in archive before extraction
-	#[argh(option, short = 's', default = "0")]
+	/// strip first n path components of every entries in archive before extraction, like the
+	/// <project>-<sha> top level directory (after the renamed directory in rename mode)
+	#[argh(option, short = 's', long = "strip-components", default = "0")]
 	pub strip: usize,
 
 	/// rename first directory of the archive to the name of the project
 	#[argh(switch, short = 'r')]
 	pub rename: bool,
 
-	/// destination directory
-	#[argh(option, short = 'd', default = "\"tmp\".to_string()")]
-	pub dir: String,
+	/// destination directory (tmp by default)
+	#[argh(option, short = 'd')]
+	pub dir: Option<String>,
 
 	/// skip extraction of projects if a directory with same name already exists. by default destination directory is removed before extraction
 	#[argh(switch, short = 'k')]
@@ -104,6 +262,31 @@ pub struct ArchiveExtract {
 	#[argh(switch, short = 'u')]
 	pub update: bool,
 
+	/// extract exactly the commits recorded in the lock file, failing for projects not in it
+	#[argh(switch, short = 'l')]
+	pub locked: bool,
+
+	/// only download and extract this subdirectory of the repository
+	#[argh(option, short = 'P')]
+	pub path: Option<String>,
+
+	/// archive format: tar.gz (default), tar.bz2 or zip
+	#[argh(option, short = 'f', default = "ArchiveFormat::TarGz")]
+	pub format: ArchiveFormat,
+
+	/// save archives to a temporary file before extraction so that interrupted downloads
+	/// can be resumed
+	#[argh(switch, short = 'c')]
+	pub resume: bool,
+
+	/// only extract entries whose path (after stripping) matches the glob (can be repeated)
+	#[argh(option, short = 'i')]
+	pub include: Vec<String>,
+
+	/// don't extract entries whose path (after stripping) matches the glob (can be repeated)
+	#[argh(option, short = 'e')]
+	pub exclude: Vec<String>,
+
 	/// reference (tag or branch) to extract an archive from
 	#[argh(positional)]
 	pub ref_: Option<String>,
@@ -235,6 +418,10 @@ pub struct PipelineList {
 	#[argh(option, short = 'l', default = "10")]
 	pub limit: usize,
 
+	/// only the pipelines of the merge request of the current branch
+	#[argh(switch, short = 'm')]
+	pub mr: bool,
+
 	/// pipeline id
 	#[argh(positional)]
 	pub id: Option<u64>,
@@ -252,6 +439,10 @@ pub struct PipelineStatus {
 	#[argh(option, short = 'r')]
 	pub ref_: Option<String>,
 
+	/// the latest pipeline of the merge request of the current branch
+	#[argh(switch, short = 'm')]
+	pub mr: bool,
+
 	/// pipeline id
 	#[argh(positional)]
 	pub id: Option<u64>,
@@ -350,9 +541,62 @@ pub struct Pipeline {
 	pub cmd: PipelineCmd,
 }
 
+/// Global options followed by a value
+const OPTIONS_WITH_VALUE: [&str; 10] = [
+	"-c",
+	"--config",
+	"--host",
+	"--remote",
+	"--color",
+	"--each-project-in",
+	"--ca-cert",
+	"--timeout",
+	"--connect-timeout",
+	"--retries",
+];
+
+/// Replace an alias of the config file by its command line. Commands can't be overridden by
+/// aliases
+fn expand_alias(mut args: Vec<String>) -> Vec<String> {
+	// the command is the first argument which is neither a global option nor its value
+	let mut i = 1;
+	while i < args.len() && args[i].starts_with('-') {
+		i += if OPTIONS_WITH_VALUE.contains(&args[i].as_str()) {
+			2
+		} else {
+			1
+		};
+	}
+	if i >= args.len()
+		|| SubCommand::COMMANDS
+			.iter()
+			.any(|command| command.name == args[i])
+	{
+		return args;
+	}
+
+	let path = args[1..i]
+		.iter()
+		.position(|arg| arg == "-c" || arg == "--config")
+		.and_then(|j| args.get(j + 2));
+	// errors are reported later by the context
+	if let Some(command) = config::Config::aliases(path)
+		.ok()
+		.and_then(|aliases| aliases.get(&args[i]).cloned())
+	{
+		args.splice(i..=i, command.split_whitespace().map(str::to_owned));
+	}
+	args
+}
+
 /// copy of argh::from_env to insert command name and version in help text
 pub fn from_env<T: TopLevelCommand>() -> T {
-	let args: Vec<String> = std::env::args().collect();
+	let args = expand_alias(std::env::args().collect());
+	// add the default options of the repository (errors are reported later by the context)
+	let args = match Settings::from_repo() {
+		Ok(Some(settings)) => settings.apply_args(args),
+		_ => args,
+	};
 	let cmd = Path::new(&args[0])
 		.file_name()
 		.and_then(|s| s.to_str())
@@ -368,7 +612,7 @@ pub fn from_env<T: TopLevelCommand>() -> T {
 	})
 }
 
-/// Display information about project
+/// Display information about project or manage projects
 #[derive(FromArgs)]
 #[argh(subcommand, name = "project")]
 pub struct Project {
@@ -379,4 +623,3304 @@ pub struct Project {
 	/// reference (tag or branch)
 	#[argh(positional)]
 	pub ref_: Option<String>,
+
+	/// operate on projects
+	#[argh(subcommand)]
+	pub cmd: Option<ProjectCmd>,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum ProjectCmd {
+	Create(ProjectCreate),
+	Fork(ProjectFork),
+	Delete(ProjectDelete),
+	Settings(ProjectSettings),
+	List(ProjectList),
+	Mirror(ProjectMirror),
+	Export(ProjectExport),
+	Import(ProjectImport),
+	Stats(ProjectStats),
+	Topics(ProjectTopics),
+	Housekeeping(ProjectHousekeeping),
+	Avatar(ProjectAvatar),
+	Languages(ProjectLanguages),
+	Contributors(ProjectContributors),
+	ForkRelation(ProjectForkRelation),
+	Star(ProjectStar),
+	Unstar(ProjectUnstar),
+	Members(ProjectMembers),
+	Share(ProjectShare),
+	Unshare(ProjectUnshare),
+	PushRules(ProjectPushRules),
+	Tokens(ProjectTokens),
+}
+
+/// Create a new project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "create")]
+pub struct ProjectCreate {
+	/// namespace (group path) of the project. user namespace by default
+	#[argh(option, short = 'n')]
+	pub namespace: Option<String>,
+
+	/// visibility: private (default), internal or public
+	#[argh(option, short = 'V', default = "\"private\".to_string()")]
+	pub visibility: String,
+
+	/// default branch name
+	#[argh(option, short = 'b')]
+	pub default_branch: Option<String>,
+
+	/// initialize the repository with a README
+	#[argh(switch, short = 'i')]
+	pub readme: bool,
+
+	/// project description
+	#[argh(option, short = 'd')]
+	pub description: Option<String>,
+
+	/// path of a template project to create the project from
+	#[argh(option, short = 't')]
+	pub from_template: Option<String>,
+
+	/// name of the project
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Fork a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "fork")]
+pub struct ProjectFork {
+	/// the project to fork
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// namespace (group path) of the fork. user namespace by default
+	#[argh(option, short = 'n')]
+	pub namespace: Option<String>,
+
+	/// name (and path) of the fork. same as the forked project by default
+	#[argh(option)]
+	pub name: Option<String>,
+
+	/// wait for the fork to finish importing
+	#[argh(switch, short = 'w')]
+	pub wait: bool,
+}
+
+/// Delete a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "delete")]
+pub struct ProjectDelete {
+	/// the project to delete
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// don't ask for confirmation (needs --i-know-what-i-am-doing)
+	#[argh(switch)]
+	pub yes: bool,
+
+	/// acknowledge that the project will be deleted without confirmation
+	#[argh(switch)]
+	pub i_know_what_i_am_doing: bool,
+}
+
+/// List projects
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct ProjectList {
+	/// list projects of a group (and its subgroups)
+	#[argh(option, short = 'g')]
+	pub group: Option<String>,
+
+	/// only list projects I am a member of
+	#[argh(switch, short = 'm')]
+	pub membership: bool,
+
+	/// only list starred projects
+	#[argh(switch, short = 's')]
+	pub starred: bool,
+
+	/// only list projects matching the search term
+	#[argh(option)]
+	pub search: Option<String>,
+
+	/// only list projects with the given topic
+	#[argh(option, short = 't')]
+	pub topic: Option<String>,
+
+	/// limit
+	#[argh(option, short = 'l', default = "20")]
+	pub limit: usize,
+}
+
+/// Export a project to a tarball
+#[derive(FromArgs)]
+#[argh(subcommand, name = "export")]
+pub struct ProjectExport {
+	/// the project to export
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// path of the downloaded tarball: <project>_export.tar.gz by default
+	#[argh(option, short = 'o')]
+	pub output: Option<String>,
+}
+
+/// Import a project from an export tarball
+#[derive(FromArgs)]
+#[argh(subcommand, name = "import")]
+pub struct ProjectImport {
+	/// path of the new project
+	#[argh(option)]
+	pub path: String,
+
+	/// namespace (group path) of the new project. user namespace by default
+	#[argh(option, short = 'n')]
+	pub namespace: Option<String>,
+
+	/// name of the new project. same as path by default
+	#[argh(option)]
+	pub name: Option<String>,
+
+	/// export tarball to import
+	#[argh(positional)]
+	pub file: String,
+}
+
+/// Show project storage statistics
+#[derive(FromArgs)]
+#[argh(subcommand, name = "stats")]
+pub struct ProjectStats {
+	/// the project to show statistics of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// aggregate statistics of all projects of a group (and its subgroups)
+	#[argh(option, short = 'g')]
+	pub group: Option<String>,
+
+	/// number of projects to show in group mode (biggest first)
+	#[argh(option, short = 'l', default = "10")]
+	pub limit: usize,
+}
+
+/// Trigger housekeeping on a project repository
+#[derive(FromArgs)]
+#[argh(subcommand, name = "housekeeping")]
+pub struct ProjectHousekeeping {
+	/// the project to run housekeeping on
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// housekeeping task: eager or prune (prune unreachable objects)
+	#[argh(option, short = 't')]
+	pub task: Option<String>,
+}
+
+/// Show repository languages
+#[derive(FromArgs)]
+#[argh(subcommand, name = "languages")]
+pub struct ProjectLanguages {
+	/// the project to show languages of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// Show repository contributors ordered by commit count
+#[derive(FromArgs)]
+#[argh(subcommand, name = "contributors")]
+pub struct ProjectContributors {
+	/// the project to show contributors of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// limit
+	#[argh(option, short = 'l', default = "10")]
+	pub limit: usize,
+}
+
+/// Star a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "star")]
+pub struct ProjectStar {
+	/// the project to star
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// Unstar a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "unstar")]
+pub struct ProjectUnstar {
+	/// the project to unstar
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// Share a project with a group
+#[derive(FromArgs)]
+#[argh(subcommand, name = "share")]
+pub struct ProjectShare {
+	/// the project to share
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// access level: guest, reporter, developer (default), maintainer or owner
+	#[argh(option, short = 'a', default = "\"developer\".to_string()")]
+	pub access: String,
+
+	/// expiration date of the share (YYYY-MM-DD)
+	#[argh(option, short = 'e')]
+	pub expires: Option<String>,
+
+	/// the group path to share the project with
+	#[argh(positional)]
+	pub group: String,
+}
+
+/// Stop sharing a project with a group
+#[derive(FromArgs)]
+#[argh(subcommand, name = "unshare")]
+pub struct ProjectUnshare {
+	/// the project to unshare
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the group path to stop sharing the project with
+	#[argh(positional)]
+	pub group: String,
+}
+
+/// Manage project push rules
+#[derive(FromArgs)]
+#[argh(subcommand, name = "push-rules")]
+pub struct ProjectPushRules {
+	/// operate on push rules
+	#[argh(subcommand)]
+	pub cmd: ProjectPushRulesCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum ProjectPushRulesCmd {
+	Get(ProjectPushRulesGet),
+	Set(ProjectPushRulesSet),
+}
+
+/// Show project push rules
+#[derive(FromArgs)]
+#[argh(subcommand, name = "get")]
+pub struct ProjectPushRulesGet {
+	/// the project to show push rules of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// output format: yaml (default) or json
+	#[argh(option, short = 'f', default = "Format::Yaml")]
+	pub format: Format,
+}
+
+/// Change project push rules
+#[derive(FromArgs)]
+#[argh(subcommand, name = "set")]
+pub struct ProjectPushRulesSet {
+	/// the project to change push rules on
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// regular expression commit messages must match (empty to remove)
+	#[argh(option, short = 'm')]
+	pub commit_message_regex: Option<String>,
+
+	/// deny deleting tags: true or false
+	#[argh(option, short = 'd')]
+	pub deny_delete_tag: Option<bool>,
+
+	/// maximum file size in MB (0 for unlimited)
+	#[argh(option, short = 's')]
+	pub max_file_size: Option<u64>,
+
+	/// reject files that are likely to contain secrets: true or false
+	#[argh(option, short = 'S')]
+	pub prevent_secrets: Option<bool>,
+
+	/// other push rules to change (key=value)
+	#[argh(positional)]
+	pub rules: Vec<String>,
+}
+
+/// Manage project access tokens
+#[derive(FromArgs)]
+#[argh(subcommand, name = "tokens")]
+pub struct ProjectTokens {
+	/// operate on access tokens
+	#[argh(subcommand)]
+	pub cmd: ProjectTokensCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum ProjectTokensCmd {
+	List(ProjectTokensList),
+	Create(ProjectTokensCreate),
+	Revoke(ProjectTokensRevoke),
+}
+
+/// List project access tokens
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct ProjectTokensList {
+	/// the project to list access tokens of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// also list revoked and expired tokens
+	#[argh(switch, short = 'a')]
+	pub all: bool,
+}
+
+/// Create a project access token, printing it once
+#[derive(FromArgs)]
+#[argh(subcommand, name = "create")]
+pub struct ProjectTokensCreate {
+	/// the project to create an access token on
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// a scope of the token (api by default)
+	#[argh(option, short = 's')]
+	pub scopes: Vec<String>,
+
+	/// access level: guest, reporter, developer, maintainer (default) or owner
+	#[argh(option, short = 'a', default = "\"maintainer\".to_string()")]
+	pub access: String,
+
+	/// the expiration date of the token (YYYY-MM-DD)
+	#[argh(option, short = 'e')]
+	pub expires: Option<String>,
+
+	/// the name of the token
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Revoke a project access token
+#[derive(FromArgs)]
+#[argh(subcommand, name = "revoke")]
+pub struct ProjectTokensRevoke {
+	/// the project of the access token
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the id of the token
+	#[argh(positional)]
+	pub id: u64,
+}
+
+/// Manage project members
+#[derive(FromArgs)]
+#[argh(subcommand, name = "members")]
+pub struct ProjectMembers {
+	/// operate on project members
+	#[argh(subcommand)]
+	pub cmd: ProjectMembersCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum ProjectMembersCmd {
+	List(ProjectMembersList),
+	Add(ProjectMembersAdd),
+	Remove(ProjectMembersRemove),
+	Update(ProjectMembersUpdate),
+}
+
+/// List direct and inherited members of a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct ProjectMembersList {
+	/// the project to list members of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// Add a member to a project, or invite someone by email
+#[derive(FromArgs)]
+#[argh(subcommand, name = "add")]
+pub struct ProjectMembersAdd {
+	/// the project to add the member to
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// access level: guest, reporter, developer (default), maintainer or owner
+	#[argh(option, short = 'a', default = "\"developer\".to_string()")]
+	pub access: String,
+
+	/// expiration date of the membership (YYYY-MM-DD)
+	#[argh(option, short = 'e')]
+	pub expires: Option<String>,
+
+	/// invite a user by email instead of adding an existing user
+	#[argh(option)]
+	pub invite: Option<String>,
+
+	/// the username of the new member
+	#[argh(positional)]
+	pub username: Option<String>,
+}
+
+/// Remove a member from a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "remove")]
+pub struct ProjectMembersRemove {
+	/// the project to remove the member from
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the username of the member
+	#[argh(positional)]
+	pub username: String,
+}
+
+/// Change the access level or expiration date of a project member
+#[derive(FromArgs)]
+#[argh(subcommand, name = "update")]
+pub struct ProjectMembersUpdate {
+	/// the project of the member
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// access level: guest, reporter, developer, maintainer or owner
+	#[argh(option, short = 'a')]
+	pub access: String,
+
+	/// expiration date of the membership (YYYY-MM-DD)
+	#[argh(option, short = 'e')]
+	pub expires: Option<String>,
+
+	/// the username of the member
+	#[argh(positional)]
+	pub username: String,
+}
+
+/// Manage the fork relationship of a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "fork-relation")]
+pub struct ProjectForkRelation {
+	/// operate on the fork relationship
+	#[argh(subcommand)]
+	pub cmd: ProjectForkRelationCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum ProjectForkRelationCmd {
+	Create(ProjectForkRelationCreate),
+	Delete(ProjectForkRelationDelete),
+}
+
+/// Mark a project as a fork of another project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "create")]
+pub struct ProjectForkRelationCreate {
+	/// the forked project
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the project it is forked from
+	#[argh(positional)]
+	pub source: String,
+}
+
+/// Remove the fork relationship of a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "delete")]
+pub struct ProjectForkRelationDelete {
+	/// the forked project
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// Set or fetch the project avatar
+#[derive(FromArgs)]
+#[argh(subcommand, name = "avatar")]
+pub struct ProjectAvatar {
+	/// operate on the avatar
+	#[argh(subcommand)]
+	pub cmd: ProjectAvatarCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum ProjectAvatarCmd {
+	Set(ProjectAvatarSet),
+	Get(ProjectAvatarGet),
+}
+
+/// Upload a new project avatar
+#[derive(FromArgs)]
+#[argh(subcommand, name = "set")]
+pub struct ProjectAvatarSet {
+	/// the project to change the avatar of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// image file
+	#[argh(positional)]
+	pub file: String,
+}
+
+/// Download the project avatar
+#[derive(FromArgs)]
+#[argh(subcommand, name = "get")]
+pub struct ProjectAvatarGet {
+	/// the project to get the avatar of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// path of the downloaded image: name of the avatar file by default
+	#[argh(option, short = 'o')]
+	pub output: Option<String>,
+}
+
+/// Manage project topics
+#[derive(FromArgs)]
+#[argh(subcommand, name = "topics")]
+pub struct ProjectTopics {
+	/// operate on topics
+	#[argh(subcommand)]
+	pub cmd: ProjectTopicsCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum ProjectTopicsCmd {
+	List(ProjectTopicsList),
+	Add(ProjectTopicsAdd),
+	Remove(ProjectTopicsRemove),
+}
+
+/// List project topics
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct ProjectTopicsList {
+	/// the project to list topics of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// Add topics to a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "add")]
+pub struct ProjectTopicsAdd {
+	/// the project to add topics to
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// topics to add
+	#[argh(positional)]
+	pub topics: Vec<String>,
+}
+
+/// Remove topics from a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "remove")]
+pub struct ProjectTopicsRemove {
+	/// the project to remove topics from
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// topics to remove
+	#[argh(positional)]
+	pub topics: Vec<String>,
+}
+
+/// Configure repository mirroring
+#[derive(FromArgs)]
+#[argh(subcommand, name = "mirror")]
+pub struct ProjectMirror {
+	/// operate on mirrors
+	#[argh(subcommand)]
+	pub cmd: ProjectMirrorCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum ProjectMirrorCmd {
+	Set(ProjectMirrorSet),
+	Status(ProjectMirrorStatus),
+}
+
+/// Add or update a push mirror, or set the pull mirror
+#[derive(FromArgs)]
+#[argh(subcommand, name = "set")]
+pub struct ProjectMirrorSet {
+	/// the project to mirror
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// git url of the mirror (including credentials if needed)
+	#[argh(option)]
+	pub url: String,
+
+	/// mirror direction: push (default) or pull
+	#[argh(option, short = 'd', default = "\"push\".to_string()")]
+	pub direction: String,
+
+	/// don't overwrite refs that diverged on the mirror (push mirror only)
+	#[argh(switch, short = 'k')]
+	pub keep_divergent_refs: bool,
+
+	/// only mirror protected branches
+	#[argh(switch)]
+	pub only_protected_branches: bool,
+}
+
+/// Show mirroring status
+#[derive(FromArgs)]
+#[argh(subcommand, name = "status")]
+pub struct ProjectMirrorStatus {
+	/// the project to show mirrors of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// Get or set project settings
+#[derive(FromArgs)]
+#[argh(subcommand, name = "settings")]
+pub struct ProjectSettings {
+	/// operate on settings
+	#[argh(subcommand)]
+	pub cmd: ProjectSettingsCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum ProjectSettingsCmd {
+	Get(ProjectSettingsGet),
+	Set(ProjectSettingsSet),
+	Export(ProjectSettingsExport),
+	Apply(ProjectSettingsApply),
+}
+
+/// Dump project settings
+#[derive(FromArgs)]
+#[argh(subcommand, name = "get")]
+pub struct ProjectSettingsGet {
+	/// the project to get settings from
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// output format: yaml (default) or json
+	#[argh(option, short = 'f', default = "Format::Yaml")]
+	pub format: Format,
+
+	/// settings to show (all by default)
+	#[argh(positional)]
+	pub keys: Vec<String>,
+}
+
+/// Change project settings
+#[derive(FromArgs)]
+#[argh(subcommand, name = "set")]
+pub struct ProjectSettingsSet {
+	/// the project to change settings on
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// settings to change (key=value)
+	#[argh(positional)]
+	pub settings: Vec<String>,
+}
+
+/// Export project settings, approvals, protections and variables references as a document
+#[derive(FromArgs)]
+#[argh(subcommand, name = "export")]
+pub struct ProjectSettingsExport {
+	/// the project to export settings from
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// output format: yaml (default) or json
+	#[argh(option, short = 'f', default = "Format::Yaml")]
+	pub format: Format,
+}
+
+/// Reconcile projects with a settings document
+#[derive(FromArgs)]
+#[argh(subcommand, name = "apply")]
+pub struct ProjectSettingsApply {
+	/// the project to apply settings to
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// apply settings to every project of that group (and its subgroups)
+	#[argh(option, short = 'g')]
+	pub group: Option<String>,
+
+	/// settings document (yaml or json)
+	#[argh(option, short = 'f')]
+	pub file: String,
+
+	/// only show the changes without applying them
+	#[argh(switch, short = 'n')]
+	pub dry_run: bool,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum TodoCmd {
+	List(TodoList),
+	Done(TodoDone),
+}
+
+/// List pending todos
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct TodoList {
+	/// only show todos related to that project
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// target type: Issue, MergeRequest, Commit, Epic, DesignManagement::Design or AlertManagement::Alert
+	#[argh(option, short = 't')]
+	pub type_: Option<String>,
+
+	/// limit
+	#[argh(option, short = 'l', default = "20")]
+	pub limit: usize,
+}
+
+/// Mark todo(s) as done
+#[derive(FromArgs)]
+#[argh(subcommand, name = "done")]
+pub struct TodoDone {
+	/// mark all pending todos as done
+	#[argh(switch, short = 'a')]
+	pub all: bool,
+
+	/// the todo id
+	#[argh(positional)]
+	pub id: Option<u64>,
+}
+
+/// Manage todos
+#[derive(FromArgs)]
+#[argh(subcommand, name = "todo")]
+pub struct Todo {
+	/// operate on todos
+	#[argh(subcommand)]
+	pub cmd: TodoCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum IssueCmd {
+	Export(IssueExport),
+	Import(IssueImport),
+	Templates(IssueTemplates),
+	Create(IssueCreate),
+	ClosedBy(IssueClosedBy),
+}
+
+/// Export project issues
+#[derive(FromArgs)]
+#[argh(subcommand, name = "export")]
+pub struct IssueExport {
+	/// the project to export issues from
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// issue state: opened (default), closed or all
+	#[argh(option, short = 's', default = "\"opened\".to_string()")]
+	pub state: String,
+
+	/// output format: csv (default), json or yaml
+	#[argh(option, short = 'f', default = "Format::Csv")]
+	pub format: Format,
+
+	/// comma separated list of columns to export
+	#[argh(
+		option,
+		short = 'c',
+		default = "\"iid,title,state,author,assignees,labels,milestone,created_at,updated_at,closed_at,web_url\".to_string()"
+	)]
+	pub columns: String,
+}
+
+/// Create issues in bulk from a yaml or csv file
+#[derive(FromArgs)]
+#[argh(subcommand, name = "import")]
+pub struct IssueImport {
+	/// the project to create issues in
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// yaml or csv file (with title, description, labels and assignees columns) containing the issues to create
+	#[argh(option, short = 'f')]
+	pub file: String,
+
+	/// only show the issues that would be created
+	#[argh(switch, short = 'n')]
+	pub dry_run: bool,
+
+	/// delay in milliseconds between two issue creations
+	#[argh(option, short = 'd', default = "200")]
+	pub delay: u64,
+}
+
+/// List issue description templates
+#[derive(FromArgs)]
+#[argh(subcommand, name = "templates")]
+pub struct IssueTemplates {
+	/// the project to list templates from
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// Create an issue
+#[derive(FromArgs)]
+#[argh(subcommand, name = "create")]
+pub struct IssueCreate {
+	/// the project to create the issue in
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// name of the template used to pre-fill the description
+	#[argh(option, short = 't')]
+	pub template: Option<String>,
+
+	/// issue description
+	#[argh(option, short = 'd')]
+	pub description: Option<String>,
+
+	/// comma separated list of labels
+	#[argh(option, short = 'l')]
+	pub labels: Option<String>,
+
+	/// issue title
+	#[argh(positional)]
+	pub title: String,
+}
+
+/// List the issues a merge request closes when merged
+#[derive(FromArgs)]
+#[argh(subcommand, name = "closed-by")]
+pub struct IssueClosedBy {
+	/// the project of the merge request
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// merge request iid (the one of the current branch by default)
+	#[argh(positional)]
+	pub mr: Option<u64>,
+}
+
+/// Manage project issues
+#[derive(FromArgs)]
+#[argh(subcommand, name = "issue")]
+pub struct Issue {
+	/// operate on issues
+	#[argh(subcommand)]
+	pub cmd: IssueCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum MrCmd {
+	Status(MrStatus),
+}
+
+/// Show a merge request with its pipeline
+#[derive(FromArgs)]
+#[argh(subcommand, name = "status")]
+pub struct MrStatus {
+	/// the project of the merge request
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// merge request iid (the one of the current branch by default)
+	#[argh(positional)]
+	pub iid: Option<u64>,
+}
+
+/// Manage merge requests
+#[derive(FromArgs)]
+#[argh(subcommand, name = "mr")]
+pub struct Mr {
+	/// operate on merge requests
+	#[argh(subcommand)]
+	pub cmd: MrCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum GroupCmd {
+	Tree(GroupTree),
+	Create(GroupCreate),
+	Edit(GroupEdit),
+	Delete(GroupDelete),
+	Members(GroupMembers),
+	Clone(GroupClone),
+	Tokens(GroupTokens),
+}
+
+/// Show subgroups and projects of a group as a tree
+#[derive(FromArgs)]
+#[argh(subcommand, name = "tree")]
+pub struct GroupTree {
+	/// the group path
+	#[argh(positional)]
+	pub group: String,
+}
+
+/// Create a group
+#[derive(FromArgs)]
+#[argh(subcommand, name = "create")]
+pub struct GroupCreate {
+	/// the parent group path (create a subgroup)
+	#[argh(option)]
+	pub parent: Option<String>,
+
+	/// the group name (default to the path)
+	#[argh(option, short = 'n')]
+	pub name: Option<String>,
+
+	/// visibility: private (default), internal or public
+	#[argh(option, short = 'V', default = "\"private\".to_string()")]
+	pub visibility: String,
+
+	/// description
+	#[argh(option, short = 'd')]
+	pub description: Option<String>,
+
+	/// the group path
+	#[argh(positional)]
+	pub path: String,
+}
+
+/// Edit a group
+#[derive(FromArgs)]
+#[argh(subcommand, name = "edit")]
+pub struct GroupEdit {
+	/// description
+	#[argh(option, short = 'd')]
+	pub description: Option<String>,
+
+	/// default branch protection: none, partial, full or push
+	#[argh(option, short = 'b')]
+	pub branch_protection: Option<String>,
+
+	/// visibility: private, internal or public
+	#[argh(option, short = 'V')]
+	pub visibility: Option<String>,
+
+	/// the group path
+	#[argh(positional)]
+	pub group: String,
+}
+
+/// Delete a group
+#[derive(FromArgs)]
+#[argh(subcommand, name = "delete")]
+pub struct GroupDelete {
+	/// don't ask for confirmation (needs --i-know-what-i-am-doing)
+	#[argh(switch)]
+	pub yes: bool,
+
+	/// acknowledge that the group will be deleted without confirmation
+	#[argh(switch)]
+	pub i_know_what_i_am_doing: bool,
+
+	/// the group path
+	#[argh(positional)]
+	pub group: String,
+}
+
+/// Clone every project of a group
+#[derive(FromArgs)]
+#[argh(subcommand, name = "clone")]
+pub struct GroupClone {
+	/// destination directory (default to the group path)
+	#[argh(option, short = 'd')]
+	pub dest: Option<String>,
+
+	/// create shallow clones with the given history depth
+	#[argh(option)]
+	pub depth: Option<u32>,
+
+	/// only clone projects whose relative path matches the glob (can be repeated)
+	#[argh(option, short = 'i')]
+	pub include: Vec<String>,
+
+	/// don't clone projects whose relative path matches the glob (can be repeated)
+	#[argh(option, short = 'e')]
+	pub exclude: Vec<String>,
+
+	/// also clone projects of subgroups
+	#[argh(switch, short = 'r')]
+	pub recursive: bool,
+
+	/// pull projects that are already cloned
+	#[argh(switch, short = 'U')]
+	pub update: bool,
+
+	/// clone with https instead of ssh
+	#[argh(switch)]
+	pub https: bool,
+
+	/// number of parallel clones
+	#[argh(option, short = 'j', default = "4")]
+	pub jobs: usize,
+
+	/// the group path
+	#[argh(positional)]
+	pub group: String,
+}
+
+/// Manage group access tokens
+#[derive(FromArgs)]
+#[argh(subcommand, name = "tokens")]
+pub struct GroupTokens {
+	/// operate on access tokens
+	#[argh(subcommand)]
+	pub cmd: GroupTokensCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum GroupTokensCmd {
+	List(GroupTokensList),
+	Create(GroupTokensCreate),
+	Revoke(GroupTokensRevoke),
+}
+
+/// List group access tokens
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct GroupTokensList {
+	/// also list revoked and expired tokens
+	#[argh(switch, short = 'a')]
+	pub all: bool,
+
+	/// the group path
+	#[argh(positional)]
+	pub group: String,
+}
+
+/// Create a group access token, printing it once
+#[derive(FromArgs)]
+#[argh(subcommand, name = "create")]
+pub struct GroupTokensCreate {
+	/// a scope of the token (api by default)
+	#[argh(option, short = 's')]
+	pub scopes: Vec<String>,
+
+	/// access level: guest, reporter, developer, maintainer (default) or owner
+	#[argh(option, short = 'a', default = "\"maintainer\".to_string()")]
+	pub access: String,
+
+	/// the expiration date of the token (YYYY-MM-DD)
+	#[argh(option, short = 'e')]
+	pub expires: Option<String>,
+
+	/// the group path
+	#[argh(positional)]
+	pub group: String,
+
+	/// the name of the token
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Revoke a group access token
+#[derive(FromArgs)]
+#[argh(subcommand, name = "revoke")]
+pub struct GroupTokensRevoke {
+	/// the group path
+	#[argh(positional)]
+	pub group: String,
+
+	/// the id of the token
+	#[argh(positional)]
+	pub id: u64,
+}
+
+/// Manage group members
+#[derive(FromArgs)]
+#[argh(subcommand, name = "members")]
+pub struct GroupMembers {
+	/// operate on group members
+	#[argh(subcommand)]
+	pub cmd: GroupMembersCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum GroupMembersCmd {
+	List(GroupMembersList),
+	Add(GroupMembersAdd),
+	Remove(GroupMembersRemove),
+	Update(GroupMembersUpdate),
+}
+
+/// List direct and inherited members of a group
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct GroupMembersList {
+	/// the group path
+	#[argh(positional)]
+	pub group: String,
+}
+
+/// Add a member to a group
+#[derive(FromArgs)]
+#[argh(subcommand, name = "add")]
+pub struct GroupMembersAdd {
+	/// access level: guest, reporter, developer (default), maintainer or owner
+	#[argh(option, short = 'a', default = "\"developer\".to_string()")]
+	pub access: String,
+
+	/// expiration date of the membership (YYYY-MM-DD)
+	#[argh(option, short = 'e')]
+	pub expires: Option<String>,
+
+	/// the group path
+	#[argh(positional)]
+	pub group: String,
+
+	/// the username of the new member
+	#[argh(positional)]
+	pub username: String,
+}
+
+/// Remove a member from a group
+#[derive(FromArgs)]
+#[argh(subcommand, name = "remove")]
+pub struct GroupMembersRemove {
+	/// the group path
+	#[argh(positional)]
+	pub group: String,
+
+	/// the username of the member
+	#[argh(positional)]
+	pub username: String,
+}
+
+/// Change the access level or expiration date of a group member
+#[derive(FromArgs)]
+#[argh(subcommand, name = "update")]
+pub struct GroupMembersUpdate {
+	/// access level: guest, reporter, developer, maintainer or owner
+	#[argh(option, short = 'a')]
+	pub access: String,
+
+	/// expiration date of the membership (YYYY-MM-DD)
+	#[argh(option, short = 'e')]
+	pub expires: Option<String>,
+
+	/// the group path
+	#[argh(positional)]
+	pub group: String,
+
+	/// the username of the member
+	#[argh(positional)]
+	pub username: String,
+}
+
+/// Manage groups
+#[derive(FromArgs)]
+#[argh(subcommand, name = "group")]
+pub struct Group {
+	/// operate on groups
+	#[argh(subcommand)]
+	pub cmd: GroupCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum BadgeCmd {
+	List(BadgeList),
+	Add(BadgeAdd),
+	Remove(BadgeRemove),
+}
+
+/// List project badges
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct BadgeList {
+	/// the project to list badges from
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// Add a badge to a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "add")]
+pub struct BadgeAdd {
+	/// the project to add the badge to
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// name of the badge
+	#[argh(option, short = 'n')]
+	pub name: Option<String>,
+
+	/// link url (gitlab badge placeholders are allowed)
+	#[argh(positional)]
+	pub link_url: String,
+
+	/// image url (gitlab badge placeholders are allowed)
+	#[argh(positional)]
+	pub image_url: String,
+}
+
+/// Remove a badge from a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "remove")]
+pub struct BadgeRemove {
+	/// the project to remove the badge from
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the badge id
+	#[argh(positional)]
+	pub id: u64,
+}
+
+/// Manage project badges
+#[derive(FromArgs)]
+#[argh(subcommand, name = "badge")]
+pub struct Badge {
+	/// operate on badges
+	#[argh(subcommand)]
+	pub cmd: BadgeCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum AccessRequestsCmd {
+	List(AccessRequestsList),
+	Approve(AccessRequestsApprove),
+	Deny(AccessRequestsDeny),
+}
+
+/// List pending access requests
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct AccessRequestsList {
+	/// the project to list access requests of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the group to list access requests of (instead of a project)
+	#[argh(option, short = 'g')]
+	pub group: Option<String>,
+}
+
+/// Approve an access request
+#[derive(FromArgs)]
+#[argh(subcommand, name = "approve")]
+pub struct AccessRequestsApprove {
+	/// the project of the access request
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the group of the access request (instead of a project)
+	#[argh(option, short = 'g')]
+	pub group: Option<String>,
+
+	/// access level: guest, reporter, developer (default), maintainer or owner
+	#[argh(option, short = 'a')]
+	pub access: Option<String>,
+
+	/// the username of the requester
+	#[argh(positional)]
+	pub username: String,
+}
+
+/// Deny an access request
+#[derive(FromArgs)]
+#[argh(subcommand, name = "deny")]
+pub struct AccessRequestsDeny {
+	/// the project of the access request
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the group of the access request (instead of a project)
+	#[argh(option, short = 'g')]
+	pub group: Option<String>,
+
+	/// the username of the requester
+	#[argh(positional)]
+	pub username: String,
+}
+
+/// Manage access requests of projects and groups
+#[derive(FromArgs)]
+#[argh(subcommand, name = "access-requests")]
+pub struct AccessRequests {
+	/// operate on access requests
+	#[argh(subcommand)]
+	pub cmd: AccessRequestsCmd,
+}
+
+/// Show audit events of projects and groups
+#[derive(FromArgs)]
+#[argh(subcommand, name = "audit")]
+pub struct Audit {
+	/// operate on audit events
+	#[argh(subcommand)]
+	pub cmd: AuditCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum AuditCmd {
+	List(AuditList),
+}
+
+/// List audit events
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct AuditList {
+	/// the project to list audit events of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the group to list audit events of (instead of a project)
+	#[argh(option, short = 'g')]
+	pub group: Option<String>,
+
+	/// only show events created after that date (YYYY-MM-DD)
+	#[argh(option, short = 's')]
+	pub since: Option<String>,
+
+	/// only show events of that user
+	#[argh(option, short = 'a')]
+	pub author: Option<String>,
+
+	/// output format: json or yaml (human readable list by default)
+	#[argh(option, short = 'f')]
+	pub format: Option<Format>,
+}
+
+/// Manage project CI/CD variables
+#[derive(FromArgs)]
+#[argh(subcommand, name = "variables")]
+pub struct Variables {
+	/// operate on variables
+	#[argh(subcommand)]
+	pub cmd: VariablesCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum VariablesCmd {
+	List(VariablesList),
+	Get(VariablesGet),
+	Set(VariablesSet),
+	Delete(VariablesDelete),
+	Export(VariablesExport),
+	Import(VariablesImport),
+}
+
+/// List project variables
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct VariablesList {
+	/// the project to list variables of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// show the values of the variables
+	#[argh(switch, short = 'r')]
+	pub reveal: bool,
+}
+
+/// Show a project variable
+#[derive(FromArgs)]
+#[argh(subcommand, name = "get")]
+pub struct VariablesGet {
+	/// the project of the variable
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// environment scope of the variable (needed when the key is defined in several scopes)
+	#[argh(option, short = 'e')]
+	pub environment_scope: Option<String>,
+
+	/// show the value of the variable
+	#[argh(switch, short = 'r')]
+	pub reveal: bool,
+
+	/// the key of the variable
+	#[argh(positional)]
+	pub key: String,
+}
+
+/// Create or update a project variable
+#[derive(FromArgs)]
+#[argh(subcommand, name = "set")]
+pub struct VariablesSet {
+	/// the project of the variable
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// environment scope of the variable (* by default)
+	#[argh(option, short = 'e', default = "\"*\".to_string()")]
+	pub environment_scope: String,
+
+	/// only expose the variable to protected branches and tags
+	#[argh(switch, short = 'P')]
+	pub protected: bool,
+
+	/// mask the variable in job logs
+	#[argh(switch, short = 'm')]
+	pub masked: bool,
+
+	/// don't expand variable references in the value
+	#[argh(switch, short = 'r')]
+	pub raw: bool,
+
+	/// variable type: env_var (default) or file to expose the value in a file
+	#[argh(option, short = 't', default = "\"env_var\".to_string()")]
+	pub variable_type: String,
+
+	/// the key of the variable
+	#[argh(positional)]
+	pub key: String,
+
+	/// the value of the variable (read from stdin if not given)
+	#[argh(positional)]
+	pub value: Option<String>,
+}
+
+/// Delete a project variable
+#[derive(FromArgs)]
+#[argh(subcommand, name = "delete")]
+pub struct VariablesDelete {
+	/// the project of the variable
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// environment scope of the variable (needed when the key is defined in several scopes)
+	#[argh(option, short = 'e')]
+	pub environment_scope: Option<String>,
+
+	/// the key of the variable
+	#[argh(positional)]
+	pub key: String,
+}
+
+/// Export project variables with their values
+#[derive(FromArgs)]
+#[argh(subcommand, name = "export")]
+pub struct VariablesExport {
+	/// the project to export variables from
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// environment scope of the variables (* by default)
+	#[argh(option, short = 'e', default = "\"*\".to_string()")]
+	pub environment_scope: String,
+
+	/// output format: dotenv (default), json or yaml
+	#[argh(option, short = 'f', default = "\"dotenv\".to_string()")]
+	pub format: String,
+}
+
+/// Create or update project variables from a dotenv file
+#[derive(FromArgs)]
+#[argh(subcommand, name = "import")]
+pub struct VariablesImport {
+	/// the project to import variables to
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// environment scope of the variables (* by default)
+	#[argh(option, short = 'e', default = "\"*\".to_string()")]
+	pub environment_scope: String,
+
+	/// only expose the variables to protected branches and tags
+	#[argh(switch, short = 'P')]
+	pub protected: bool,
+
+	/// mask the variables in job logs
+	#[argh(switch, short = 'm')]
+	pub masked: bool,
+
+	/// don't expand variable references in the values
+	#[argh(switch, short = 'r')]
+	pub raw: bool,
+
+	/// variable type: env_var (default) or file to expose the values in files
+	#[argh(option, short = 't', default = "\"env_var\".to_string()")]
+	pub variable_type: String,
+
+	/// delete the variables of the scope that are not present in the file
+	#[argh(switch, short = 's')]
+	pub sync: bool,
+
+	/// dotenv file containing the variables (KEY=value lines)
+	#[argh(option, short = 'f')]
+	pub file: String,
+}
+
+/// Validate and inspect CI configurations
+#[derive(FromArgs)]
+#[argh(subcommand, name = "ci")]
+pub struct Ci {
+	/// operate on ci configurations
+	#[argh(subcommand)]
+	pub cmd: CiCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum CiCmd {
+	Lint(CiLint),
+	View(CiView),
+	InstallHook(CiInstallHook),
+}
+
+/// Check a CI configuration file
+#[derive(FromArgs)]
+#[argh(subcommand, name = "lint")]
+pub struct CiLint {
+	/// the project to check the configuration against
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// simulate a pipeline creation on that reference (tag or branch)
+	#[argh(option, short = 'r')]
+	pub ref_: Option<String>,
+
+	/// path of the CI configuration file (.gitlab-ci.yml by default)
+	#[argh(positional, default = "\".gitlab-ci.yml\".to_string()")]
+	pub path: String,
+}
+
+/// Show a CI configuration file
+#[derive(FromArgs)]
+#[argh(subcommand, name = "view")]
+pub struct CiView {
+	/// the project to expand the configuration against
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// show the configuration with all includes and extends resolved
+	#[argh(switch, short = 'm')]
+	pub merged: bool,
+
+	/// show the differences between the file and the merged configuration
+	#[argh(switch, short = 'd')]
+	pub diff: bool,
+
+	/// path of the CI configuration file (.gitlab-ci.yml by default)
+	#[argh(positional, default = "\".gitlab-ci.yml\".to_string()")]
+	pub path: String,
+}
+
+/// Install a git pre-push hook checking the CI configuration
+#[derive(FromArgs)]
+#[argh(subcommand, name = "install-hook")]
+pub struct CiInstallHook {
+	/// other glctl commands to run before pushing (ex: "tags list")
+	#[argh(option, short = 'c')]
+	pub command: Vec<String>,
+
+	/// overwrite an existing pre-push hook
+	#[argh(switch, short = 'f')]
+	pub force: bool,
+
+	/// path of the CI configuration file (.gitlab-ci.yml by default)
+	#[argh(positional, default = "\".gitlab-ci.yml\".to_string()")]
+	pub path: String,
+}
+
+/// Manage project environments
+#[derive(FromArgs)]
+#[argh(subcommand, name = "env")]
+pub struct Env {
+	/// operate on environments
+	#[argh(subcommand)]
+	pub cmd: EnvCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum EnvCmd {
+	List(EnvList),
+	Show(EnvShow),
+	Stop(EnvStop),
+	Delete(EnvDelete),
+	Protect(EnvProtect),
+	Unprotect(EnvUnprotect),
+}
+
+/// List project environments with their last deployment
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct EnvList {
+	/// the project to list environments of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// state: available, stopping or stopped (all by default)
+	#[argh(option, short = 's')]
+	pub state: Option<String>,
+
+	/// only list review apps (environments under review/)
+	#[argh(switch, short = 'r')]
+	pub review_apps: bool,
+}
+
+/// Show an environment and its last deployment
+#[derive(FromArgs)]
+#[argh(subcommand, name = "show")]
+pub struct EnvShow {
+	/// the project of the environment
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the name of the environment
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Stop an environment
+#[derive(FromArgs)]
+#[argh(subcommand, name = "stop")]
+pub struct EnvStop {
+	/// the project of the environment
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the name of the environment
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Delete a stopped environment or stale review apps
+#[derive(FromArgs)]
+#[argh(subcommand, name = "delete")]
+pub struct EnvDelete {
+	/// the project of the environment
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// delete the stopped review apps instead of a single environment
+	#[argh(switch, short = 'r')]
+	pub review_apps: bool,
+
+	/// only delete review apps last updated before that date (YYYY-MM-DD)
+	#[argh(option, short = 'b')]
+	pub before: Option<String>,
+
+	/// only show the review apps that would be deleted
+	#[argh(switch, short = 'n')]
+	pub dry_run: bool,
+
+	/// the name of the environment
+	#[argh(positional)]
+	pub name: Option<String>,
+}
+
+/// Protect an environment so that only some users can deploy to it
+#[derive(FromArgs)]
+#[argh(subcommand, name = "protect")]
+pub struct EnvProtect {
+	/// the project of the environment
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// who can deploy: developer, maintainer, admin or a group path (repeatable)
+	#[argh(option, short = 'a')]
+	pub deploy_access: Vec<String>,
+
+	/// the name of the environment
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Unprotect an environment
+#[derive(FromArgs)]
+#[argh(subcommand, name = "unprotect")]
+pub struct EnvUnprotect {
+	/// the project of the environment
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the name of the environment
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Manage deploy freeze periods
+#[derive(FromArgs)]
+#[argh(subcommand, name = "freeze")]
+pub struct Freeze {
+	/// operate on freeze periods
+	#[argh(subcommand)]
+	pub cmd: FreezeCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum FreezeCmd {
+	List(FreezeList),
+	Add(FreezeAdd),
+	Remove(FreezeRemove),
+}
+
+/// List deploy freeze periods
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct FreezeList {
+	/// the project to list freeze periods of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// Add a deploy freeze period
+#[derive(FromArgs)]
+#[argh(subcommand, name = "add")]
+pub struct FreezeAdd {
+	/// the project to add a freeze period to
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the start of the freeze period in cron format (ex: "0 23 * * 5")
+	#[argh(option, short = 's')]
+	pub start: String,
+
+	/// the end of the freeze period in cron format (ex: "0 7 * * 1")
+	#[argh(option, short = 'e')]
+	pub end: String,
+
+	/// the timezone of the cron expressions (UTC by default)
+	#[argh(option, short = 't')]
+	pub timezone: Option<String>,
+}
+
+/// Remove a deploy freeze period
+#[derive(FromArgs)]
+#[argh(subcommand, name = "remove")]
+pub struct FreezeRemove {
+	/// the project to remove a freeze period from
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the id of the freeze period
+	#[argh(positional)]
+	pub id: u64,
+}
+
+/// Manage project feature flags
+#[derive(FromArgs)]
+#[argh(subcommand, name = "feature-flags")]
+pub struct FeatureFlags {
+	/// operate on feature flags
+	#[argh(subcommand)]
+	pub cmd: FeatureFlagsCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum FeatureFlagsCmd {
+	List(FeatureFlagsList),
+	Enable(FeatureFlagsEnable),
+	Disable(FeatureFlagsDisable),
+	SetStrategy(FeatureFlagsSetStrategy),
+}
+
+/// List feature flags and their strategies
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct FeatureFlagsList {
+	/// the project to list feature flags of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// scope: enabled or disabled (all by default)
+	#[argh(option, short = 's')]
+	pub scope: Option<String>,
+}
+
+/// Enable a feature flag
+#[derive(FromArgs)]
+#[argh(subcommand, name = "enable")]
+pub struct FeatureFlagsEnable {
+	/// the project of the feature flag
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the name of the feature flag
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Disable a feature flag
+#[derive(FromArgs)]
+#[argh(subcommand, name = "disable")]
+pub struct FeatureFlagsDisable {
+	/// the project of the feature flag
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the name of the feature flag
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Replace the strategies of a feature flag with a single strategy
+#[derive(FromArgs)]
+#[argh(subcommand, name = "set-strategy")]
+pub struct FeatureFlagsSetStrategy {
+	/// the project of the feature flag
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// strategy: default, gradualRolloutUserId, userWithId or flexibleRollout
+	#[argh(option, short = 's')]
+	pub strategy: String,
+
+	/// a parameter of the strategy as key=value (ex: percentage=50)
+	#[argh(option, short = 'P')]
+	pub param: Vec<String>,
+
+	/// environment scope the strategy applies to (repeatable, * by default)
+	#[argh(option, short = 'e')]
+	pub environment_scope: Vec<String>,
+
+	/// the name of the feature flag
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Manage the projects allowed to access a project with their CI job token
+#[derive(FromArgs)]
+#[argh(subcommand, name = "job-token-allowlist")]
+pub struct JobTokenAllowlist {
+	/// operate on the job token allowlist
+	#[argh(subcommand)]
+	pub cmd: JobTokenAllowlistCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum JobTokenAllowlistCmd {
+	List(JobTokenAllowlistList),
+	Add(JobTokenAllowlistAdd),
+	Remove(JobTokenAllowlistRemove),
+}
+
+/// List the projects allowed to access a project with their CI job token
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct JobTokenAllowlistList {
+	/// the project to list the allowlist of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// Allow a project to access a project with its CI job token
+#[derive(FromArgs)]
+#[argh(subcommand, name = "add")]
+pub struct JobTokenAllowlistAdd {
+	/// the project to change the allowlist of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the project to allow
+	#[argh(positional)]
+	pub target: String,
+}
+
+/// Remove a project from the CI job token allowlist of a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "remove")]
+pub struct JobTokenAllowlistRemove {
+	/// the project to change the allowlist of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the project to remove
+	#[argh(positional)]
+	pub target: String,
+}
+
+/// Manage project secure files
+#[derive(FromArgs)]
+#[argh(subcommand, name = "secure-files")]
+pub struct SecureFiles {
+	/// operate on secure files
+	#[argh(subcommand)]
+	pub cmd: SecureFilesCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum SecureFilesCmd {
+	List(SecureFilesList),
+	Upload(SecureFilesUpload),
+	Download(SecureFilesDownload),
+	Delete(SecureFilesDelete),
+}
+
+/// List secure files
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct SecureFilesList {
+	/// the project to list secure files of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// Upload a secure file
+#[derive(FromArgs)]
+#[argh(subcommand, name = "upload")]
+pub struct SecureFilesUpload {
+	/// the project to upload the secure file to
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the name of the secure file (file name by default)
+	#[argh(option, short = 'n')]
+	pub name: Option<String>,
+
+	/// the file to upload
+	#[argh(positional)]
+	pub file: String,
+}
+
+/// Download a secure file
+#[derive(FromArgs)]
+#[argh(subcommand, name = "download")]
+pub struct SecureFilesDownload {
+	/// the project of the secure file
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// where to save the secure file (its name by default)
+	#[argh(option, short = 'o')]
+	pub output: Option<String>,
+
+	/// the name of the secure file
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Delete a secure file
+#[derive(FromArgs)]
+#[argh(subcommand, name = "delete")]
+pub struct SecureFilesDelete {
+	/// the project of the secure file
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the name of the secure file
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Manage runners
+#[derive(FromArgs)]
+#[argh(subcommand, name = "runner")]
+pub struct Runner {
+	/// operate on runners
+	#[argh(subcommand)]
+	pub cmd: RunnerCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum RunnerCmd {
+	List(RunnerList),
+	Pause(RunnerPause),
+	Resume(RunnerResume),
+	Delete(RunnerDelete),
+	Create(RunnerCreate),
+	Jobs(RunnerJobs),
+}
+
+/// List runners of a project, a group or the whole instance
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct RunnerList {
+	/// the project to list runners of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// list runners of a group instead of a project
+	#[argh(option, short = 'g')]
+	pub group: Option<String>,
+
+	/// list all the runners of the instance (administrators only)
+	#[argh(switch, short = 'a')]
+	pub all: bool,
+
+	/// status: online, offline, stale or never_contacted (all by default)
+	#[argh(option, short = 's')]
+	pub status: Option<String>,
+}
+
+/// Pause a runner so that it doesn't pick up new jobs
+#[derive(FromArgs)]
+#[argh(subcommand, name = "pause")]
+pub struct RunnerPause {
+	/// the id of the runner
+	#[argh(positional)]
+	pub id: u64,
+}
+
+/// Resume a paused runner
+#[derive(FromArgs)]
+#[argh(subcommand, name = "resume")]
+pub struct RunnerResume {
+	/// the id of the runner
+	#[argh(positional)]
+	pub id: u64,
+}
+
+/// Delete a runner
+#[derive(FromArgs)]
+#[argh(subcommand, name = "delete")]
+pub struct RunnerDelete {
+	/// the id of the runner
+	#[argh(positional)]
+	pub id: u64,
+}
+
+/// Create a runner and print its authentication token to register it
+#[derive(FromArgs)]
+#[argh(subcommand, name = "create")]
+pub struct RunnerCreate {
+	/// the project to create a runner for
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// create a runner for a group instead of a project
+	#[argh(option, short = 'g')]
+	pub group: Option<String>,
+
+	/// create an instance runner (administrators only)
+	#[argh(switch, short = 'i')]
+	pub instance: bool,
+
+	/// the description of the runner
+	#[argh(option, short = 'd')]
+	pub description: Option<String>,
+
+	/// tags of the runner (repeatable or comma separated)
+	#[argh(option, short = 't')]
+	pub tags: Vec<String>,
+
+	/// pick up jobs without tags
+	#[argh(switch, short = 'u')]
+	pub run_untagged: bool,
+
+	/// lock the runner to the current project
+	#[argh(switch, short = 'l')]
+	pub locked: bool,
+
+	/// create the runner paused
+	#[argh(switch)]
+	pub paused: bool,
+}
+
+/// List the last jobs processed by a runner
+#[derive(FromArgs)]
+#[argh(subcommand, name = "jobs")]
+pub struct RunnerJobs {
+	/// status: running, success, failed or canceled (all by default)
+	#[argh(option, short = 's')]
+	pub status: Option<String>,
+
+	/// limit
+	#[argh(option, short = 'l', default = "20")]
+	pub limit: usize,
+
+	/// the id of the runner
+	#[argh(positional)]
+	pub id: u64,
+}
+
+/// Browse the container registry of a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "registry")]
+pub struct Registry {
+	/// operate on the container registry
+	#[argh(subcommand)]
+	pub cmd: RegistryCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum RegistryCmd {
+	List(RegistryList),
+	Tags(RegistryTags),
+	Cleanup(RegistryCleanup),
+	Policy(RegistryPolicy),
+}
+
+/// List the container registry repositories of a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct RegistryList {
+	/// the project to list registry repositories of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// List the tags of a container registry repository
+#[derive(FromArgs)]
+#[argh(subcommand, name = "tags")]
+pub struct RegistryTags {
+	/// the project of the repository
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// sort by creation date: desc (default) or asc
+	#[argh(option, short = 's', default = "\"desc\".to_string()")]
+	pub sort: String,
+
+	/// limit
+	#[argh(option, short = 'l', default = "20")]
+	pub limit: usize,
+
+	/// the repository name, path or id (the project root repository by default)
+	#[argh(positional)]
+	pub repository: Option<String>,
+}
+
+/// Delete the tags of a container registry repository in bulk
+#[derive(FromArgs)]
+#[argh(subcommand, name = "cleanup")]
+pub struct RegistryCleanup {
+	/// the project of the repository
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// delete the tags whose name matches this regex (all by default)
+	#[argh(option, short = 'r', default = "\".*\".to_string()")]
+	pub name_regex: String,
+
+	/// keep the tags whose name matches this regex
+	#[argh(option, short = 'K')]
+	pub keep_regex: Option<String>,
+
+	/// keep the n latest matching tags
+	#[argh(option, short = 'k')]
+	pub keep_n: Option<u64>,
+
+	/// only delete tags older than this age (ex: 12h, 30d, 2w, 3month)
+	#[argh(option, short = 'o')]
+	pub older_than: Option<String>,
+
+	/// only show the tags that would be deleted
+	#[argh(switch, short = 'n')]
+	pub dry_run: bool,
+
+	/// the repository name, path or id (the project root repository by default)
+	#[argh(positional)]
+	pub repository: Option<String>,
+}
+
+/// Browse and download project packages
+#[derive(FromArgs)]
+#[argh(subcommand, name = "package")]
+pub struct Package {
+	/// operate on packages
+	#[argh(subcommand)]
+	pub cmd: PackageCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum PackageCmd {
+	List(PackageList),
+	Show(PackageShow),
+	Download(PackageDownload),
+	Publish(PackagePublish),
+	Delete(PackageDelete),
+	Prune(PackagePrune),
+}
+
+/// List project packages, most recent first
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct PackageList {
+	/// the project to list packages of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// package type: generic, maven, npm, pypi, conan, nuget, helm...
+	#[argh(option, short = 't', long = "type")]
+	pub package_type: Option<String>,
+
+	/// only list packages whose name contains this string
+	#[argh(option, short = 'n')]
+	pub name: Option<String>,
+}
+
+/// Show a package and its files
+#[derive(FromArgs)]
+#[argh(subcommand, name = "show")]
+pub struct PackageShow {
+	/// the project of the package
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the id of the package
+	#[argh(positional)]
+	pub id: u64,
+}
+
+/// Download all the files of a package
+#[derive(FromArgs)]
+#[argh(subcommand, name = "download")]
+pub struct PackageDownload {
+	/// the project of the package
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the directory to save the files to (current directory by default)
+	#[argh(option, short = 'd', default = "\".\".to_string()")]
+	pub dest: String,
+
+	/// write the sha256 of the downloaded files to this file (sha256sum format)
+	#[argh(option, short = 'c')]
+	pub checksum_file: Option<String>,
+
+	/// the id of the package
+	#[argh(positional)]
+	pub id: u64,
+}
+
+/// Publish files to the generic package registry
+#[derive(FromArgs)]
+#[argh(subcommand, name = "publish")]
+pub struct PackagePublish {
+	/// the project to publish the package to
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the name of the package
+	#[argh(option, short = 'n')]
+	pub name: String,
+
+	/// the version of the package
+	#[argh(option, short = 'v')]
+	pub version: String,
+
+	/// the files to upload
+	#[argh(positional)]
+	pub files: Vec<String>,
+}
+
+/// Delete a package
+#[derive(FromArgs)]
+#[argh(subcommand, name = "delete")]
+pub struct PackageDelete {
+	/// the project of the package
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the id of the package
+	#[argh(positional)]
+	pub id: u64,
+}
+
+/// Delete old versions of each package
+#[derive(FromArgs)]
+#[argh(subcommand, name = "prune")]
+pub struct PackagePrune {
+	/// the project to prune packages of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// only prune packages of this type
+	#[argh(option, short = 't', long = "type")]
+	pub package_type: Option<String>,
+
+	/// only prune packages whose name contains this string
+	#[argh(option, short = 'n')]
+	pub name: Option<String>,
+
+	/// keep the n latest versions of each package
+	#[argh(option, short = 'k')]
+	pub keep_n: Option<usize>,
+
+	/// only delete versions older than this age (ex: 12h, 30d, 2w, 3month)
+	#[argh(option, short = 'o')]
+	pub older_than: Option<String>,
+
+	/// only show the versions that would be deleted
+	#[argh(switch, short = 'N')]
+	pub dry_run: bool,
+}
+
+/// Inspect and purge the dependency proxy of a group
+#[derive(FromArgs)]
+#[argh(subcommand, name = "dep-proxy")]
+pub struct DepProxy {
+	/// operate on the dependency proxy
+	#[argh(subcommand)]
+	pub cmd: DepProxyCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum DepProxyCmd {
+	Status(DepProxyStatus),
+	Purge(DepProxyPurge),
+}
+
+/// Show the dependency proxy cache size of a group
+#[derive(FromArgs)]
+#[argh(subcommand, name = "status")]
+pub struct DepProxyStatus {
+	/// the group
+	#[argh(positional)]
+	pub group: String,
+}
+
+/// Purge the dependency proxy cache of a group
+#[derive(FromArgs)]
+#[argh(subcommand, name = "purge")]
+pub struct DepProxyPurge {
+	/// the group
+	#[argh(positional)]
+	pub group: String,
+}
+
+/// Manage GitLab-managed terraform states
+#[derive(FromArgs)]
+#[argh(subcommand, name = "tf-state")]
+pub struct TfState {
+	/// operate on terraform states
+	#[argh(subcommand)]
+	pub cmd: TfStateCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum TfStateCmd {
+	List(TfStateList),
+	Download(TfStateDownload),
+	Lock(TfStateLock),
+	Unlock(TfStateUnlock),
+	Delete(TfStateDelete),
+}
+
+/// List the terraform states of a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct TfStateList {
+	/// the project to list terraform states of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+}
+
+/// Download the latest version of a terraform state
+#[derive(FromArgs)]
+#[argh(subcommand, name = "download")]
+pub struct TfStateDownload {
+	/// the project of the state
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// where to save the state (<name>.tfstate by default)
+	#[argh(option, short = 'o')]
+	pub output: Option<String>,
+
+	/// the name of the state
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Lock a terraform state
+#[derive(FromArgs)]
+#[argh(subcommand, name = "lock")]
+pub struct TfStateLock {
+	/// the project of the state
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the name of the state
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Unlock a terraform state, even if locked by someone else
+#[derive(FromArgs)]
+#[argh(subcommand, name = "unlock")]
+pub struct TfStateUnlock {
+	/// the project of the state
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the name of the state
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Delete a terraform state and all its versions
+#[derive(FromArgs)]
+#[argh(subcommand, name = "delete")]
+pub struct TfStateDelete {
+	/// the project of the state
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the name of the state
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Manage GitLab Pages
+#[derive(FromArgs)]
+#[argh(subcommand, name = "pages")]
+pub struct Pages {
+	/// operate on pages
+	#[argh(subcommand)]
+	pub cmd: PagesCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum PagesCmd {
+	Domains(PagesDomains),
+}
+
+/// Manage custom pages domains
+#[derive(FromArgs)]
+#[argh(subcommand, name = "domains")]
+pub struct PagesDomains {
+	/// operate on pages domains
+	#[argh(subcommand)]
+	pub cmd: PagesDomainsCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum PagesDomainsCmd {
+	List(PagesDomainsList),
+	Add(PagesDomainsAdd),
+	Remove(PagesDomainsRemove),
+	Verify(PagesDomainsVerify),
+}
+
+/// List pages domains with their verification and certificate status
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct PagesDomainsList {
+	/// the project to list domains of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// list the domains of all projects (administrators only)
+	#[argh(switch, short = 'a')]
+	pub all: bool,
+}
+
+/// Add a custom pages domain
+#[derive(FromArgs)]
+#[argh(subcommand, name = "add")]
+pub struct PagesDomainsAdd {
+	/// the project to add the domain to
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// get a certificate from Let's Encrypt
+	#[argh(switch, short = 'a')]
+	pub auto_ssl: bool,
+
+	/// PEM certificate file of the domain
+	#[argh(option, short = 'c')]
+	pub certificate: Option<String>,
+
+	/// PEM private key file of the certificate
+	#[argh(option, short = 'k')]
+	pub key: Option<String>,
+
+	/// the domain name
+	#[argh(positional)]
+	pub domain: String,
+}
+
+/// Remove a custom pages domain
+#[derive(FromArgs)]
+#[argh(subcommand, name = "remove")]
+pub struct PagesDomainsRemove {
+	/// the project of the domain
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the domain name
+	#[argh(positional)]
+	pub domain: String,
+}
+
+/// Check the verification code of a custom pages domain
+#[derive(FromArgs)]
+#[argh(subcommand, name = "verify")]
+pub struct PagesDomainsVerify {
+	/// the project of the domain
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the domain name
+	#[argh(positional)]
+	pub domain: String,
+}
+
+/// Manage the container registry cleanup policy of a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "policy")]
+pub struct RegistryPolicy {
+	/// operate on the cleanup policy
+	#[argh(subcommand)]
+	pub cmd: RegistryPolicyCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum RegistryPolicyCmd {
+	Get(RegistryPolicyGet),
+	Set(RegistryPolicySet),
+}
+
+/// Show the container registry cleanup policy
+#[derive(FromArgs)]
+#[argh(subcommand, name = "get")]
+pub struct RegistryPolicyGet {
+	/// the project to get the cleanup policy of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// output format: yaml (default) or json
+	#[argh(option, short = 'f', default = "Format::Yaml")]
+	pub format: Format,
+}
+
+/// Change the container registry cleanup policy
+#[derive(FromArgs)]
+#[argh(subcommand, name = "set")]
+pub struct RegistryPolicySet {
+	/// the project to change the cleanup policy of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// enable or disable the policy
+	#[argh(option, short = 'e')]
+	pub enabled: Option<bool>,
+
+	/// how often the policy runs: 1d, 7d, 14d, 1month or 3month
+	#[argh(option, short = 'c')]
+	pub cadence: Option<String>,
+
+	/// number of tags to keep: 1, 5, 10, 25, 50 or 100
+	#[argh(option, short = 'k')]
+	pub keep_n: Option<u64>,
+
+	/// remove tags older than: 7d, 14d, 30d or 90d
+	#[argh(option, short = 'o')]
+	pub older_than: Option<String>,
+
+	/// remove tags whose name matches this regex
+	#[argh(option, short = 'r')]
+	pub name_regex: Option<String>,
+
+	/// keep tags whose name matches this regex
+	#[argh(option, short = 'K')]
+	pub keep_regex: Option<String>,
+}
+
+/// Fetch and commit repository files
+#[derive(FromArgs)]
+#[argh(subcommand, name = "file")]
+pub struct File {
+	/// operate on repository files
+	#[argh(subcommand)]
+	pub cmd: FileCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum FileCmd {
+	Get(FileGet),
+	Put(FilePut),
+	Rm(FileRm),
+}
+
+/// Print a raw file of the repository or save it to a file
+#[derive(FromArgs)]
+#[argh(subcommand, name = "get")]
+pub struct FileGet {
+	/// the project to get the file from
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// reference (branch, tag or commit) to get the file from (default branch by default)
+	#[argh(option, short = 'r')]
+	pub ref_: Option<String>,
+
+	/// save the file to this path instead of printing it
+	#[argh(option, short = 'o')]
+	pub output: Option<String>,
+
+	/// path of the file in the repository
+	#[argh(positional)]
+	pub path: String,
+}
+
+/// Create or update a file of the repository in a new commit
+#[derive(FromArgs)]
+#[argh(subcommand, name = "put")]
+pub struct FilePut {
+	/// the project to commit the file to
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the local file to upload
+	#[argh(option, short = 'f')]
+	pub from: String,
+
+	/// the commit message ("Update <path>" by default)
+	#[argh(option, short = 'm')]
+	pub message: Option<String>,
+
+	/// the branch to commit to (default branch by default)
+	#[argh(option, short = 'b')]
+	pub branch: Option<String>,
+
+	/// path of the file in the repository
+	#[argh(positional)]
+	pub path: String,
+}
+
+/// Delete a file of the repository in a new commit
+#[derive(FromArgs)]
+#[argh(subcommand, name = "rm")]
+pub struct FileRm {
+	/// the project to delete the file from
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the commit message ("Delete <path>" by default)
+	#[argh(option, short = 'm')]
+	pub message: Option<String>,
+
+	/// the branch to commit to (default branch by default)
+	#[argh(option, short = 'b')]
+	pub branch: Option<String>,
+
+	/// path of the file in the repository
+	#[argh(positional)]
+	pub path: String,
+}
+
+/// List the repository tree
+#[derive(FromArgs)]
+#[argh(subcommand, name = "tree")]
+pub struct Tree {
+	/// the project to list the tree of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// reference (branch, tag or commit) to list the tree of (default branch by default)
+	#[argh(option, short = 'r')]
+	pub ref_: Option<String>,
+
+	/// list subdirectories recursively
+	#[argh(switch, short = 'R')]
+	pub recursive: bool,
+
+	/// the directory to list (the root by default)
+	#[argh(positional)]
+	pub path: Option<String>,
+}
+
+/// Browse the commits of a repository
+#[derive(FromArgs)]
+#[argh(subcommand, name = "commits")]
+pub struct Commits {
+	/// operate on commits
+	#[argh(subcommand)]
+	pub cmd: CommitsCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum CommitsCmd {
+	List(CommitsList),
+	Show(CommitsShow),
+	Status(CommitsStatus),
+	CherryPick(CommitsCherryPick),
+	Revert(CommitsRevert),
+	Stats(CommitsStats),
+}
+
+/// List the commits of a repository, most recent first
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct CommitsList {
+	/// the project to list commits of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// reference (branch, tag or commit) to list commits of (default branch by default)
+	#[argh(option, short = 'r')]
+	pub ref_: Option<String>,
+
+	/// only show commits made after that date (YYYY-MM-DD)
+	#[argh(option, short = 's')]
+	pub since: Option<String>,
+
+	/// only show commits of that author (name or email)
+	#[argh(option, short = 'a')]
+	pub author: Option<String>,
+
+	/// only show commits touching that path
+	#[argh(option, short = 'P')]
+	pub path: Option<String>,
+
+	/// maximum number of commits to show
+	#[argh(option, short = 'l', default = "20")]
+	pub limit: usize,
+}
+
+/// Show a commit with its diff
+#[derive(FromArgs)]
+#[argh(subcommand, name = "show")]
+pub struct CommitsShow {
+	/// the project of the commit
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// show the patch (default unless --stat is given)
+	#[argh(switch, short = 'P')]
+	pub patch: bool,
+
+	/// show the number of changed lines per file
+	#[argh(switch, short = 's')]
+	pub stat: bool,
+
+	/// the commit sha (or a branch or tag name)
+	#[argh(positional)]
+	pub sha: String,
+}
+
+/// Manage the external statuses of a commit
+#[derive(FromArgs)]
+#[argh(subcommand, name = "status")]
+pub struct CommitsStatus {
+	/// operate on commit statuses
+	#[argh(subcommand)]
+	pub cmd: CommitsStatusCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum CommitsStatusCmd {
+	Set(CommitsStatusSet),
+	List(CommitsStatusList),
+}
+
+/// Report the status of an external check on a commit
+#[derive(FromArgs)]
+#[argh(subcommand, name = "set")]
+pub struct CommitsStatusSet {
+	/// the project of the commit
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// state of the check: pending, running, success, failed or canceled
+	#[argh(option, short = 's')]
+	pub state: String,
+
+	/// name of the check
+	#[argh(option, short = 'n', default = "\"default\".to_string()")]
+	pub name: String,
+
+	/// the branch or tag the status applies to (needed when the commit is on several branches)
+	#[argh(option, short = 'r')]
+	pub ref_: Option<String>,
+
+	/// url of the check details
+	#[argh(option, short = 't')]
+	pub target_url: Option<String>,
+
+	/// short description of the status
+	#[argh(option, short = 'd')]
+	pub description: Option<String>,
+
+	/// total code coverage
+	#[argh(option, short = 'c')]
+	pub coverage: Option<f64>,
+
+	/// the commit sha
+	#[argh(positional)]
+	pub sha: String,
+}
+
+/// List the statuses and pipelines of a commit
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct CommitsStatusList {
+	/// the project of the commit
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// also show the statuses replaced by a newer one with the same name
+	#[argh(switch, short = 'a')]
+	pub all: bool,
+
+	/// the commit sha
+	#[argh(positional)]
+	pub sha: String,
+}
+
+/// Cherry-pick a commit on a branch
+#[derive(FromArgs)]
+#[argh(subcommand, name = "cherry-pick")]
+pub struct CommitsCherryPick {
+	/// the project of the commit
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the branch to cherry-pick the commit on
+	#[argh(option, short = 't')]
+	pub to: String,
+
+	/// a custom commit message
+	#[argh(option, short = 'm')]
+	pub message: Option<String>,
+
+	/// only check that the commit can be cherry-picked
+	#[argh(switch, short = 'N')]
+	pub dry_run: bool,
+
+	/// the commit sha
+	#[argh(positional)]
+	pub sha: String,
+}
+
+/// Revert a commit on a branch
+#[derive(FromArgs)]
+#[argh(subcommand, name = "revert")]
+pub struct CommitsRevert {
+	/// the project of the commit
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// the branch to revert the commit on
+	#[argh(option, short = 't')]
+	pub to: String,
+
+	/// only check that the commit can be reverted
+	#[argh(switch, short = 'N')]
+	pub dry_run: bool,
+
+	/// the commit sha
+	#[argh(positional)]
+	pub sha: String,
+}
+
+/// Show who last changed each line of a file
+#[derive(FromArgs)]
+#[argh(subcommand, name = "blame")]
+pub struct Blame {
+	/// the project of the file
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// reference (branch, tag or commit) to blame the file at (default branch by default)
+	#[argh(option, short = 'r')]
+	pub ref_: Option<String>,
+
+	/// only blame the lines between L1 and L2 included (L1:L2)
+	#[argh(option, short = 'L')]
+	pub range: Option<String>,
+
+	/// path of the file in the repository
+	#[argh(positional)]
+	pub path: String,
+}
+
+/// Clone a project
+#[derive(FromArgs)]
+#[argh(subcommand, name = "clone")]
+pub struct GitClone {
+	/// protocol: ssh or https (ssh by default)
+	#[argh(option)]
+	pub protocol: Option<Protocol>,
+
+	/// branch to check out instead of the default one
+	#[argh(option, short = 'b')]
+	pub branch: Option<String>,
+
+	/// create a shallow clone with the given history depth
+	#[argh(option)]
+	pub depth: Option<u32>,
+
+	/// write a .glctl.yaml file with the project in the clone
+	#[argh(switch, short = 's')]
+	pub save_defaults: bool,
+
+	/// the project path
+	#[argh(positional)]
+	pub project: String,
+
+	/// destination directory (default to the project name)
+	#[argh(positional)]
+	pub dir: Option<String>,
+}
+
+/// Search projects and groups
+#[derive(FromArgs)]
+#[argh(subcommand, name = "search")]
+pub struct Search {
+	/// operate on search
+	#[argh(subcommand)]
+	pub cmd: SearchCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum SearchCmd {
+	Code(SearchCode),
+}
+
+/// Search code in a project, a group or the whole instance
+#[derive(FromArgs)]
+#[argh(subcommand, name = "code")]
+pub struct SearchCode {
+	/// the project to search in (current project by default)
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// search in all projects of a group
+	#[argh(option, short = 'g')]
+	pub group: Option<String>,
+
+	/// search in all projects of the instance
+	#[argh(switch, short = 'G')]
+	pub global: bool,
+
+	/// only search in files whose name matches (* wildcards allowed)
+	#[argh(option, short = 'f')]
+	pub filename: Option<String>,
+
+	/// reference to search in (project only, default branch by default)
+	#[argh(option, short = 'r')]
+	pub ref_: Option<String>,
+
+	/// maximum number of matches to show
+	#[argh(option, short = 'l', default = "20")]
+	pub limit: usize,
+
+	/// the text to search
+	#[argh(positional)]
+	pub query: String,
+}
+
+/// Show the number of commits and changed lines per author
+#[derive(FromArgs)]
+#[argh(subcommand, name = "stats")]
+pub struct CommitsStats {
+	/// the project to show statistics of
+	#[argh(option, short = 'p')]
+	pub project: Option<String>,
+
+	/// reference (branch, tag or commit) to count commits of (default branch by default)
+	#[argh(option, short = 'r')]
+	pub ref_: Option<String>,
+
+	/// only count commits made after that date (YYYY-MM-DD)
+	#[argh(option, short = 's')]
+	pub since: Option<String>,
+
+	/// output format: csv, json or yaml (table by default)
+	#[argh(option, short = 'f')]
+	pub format: Option<Format>,
+}
+
+/// Login to, logout from or check the gitlab host
+#[derive(FromArgs)]
+#[argh(subcommand, name = "auth")]
+pub struct Auth {
+	/// operate on authentication
+	#[argh(subcommand)]
+	pub cmd: AuthCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum AuthCmd {
+	Login(AuthLogin),
+	Logout(AuthLogout),
+	Status(AuthStatus),
+}
+
+/// Login with the OAuth2 authorization code flow and save the tokens to cache
+#[derive(FromArgs)]
+#[argh(subcommand, name = "login")]
+pub struct AuthLogin {}
+
+/// Remove the saved tokens from cache
+#[derive(FromArgs)]
+#[argh(subcommand, name = "logout")]
+pub struct AuthLogout {}
+
+/// Check the token and show the user, scopes and expiry date of the token and the gitlab version
+#[derive(FromArgs)]
+#[argh(subcommand, name = "status")]
+pub struct AuthStatus {}
+
+/// Manage personal access tokens
+#[derive(FromArgs)]
+#[argh(subcommand, name = "token")]
+pub struct Token {
+	/// operate on personal access tokens
+	#[argh(subcommand)]
+	pub cmd: TokenCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum TokenCmd {
+	List(TokenList),
+	Create(TokenCreate),
+	Rotate(TokenRotate),
+	Revoke(TokenRevoke),
+}
+
+/// List personal access tokens
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct TokenList {
+	/// only list the tokens of a user (admin only for other users)
+	#[argh(option, short = 'u')]
+	pub user: Option<String>,
+
+	/// also list revoked and expired tokens
+	#[argh(switch, short = 'a')]
+	pub all: bool,
+}
+
+/// Create a personal access token (admin only)
+#[derive(FromArgs)]
+#[argh(subcommand, name = "create")]
+pub struct TokenCreate {
+	/// the user to create the token for (current user by default)
+	#[argh(option, short = 'u')]
+	pub user: Option<String>,
+
+	/// a scope of the token (api by default)
+	#[argh(option, short = 's')]
+	pub scopes: Vec<String>,
+
+	/// the expiration date of the token (YYYY-MM-DD)
+	#[argh(option, short = 'e')]
+	pub expires: Option<String>,
+
+	/// replace the access token of the configuration by the new one
+	#[argh(switch, short = 'w')]
+	pub write: bool,
+
+	/// the name of the token
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Rotate a personal access token, revoking it and creating a new one
+#[derive(FromArgs)]
+#[argh(subcommand, name = "rotate")]
+pub struct TokenRotate {
+	/// the expiration date of the new token (YYYY-MM-DD, one week by default)
+	#[argh(option, short = 'e')]
+	pub expires: Option<String>,
+
+	/// replace the access token of the configuration by the new one
+	#[argh(switch, short = 'w')]
+	pub write: bool,
+
+	/// the id of the token (the token in use by default)
+	#[argh(positional)]
+	pub id: Option<u64>,
+}
+
+/// Revoke a personal access token
+#[derive(FromArgs)]
+#[argh(subcommand, name = "revoke")]
+pub struct TokenRevoke {
+	/// the id of the token (the token in use by default)
+	#[argh(positional)]
+	pub id: Option<u64>,
+}
+
+/// Instance administration
+#[derive(FromArgs)]
+#[argh(subcommand, name = "admin")]
+pub struct Admin {
+	/// operate on the instance
+	#[argh(subcommand)]
+	pub cmd: AdminCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum AdminCmd {
+	ImpersonationToken(AdminImpersonationToken),
+}
+
+/// Manage impersonation tokens of users
+#[derive(FromArgs)]
+#[argh(subcommand, name = "impersonation-token")]
+pub struct AdminImpersonationToken {
+	/// operate on impersonation tokens
+	#[argh(subcommand)]
+	pub cmd: AdminImpersonationTokenCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum AdminImpersonationTokenCmd {
+	List(AdminImpersonationTokenList),
+	Create(AdminImpersonationTokenCreate),
+	Revoke(AdminImpersonationTokenRevoke),
+}
+
+/// List impersonation tokens of a user
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct AdminImpersonationTokenList {
+	/// the username of the user
+	#[argh(option, short = 'u')]
+	pub user: String,
+
+	/// also list revoked and expired tokens
+	#[argh(switch, short = 'a')]
+	pub all: bool,
+}
+
+/// Create an impersonation token for a user, printing it once
+#[derive(FromArgs)]
+#[argh(subcommand, name = "create")]
+pub struct AdminImpersonationTokenCreate {
+	/// the username of the user
+	#[argh(option, short = 'u')]
+	pub user: String,
+
+	/// a scope of the token: api (default) or read_user
+	#[argh(option, short = 's')]
+	pub scopes: Vec<String>,
+
+	/// the expiration date of the token (YYYY-MM-DD)
+	#[argh(option, short = 'e')]
+	pub expires: Option<String>,
+
+	/// the name of the token
+	#[argh(positional)]
+	pub name: String,
+}
+
+/// Revoke an impersonation token of a user
+#[derive(FromArgs)]
+#[argh(subcommand, name = "revoke")]
+pub struct AdminImpersonationTokenRevoke {
+	/// the username of the user
+	#[argh(option, short = 'u')]
+	pub user: String,
+
+	/// the id of the token
+	#[argh(positional)]
+	pub id: u64,
+}
+
+/// Manage the configuration file
+#[derive(FromArgs)]
+#[argh(subcommand, name = "config")]
+pub struct Config {
+	/// operate on the configuration
+	#[argh(subcommand)]
+	pub cmd: ConfigCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum ConfigCmd {
+	Init(ConfigInit),
+	Check(ConfigCheck),
+	Path(ConfigPath),
+}
+
+/// Create the configuration file by answering questions, checking the authentication
+#[derive(FromArgs)]
+#[argh(subcommand, name = "init")]
+pub struct ConfigInit {
+	/// overwrite an existing configuration file
+	#[argh(switch, short = 'f')]
+	pub force: bool,
+}
+
+/// Check the configuration file, the authentication of its hosts and the default projects
+#[derive(FromArgs)]
+#[argh(subcommand, name = "check")]
+pub struct ConfigCheck {}
+
+/// Show where the configuration, the cache and the repository defaults are
+#[derive(FromArgs)]
+#[argh(subcommand, name = "path")]
+pub struct ConfigPath {}
+
+/// Manage aliases of command lines
+#[derive(FromArgs)]
+#[argh(subcommand, name = "alias")]
+pub struct Alias {
+	/// operate on aliases
+	#[argh(subcommand)]
+	pub cmd: AliasCmd,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub enum AliasCmd {
+	List(AliasList),
+	Set(AliasSet),
+	Remove(AliasRemove),
+}
+
+/// List the aliases of the configuration
+#[derive(FromArgs)]
+#[argh(subcommand, name = "list")]
+pub struct AliasList {}
+
+/// Define an alias of a command line (ex: glctl alias set st pipeline status)
+#[derive(FromArgs)]
+#[argh(subcommand, name = "set")]
+pub struct AliasSet {
+	/// name of the alias
+	#[argh(positional)]
+	pub name: String,
+
+	/// command line run in place of the alias
+	#[argh(positional, greedy)]
+	pub command: Vec<String>,
+}
+
+/// Remove an alias from the configuration
+#[derive(FromArgs)]
+#[argh(subcommand, name = "remove")]
+pub struct AliasRemove {
+	/// name of the alias
+	#[argh(positional)]
+	pub name: String,
 }
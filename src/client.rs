@@ -0,0 +1,195 @@
+use crate::config::Connection;
+
+use anyhow::{anyhow, Context, Result};
+use bytes::Bytes;
+use gitlab::{
+	api::{self, users::CurrentUser, ApiError, Client, Query, RestClient},
+	Gitlab,
+};
+use graphql_client::{GraphQLQuery, QueryBody, Response as GraphQLResponse};
+use http::{request::Builder as RequestBuilder, Response};
+use reqwest::{blocking, Method, StatusCode, Url};
+use serde::Deserialize;
+use std::{cell::Cell, fmt::Debug, thread, time::Duration};
+
+/// List of token scopes to show in messages
+pub fn scope_list(scopes: &[String]) -> String {
+	if scopes.is_empty() {
+		"no scope".to_owned()
+	} else {
+		scopes.join(", ")
+	}
+}
+
+/// Delay before retrying a request, doubling with each attempt
+pub fn retry_delay(attempt: u32) -> Duration {
+	Duration::from_secs(1 << attempt.min(6))
+}
+
+/// Connection to gitlab. The gitlab crate neither supports custom TLS settings nor the
+/// header of CI job tokens, so requests are sent with our own http client
+pub struct GitlabClient {
+	/// http client configured for the host
+	client: blocking::Client,
+	/// base url of the rest api
+	rest_url: Url,
+	/// url of the graphql api
+	graphql_url: Url,
+	/// authentication header added to each request
+	auth: (&'static str, String),
+	/// number of times a request is retried after a transient failure
+	retries: u32,
+	/// scopes of the token, when they are checked
+	scopes: Option<Vec<String>>,
+	/// whether the lack of scope has already been reported
+	warned: Cell<bool>,
+}
+
+impl GitlabClient {
+	/// Initializer. The authentication is not checked
+	pub fn new(host: &str, connection: &Connection, auth: (&'static str, String)) -> Result<Self> {
+		Ok(Self {
+			client: connection.client()?,
+			rest_url: Url::parse(&format!("https://{}/api/v4/", host))?,
+			graphql_url: Url::parse(&format!("https://{}/api/graphql", host))?,
+			auth,
+			retries: connection.retries(),
+			scopes: None,
+			warned: Cell::new(false),
+		})
+	}
+
+	/// Set the scopes of the token, to warn before a request that needs more
+	pub fn set_scopes(&mut self, scopes: Vec<String>) {
+		self.scopes = Some(scopes);
+	}
+
+	/// Print a warning if a request changing data is about to be sent without the api scope
+	fn check_scopes(&self, method: &Method) {
+		if let Some(scopes) = &self.scopes {
+			if !matches!(*method, Method::GET | Method::HEAD)
+				&& !scopes.iter().any(|scope| scope == "api")
+				&& !self.warned.replace(true)
+			{
+				eprintln!(
+					"Warning: this command needs the api scope but the token only has {}",
+					scope_list(scopes)
+				);
+			}
+		}
+	}
+
+	/// Check the authentication by querying the current user
+	pub fn check(self) -> Result<Self> {
+		let endpoint = CurrentUser::builder().build()?;
+		api::ignore(endpoint).query(&self)?;
+		Ok(self)
+	}
+
+	/// Http client configured for the host, to query it directly
+	pub fn http(&self) -> &blocking::Client {
+		&self.client
+	}
+
+	/// Send a GraphQL query
+	pub fn graphql<Q>(&self, query: &QueryBody<Q::Variables>) -> Result<Q::ResponseData>
+	where
+		Q: GraphQLQuery,
+		Q::Variables: Debug,
+		for<'d> Q::ResponseData: Deserialize<'d>,
+	{
+		let request = self
+			.client
+			.post(self.graphql_url.clone())
+			.header(self.auth.0, &self.auth.1)
+			.json(query)
+			.build()?;
+		let rsp = self.send(request)?;
+		let status = rsp.status();
+		if status.is_server_error() {
+			return Err(anyhow!("gitlab HTTP error: {}", status));
+		}
+		let rsp: GraphQLResponse<Q::ResponseData> =
+			serde_json::from_reader(rsp).with_context(|| "Unexpected GraphQL response")?;
+		if let Some(errors) = rsp.errors {
+			return Err(anyhow!(
+				"graphql error: {}",
+				errors
+					.iter()
+					.map(|error| error.message.as_str())
+					.collect::<Vec<_>>()
+					.join(", ")
+			));
+		}
+		rsp.data.ok_or_else(|| anyhow!("no response from gitlab"))
+	}
+
+	/// Send a request, retrying on connection failures, and also on timeouts and server
+	/// errors when the request can be repeated safely
+	fn send(&self, request: blocking::Request) -> reqwest::Result<blocking::Response> {
+		let idempotent = matches!(*request.method(), Method::GET | Method::HEAD);
+		let mut attempt = 0;
+		loop {
+			// the body is in memory, so the request can always be cloned
+			let result = self.client.execute(request.try_clone().unwrap());
+			let transient = match &result {
+				Ok(rsp) => {
+					idempotent
+						&& (rsp.status().is_server_error()
+							|| rsp.status() == StatusCode::TOO_MANY_REQUESTS)
+				}
+				Err(err) => err.is_connect() || (idempotent && err.is_timeout()),
+			};
+			if !transient || attempt >= self.retries {
+				return result;
+			}
+			attempt += 1;
+			eprintln!(
+				"Request to {} failed ({}). Retrying ({}/{})",
+				request.url().path(),
+				match &result {
+					Ok(rsp) => rsp.status().to_string(),
+					Err(err) => err.to_string(),
+				},
+				attempt,
+				self.retries
+			);
+			thread::sleep(retry_delay(attempt));
+		}
+	}
+}
+
+impl RestClient for GitlabClient {
+	type Error = <Gitlab as RestClient>::Error;
+
+	fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+		Ok(self.rest_url.join(endpoint)?)
+	}
+}
+
+impl Client for GitlabClient {
+	fn rest(
+		&self,
+		request: RequestBuilder,
+		body: Vec<u8>,
+	) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+		let call = || -> Result<_, Self::Error> {
+			let request = request
+				.header(self.auth.0, self.auth.1.as_str())
+				.body(body)?;
+			let request: blocking::Request = request.try_into()?;
+			self.check_scopes(request.method());
+			let rsp = self.send(request)?;
+
+			let mut http_rsp = Response::builder()
+				.status(rsp.status())
+				.version(rsp.version());
+			let headers = http_rsp.headers_mut().unwrap();
+			for (key, value) in rsp.headers() {
+				headers.insert(key, value.clone());
+			}
+			Ok(http_rsp.body(rsp.bytes()?)?)
+		};
+		call().map_err(ApiError::client)
+	}
+}
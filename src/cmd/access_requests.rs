@@ -0,0 +1,145 @@
+use crate::{
+	api::access_requests::{AccessRequests, ApproveAccessRequest, DenyAccessRequest, Source},
+	args::{self, AccessRequestsCmd},
+	color::StyledStr,
+	context::CliContext,
+	utils::{access_level_name, parse_access_level},
+};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use gitlab::{
+	api::{self, groups, Pagination, Query},
+	types,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct AccessRequest {
+	username: String,
+	name: String,
+	requested_at: DateTime<Utc>,
+	web_url: String,
+}
+
+#[derive(Deserialize)]
+struct ApprovedRequest {
+	username: String,
+	access_level: u64,
+}
+
+/// Get the group or the project (which can be the one provided or a default one) the
+/// access requests belong to, along with its path and members page url
+fn get_source(
+	context: &CliContext,
+	project: Option<&String>,
+	group: Option<&String>,
+) -> Result<(Source<'static>, String, String)> {
+	if let Some(group) = group {
+		let group: types::Group = groups::Group::builder()
+			.group(group.as_str())
+			.build()?
+			.query(&context.gitlab)
+			.with_context(|| format!("Can't find a group named {}", group))?;
+		Ok((
+			Source::Group(group.id.value().into()),
+			group.full_path,
+			format!("{}/-/group_members", group.web_url),
+		))
+	} else {
+		let project = context.get_project(project)?;
+		Ok((
+			Source::Project(project.id.value().into()),
+			project.path_with_namespace,
+			format!("{}/-/project_members", project.web_url),
+		))
+	}
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::AccessRequests) -> Result<()> {
+	match &args.cmd {
+		AccessRequestsCmd::List(cmd_args) => {
+			let (source, path, members_url) =
+				get_source(context, cmd_args.project.as_ref(), cmd_args.group.as_ref())?;
+			let endpoint = AccessRequests::builder().source(source).build()?;
+			let requests: Vec<AccessRequest> = api::paged(endpoint, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to list access requests of {}", &path))?;
+
+			let mut msg = StyledStr::new();
+			if requests.is_empty() {
+				msg.none("No pending access requests for ");
+				msg.literal(&path);
+				msg.none("\n");
+			}
+			for request in requests.iter() {
+				msg.none("- ");
+				msg.literal(&request.username);
+				msg.none(format!(" ({}) [", request.name));
+				msg.literal(
+					timeago::Formatter::new().convert_chrono(request.requested_at, Utc::now()),
+				);
+				msg.none("]");
+				if context.url {
+					msg.hint(format!(" ({})", request.web_url));
+				}
+				msg.none("\n");
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(members_url);
+			}
+			Ok(())
+		}
+
+		AccessRequestsCmd::Approve(cmd_args) => {
+			let (source, path, _) =
+				get_source(context, cmd_args.project.as_ref(), cmd_args.group.as_ref())?;
+			let user = context.get_user(&cmd_args.username)?;
+			let mut endpoint = ApproveAccessRequest::builder();
+			endpoint.source(source).user(user.id.value());
+			if let Some(access) = &cmd_args.access {
+				endpoint.access_level(parse_access_level(access)?);
+			}
+			let approved: ApprovedRequest =
+				endpoint.build()?.query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to approve the access request of {} to {}",
+						&cmd_args.username, &path
+					)
+				})?;
+			println!(
+				"{} has been granted {} access to {}",
+				&approved.username,
+				access_level_name(approved.access_level),
+				&path
+			);
+			Ok(())
+		}
+
+		AccessRequestsCmd::Deny(cmd_args) => {
+			let (source, path, _) =
+				get_source(context, cmd_args.project.as_ref(), cmd_args.group.as_ref())?;
+			let user = context.get_user(&cmd_args.username)?;
+			let endpoint = DenyAccessRequest::builder()
+				.source(source)
+				.user(user.id.value())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to deny the access request of {} to {}",
+						&cmd_args.username, &path
+					)
+				})?;
+			println!(
+				"the access request of {} to {} has been denied",
+				&cmd_args.username, &path
+			);
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,15 @@
+mod impersonation_token;
+
+use crate::{
+	args::{self, AdminCmd},
+	context::CliContext,
+};
+
+use anyhow::Result;
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Admin) -> Result<()> {
+	match &args.cmd {
+		AdminCmd::ImpersonationToken(cmd_args) => impersonation_token::cmd(context, cmd_args),
+	}
+}
@@ -0,0 +1,160 @@
+use crate::{
+	args::{self, AdminImpersonationTokenCmd},
+	color::StyledStr,
+	context::CliContext,
+	utils::parse_date,
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use gitlab::api::{
+	self,
+	users::impersonation_tokens::{
+		CreateImpersonationToken, DeleteImpersonationToken, ImpersonationTokenScope,
+		ImpersonationTokenState, ImpersonationTokens,
+	},
+	Pagination, Query,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct ImpersonationToken {
+	id: u64,
+	name: String,
+	active: bool,
+	revoked: bool,
+	scopes: Vec<String>,
+	expires_at: Option<NaiveDate>,
+	last_used_at: Option<DateTime<Utc>>,
+	/// only returned on creation
+	token: Option<String>,
+}
+
+/// Parse an impersonation token scope
+fn parse_scope(scope: &str) -> Result<ImpersonationTokenScope> {
+	match scope {
+		"api" => Ok(ImpersonationTokenScope::Api),
+		"read_user" => Ok(ImpersonationTokenScope::ReadUser),
+		scope => bail!(
+			"{} not supported for --scopes. Use either \"api\" or \"read_user\"",
+			scope
+		),
+	}
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::AdminImpersonationToken) -> Result<()> {
+	match &args.cmd {
+		AdminImpersonationTokenCmd::List(cmd_args) => {
+			let user = context.get_user(&cmd_args.user)?;
+			let endpoint = ImpersonationTokens::builder()
+				.user(user.id.value())
+				.state(if cmd_args.all {
+					ImpersonationTokenState::All
+				} else {
+					ImpersonationTokenState::Active
+				})
+				.build()?;
+			let tokens: Vec<ImpersonationToken> = api::paged(endpoint, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to list impersonation tokens of {} (admin only)",
+						&cmd_args.user
+					)
+				})?;
+
+			let mut msg = StyledStr::new();
+			if tokens.is_empty() {
+				msg.none("No impersonation tokens for ");
+				msg.literal(&cmd_args.user);
+				msg.none("\n");
+			}
+			for token in tokens.iter() {
+				msg.none(format!("- #{} ", token.id));
+				msg.literal(&token.name);
+				msg.none(format!(" [{}]", token.scopes.join(", ")));
+				if token.revoked {
+					msg.error(" revoked");
+				} else if !token.active {
+					msg.warning(" expired");
+				}
+				msg.hint(format!(
+					" (expires {}, last used {})",
+					token
+						.expires_at
+						.map(|date| date.to_string())
+						.unwrap_or_else(|| "never".to_owned()),
+					token
+						.last_used_at
+						.map(|date| timeago::Formatter::new().convert_chrono(date, Utc::now()))
+						.unwrap_or_else(|| "never".to_owned())
+				));
+				msg.none("\n");
+			}
+			context.print_msg(msg)
+		}
+
+		AdminImpersonationTokenCmd::Create(cmd_args) => {
+			let user = context.get_user(&cmd_args.user)?;
+			let scopes = if cmd_args.scopes.is_empty() {
+				vec![ImpersonationTokenScope::Api]
+			} else {
+				cmd_args
+					.scopes
+					.iter()
+					.map(|scope| parse_scope(scope))
+					.collect::<Result<Vec<_>>>()?
+			};
+			let mut endpoint = CreateImpersonationToken::builder();
+			endpoint
+				.user(user.id.value())
+				.name(cmd_args.name.as_str())
+				.scopes(scopes.into_iter());
+			if let Some(expires) = &cmd_args.expires {
+				endpoint.expires_at(parse_date(expires)?);
+			}
+			let token: ImpersonationToken =
+				endpoint.build()?.query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to create impersonation token {} for {} (admin only)",
+						&cmd_args.name, &cmd_args.user
+					)
+				})?;
+
+			let mut msg = StyledStr::new();
+			msg.none(format!("impersonation token #{} ", token.id));
+			msg.literal(&token.name);
+			msg.none(format!(" has been created for {}", &cmd_args.user));
+			if let Some(expires_at) = token.expires_at {
+				msg.none(format!(" and expires on {}", expires_at));
+			}
+			msg.none("\n");
+			msg.hint("copy it now as it can't be shown again: ");
+			msg.good(token.token.as_deref().unwrap_or_default());
+			msg.none("\n");
+			context.print_msg(msg)
+		}
+
+		AdminImpersonationTokenCmd::Revoke(cmd_args) => {
+			let user = context.get_user(&cmd_args.user)?;
+			let endpoint = DeleteImpersonationToken::builder()
+				.user(user.id.value())
+				.token_id(cmd_args.id)
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to revoke impersonation token #{} of {} (admin only)",
+						cmd_args.id, &cmd_args.user
+					)
+				})?;
+			println!(
+				"impersonation token #{} of {} has been revoked",
+				cmd_args.id, &cmd_args.user
+			);
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,62 @@
+use crate::{
+	args::{self, AliasCmd, Opts, SubCommand},
+	config::Config,
+};
+
+use anyhow::{anyhow, bail, Result};
+use argh::SubCommands;
+use std::path::PathBuf;
+
+/// Command implementation. It runs before connecting to gitlab as aliases only concern the
+/// config file
+pub fn cmd(opts: &Opts, args: &args::Alias) -> Result<()> {
+	match &args.cmd {
+		AliasCmd::List(_) => {
+			let aliases = Config::aliases(opts.config.as_ref())?;
+			if aliases.is_empty() {
+				println!("No alias defined");
+			}
+			for (name, command) in aliases {
+				println!("{} = {}", name, command);
+			}
+			Ok(())
+		}
+
+		AliasCmd::Set(cmd_args) => {
+			if SubCommand::COMMANDS
+				.iter()
+				.any(|command| command.name == cmd_args.name)
+			{
+				bail!(
+					"{} is a command and can't be used as an alias",
+					&cmd_args.name
+				)
+			}
+			if cmd_args.command.is_empty() {
+				bail!(
+					"The command line of the alias {} is missing",
+					&cmd_args.name
+				)
+			}
+			let command = cmd_args.command.join(" ");
+			Config::save_alias(&config_path(opts)?, &cmd_args.name, Some(&command))?;
+			println!("{} is now an alias of {}", &cmd_args.name, &command);
+			Ok(())
+		}
+
+		AliasCmd::Remove(cmd_args) => {
+			if Config::save_alias(&config_path(opts)?, &cmd_args.name, None)? {
+				println!("Alias {} has been removed", &cmd_args.name);
+			} else {
+				println!("No alias named {}", &cmd_args.name);
+			}
+			Ok(())
+		}
+	}
+}
+
+/// Path of the config file holding the aliases
+fn config_path(opts: &Opts) -> Result<PathBuf> {
+	Config::find_path(opts.config.as_ref())
+		.ok_or_else(|| anyhow!("No configuration file found. Create one with glctl config init"))
+}
@@ -1,21 +1,23 @@
 use crate::{
 	archive::Archive,
-	args::{self, ArchiveCmd},
+	args::{self, ArchiveCmd, ArchiveFormat},
 	context::CliContext,
-	lockfile::LockFile,
+	lockfile::{LockEntry, LockFile},
+	utils::glob_match,
 };
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use bytesize::ByteSize;
+use bzip2::read::BzDecoder;
 use flate2::read::GzDecoder;
-use gitlab::api::{self, Query};
 use serde::Deserialize;
 use std::{
 	collections::BTreeMap,
-	fs::{create_dir, create_dir_all, remove_dir_all, File},
-	io,
+	env,
+	fs::{create_dir_all, read_to_string, remove_dir_all, remove_file, write, File},
+	io::{self, Read, Seek},
 	ops::Deref,
-	path::PathBuf,
+	path::{Path, PathBuf},
 };
 
 fn get_or_create_dir(dir: &str, keep: bool, update: bool, verbose: bool) -> Result<PathBuf> {
@@ -68,12 +70,159 @@ impl Deref for BatchConfig {
 	}
 }
 
+/// Extract the entries of an archive of a project
+struct Extractor<'a> {
+	project: &'a str,
+	/// the subdirectory the archive was restricted to
+	subdir: Option<&'a Path>,
+	/// number of leading path components to strip
+	strip: usize,
+	/// directory to extract entries to
+	root: &'a Path,
+	/// globs the stripped paths must match to be extracted
+	include: &'a [String],
+	/// globs of stripped paths not to extract
+	exclude: &'a [String],
+	verbose: bool,
+}
+
+impl<'a> Extractor<'a> {
+	/// Get the local path of an archive entry or None if there is nothing to extract
+	fn dest(&self, path: &Path) -> Option<PathBuf> {
+		// remove the subdirectory components following the top level dir so that
+		// its content is extracted as if it was the whole repository
+		let mut components = path.components();
+		let top = components.next();
+		let path = match self
+			.subdir
+			.map(|subdir| components.as_path().strip_prefix(subdir))
+		{
+			Some(Ok(rest)) => top
+				.map(|top| PathBuf::from(top.as_os_str()))
+				.unwrap_or_default()
+				.join(rest),
+			_ => path.to_path_buf(),
+		};
+		// strip leading path components if necessary
+		let path: PathBuf = path.components().skip(self.strip).collect();
+		// don't do anything if empty path
+		if path.as_os_str().is_empty() {
+			return None;
+		}
+		let relative = path.to_string_lossy();
+		let included =
+			self.include.is_empty() || self.include.iter().any(|glob| glob_match(glob, &relative));
+		let excluded = self.exclude.iter().any(|glob| glob_match(glob, &relative));
+		if !included || excluded {
+			return None;
+		}
+		Some(self.root.join(path))
+	}
+
+	/// Create a directory if it doesn't exist
+	fn dir(&self, path: &Path) {
+		if !path.exists() {
+			match create_dir_all(path) {
+				Ok(()) => {
+					if self.verbose {
+						println!("  {}", path.to_string_lossy());
+					}
+				}
+				Err(err) => eprintln!("  Can't create dir {}: {:?}", path.to_string_lossy(), &err),
+			}
+		}
+	}
+
+	/// Extract a file to local filesystem
+	fn file(&self, path: &Path, reader: &mut impl Read) {
+		// parent directories may have been filtered out
+		if let Some(parent) = path.parent() {
+			self.dir(parent);
+		}
+		let mut file = match File::create(path) {
+			Ok(file) => file,
+			Err(err) => {
+				eprintln!("  Can't create file {}: {:?}", path.to_string_lossy(), &err);
+				return;
+			}
+		};
+		match io::copy(reader, &mut file) {
+			Ok(size) => {
+				if self.verbose {
+					println!("  {} ({})", path.to_string_lossy(), ByteSize(size));
+				}
+			}
+			Err(err) => eprintln!("  Can't extract {}: {:?}", path.to_string_lossy(), &err),
+		}
+	}
+
+	/// Extract a tar archive
+	fn tar<R: Read>(&self, mut archive: tar::Archive<R>) -> Result<()> {
+		// for each entry in the arquive
+		for entry in archive.entries()? {
+			let mut entry = match entry {
+				Ok(entry) => entry,
+				Err(err) => {
+					eprintln!("  Can't get {} archive entry: {:?}", self.project, &err);
+					continue;
+				}
+			};
+			let path = match self.dest(&entry.path()?) {
+				Some(path) => path,
+				None => continue,
+			};
+
+			// get the entry type
+			let file_type = entry.header().entry_type();
+			match file_type {
+				// if it's a directory, create it if doesn't exist
+				tar::EntryType::Directory => self.dir(&path),
+				// if it's a file, extract it to local filesystem
+				tar::EntryType::Regular => self.file(&path, &mut entry),
+				// TODO: support other types (links)
+				_ => eprintln!("  {} ({:?}) ignored", path.to_string_lossy(), &file_type),
+			}
+		}
+		Ok(())
+	}
+
+	/// Extract a zip archive
+	fn zip<R: Read + Seek>(&self, content: R) -> Result<()> {
+		let mut archive = zip::ZipArchive::new(content)
+			.with_context(|| format!("Can't read {} zip archive", self.project))?;
+		for i in 0..archive.len() {
+			let mut entry = match archive.by_index(i) {
+				Ok(entry) => entry,
+				Err(err) => {
+					eprintln!("  Can't get {} archive entry: {:?}", self.project, &err);
+					continue;
+				}
+			};
+			// names escaping the archive are ignored
+			let path = match entry.enclosed_name().and_then(|path| self.dest(path)) {
+				Some(path) => path,
+				None => continue,
+			};
+			if entry.is_dir() {
+				self.dir(&path);
+			} else {
+				self.file(&path, &mut entry);
+			}
+		}
+		Ok(())
+	}
+}
+
 /// Command implementaton
 pub fn cmd(context: &CliContext, args: &args::Archive) -> Result<()> {
 	match &args.cmd {
 		ArchiveCmd::Extract(args) => {
 			// rename mode is like -s 1 (we remove the first path component) + replace by the project name
-			let strip = if args.rename { 1 } else { args.strip };
+			let strip = if args.rename {
+				1 + args.strip
+			} else {
+				args.strip
+			};
 			// determine the list of project/tag to extract
 			let batch = if let Some(ref config) = args.batch {
 				// in batch mode, we read from a file
@@ -85,8 +234,22 @@ pub fn cmd(context: &CliContext, args: &args::Archive) -> Result<()> {
 				BatchConfig::singleton(project.path_with_namespace, ref_)
 			};
 
+			if args.locked && args.update {
+				bail!("--locked and --update can't be used together")
+			}
+			let subdir = args
+				.path
+				.as_ref()
+				.map(|path| PathBuf::from(path.trim_matches('/')));
+
 			// create the dest directory
-			let dest_dir = get_or_create_dir(&args.dir, args.keep, args.update, context.verbose)?;
+			let dir = args
+				.dir
+				.as_ref()
+				.or(context.config.settings.archive_dir.as_ref())
+				.map(String::as_str)
+				.unwrap_or("tmp");
+			let dest_dir = get_or_create_dir(dir, args.keep, args.update, context.verbose)?;
 			// open lock file (update mode)
 			let lock_name = if let Some(ref batch) = args.batch {
 				batch
@@ -105,6 +268,11 @@ pub fn cmd(context: &CliContext, args: &args::Archive) -> Result<()> {
 				};
 				let prj_dir = dest_dir.join(&project[i..]);
 				let is_extracted = prj_dir.exists();
+				// commit of the last extraction, kept next to the project dir
+				let sha_file = dest_dir.join(format!(".{}.sha", &project[i..]));
+				let cached = read_to_string(&sha_file)
+					.ok()
+					.map(|sha| sha.trim().to_owned());
 
 				// don't overwrite if we were asked to keep.
 				if args.keep && is_extracted {
@@ -117,44 +285,51 @@ pub fn cmd(context: &CliContext, args: &args::Archive) -> Result<()> {
 					continue;
 				}
 
-				let prj = context.get_project(Some(project))?;
-				let tag = context.get_tag(Some(tag), &prj)?;
-				// get locked_commit or tag commit
-				let mut found = false;
-				let mut commit = match lock.get(project) {
-					Some(commit) => {
-						found = true;
-						commit
-					}
-					None => tag.commit.id.value(),
-				};
+				// locked commit of the same reference if any
+				let locked = lock
+					.get(project)
+					.filter(|entry| entry.ref_().is_none_or(|locked| locked == tag))
+					.map(|entry| entry.commit().to_owned());
+				let commit = if args.locked {
+					// reproduce exactly the locked extraction without resolving the reference
+					locked.with_context(|| {
+						format!(
+							"{} {} is not in the lockfile. Run without --locked to resolve it",
+							project, tag
+						)
+					})?
+				} else {
+					let prj = context.get_project(Some(project))?;
+					// the reference can be a tag, a branch or a commit
+					let latest = context.get_commit(tag, &prj)?.id.value().to_owned();
+					// get locked_commit or reference commit
+					let commit = locked.clone().unwrap_or_else(|| latest.clone());
 
-				if args.update && is_extracted {
-					// skip if extracted and locked commit match
-					if found && commit == tag.commit.id.value() {
-						println!(
-							"{} {} ({}) already extracted",
-							project,
-							tag.name,
-							&commit[..8]
-						);
-						continue;
-					} else {
+					if args.update && is_extracted {
+						// skip if extracted and locked or last extracted commit match
+						if (locked.is_some() && commit == latest)
+							|| cached.as_deref() == Some(latest.as_str())
+						{
+							println!("{} {} ({}) already extracted", project, tag, &commit[..8]);
+							continue;
+						}
 						// issue a warning when version mismatch before overwriting
-						if commit != tag.commit.id.value() {
+						if commit != latest {
 							eprintln!(
 								"Extracted commit {} and {} commit {} mismatch",
 								&commit[..8],
-								&tag.name,
-								&tag.commit.short_id.value()
+								tag,
+								&latest[..8]
 							);
 						}
 						// remove project dir before update
 						remove_dir_all(&prj_dir)
 							.with_context(|| format!("Can't remove dir {:?}", &prj_dir))?;
-						commit = tag.commit.id.value();
+						latest
+					} else {
+						commit
 					}
-				}
+				};
 
 				// create the top level dir when it is to be renamed after the project
 				if args.rename {
@@ -162,118 +337,89 @@ pub fn cmd(context: &CliContext, args: &args::Archive) -> Result<()> {
 						.with_context(|| format!("Can't create dir {:?}", &prj_dir))?;
 				}
 
-				let endpoint = Archive::builder()
+				let mut endpoint = Archive::builder();
+				endpoint
 					.project(project.as_str())
-					.sha(commit)
-					.build()?;
-
-				// NOTE: api::raw returns a vec<u8>. It would be
-				// more memory efficient to return the rewest::Response to read
-				// from a stream instead
-				let targz = api::raw(endpoint).query(&context.gitlab)?;
-
-				println!("Extracting {} {} ({})", &project, &tag.name, &commit[..8]);
-				// chain gzip reader and arquive reader. turn vec<u8> to a slice
-				// to be able to io::Read from it
-				let tar = GzDecoder::new(targz.as_slice());
-				let mut arquive = tar::Archive::new(tar);
-
-				// for each entry in the arquive
-				for entry in arquive.entries()? {
-					let mut entry = match entry {
-						Ok(entry) => entry,
-						Err(err) => {
-							eprintln!("  Can't get {} archive entry: {:?}", &project, &err);
-							continue;
-						}
-					};
-
-					// strip leading path components if necessary
-					let entry_path: PathBuf = entry.path()?.components().skip(strip).collect();
-					// don't do anything if empty path
-					if entry_path.to_str().filter(|s| s.is_empty()).is_some() {
-						continue;
-					}
+					.sha(commit.as_str())
+					.format(args.format);
+				if let Some(path) = &args.path {
+					endpoint.path(path.as_str());
+				}
+				let endpoint = endpoint.build()?;
 
+				println!("Extracting {} {} ({})", &project, tag, &commit[..8]);
+				let extractor = Extractor {
+					project,
+					subdir: subdir.as_deref(),
+					strip,
 					// append project dir in rename mode otherwise append destination dir
-					let entry_path = if args.rename {
-						prj_dir.join(entry_path)
+					root: if args.rename {
+						prj_dir.as_path()
 					} else {
-						dest_dir.join(entry_path)
-					};
-
-					// get the entry type
-					let file_type = entry.header().entry_type();
-					match file_type {
-						// if it's a directory, create it if doesn't exist
-						tar::EntryType::Directory => {
-							if !entry_path.exists() {
-								match create_dir(&entry_path) {
-									Ok(()) => {
-										if context.verbose {
-											println!("  {}", &entry_path.to_string_lossy());
-										}
-									}
-									Err(err) => {
-										eprintln!(
-											"  Can't create dir {}: {:?}",
-											&entry_path.to_string_lossy(),
-											&err
-										);
-										continue;
-									}
-								}
-							}
+						dest_dir.as_path()
+					},
+					include: &args.include,
+					exclude: &args.exclude,
+					verbose: context.verbose,
+				};
+				// the zip directory is at the end of the archive which needs to be saved first
+				if args.resume || args.format == ArchiveFormat::Zip {
+					// kept out of the destination dir which may be removed before extraction, and
+					// named after the subpath as the archive only contains it
+					let subpath = args
+						.path
+						.as_ref()
+						.map(|path| format!("-{}", path.trim_matches('/').replace('/', "_")))
+						.unwrap_or_default();
+					let archive_file = env::temp_dir().join(format!(
+						"glctl-{}-{}{}.{}",
+						project.replace('/', "_"),
+						&commit[..8],
+						subpath,
+						args.format.extension()
+					));
+					context
+						.download(&endpoint, &archive_file)
+						.with_context(|| {
+							format!("Failed to download {} archive of {}", tag, project)
+						})?;
+					let file = File::open(&archive_file)
+						.with_context(|| format!("Can't open {:?}", &archive_file))?;
+					match args.format {
+						ArchiveFormat::TarGz => {
+							extractor.tar(tar::Archive::new(GzDecoder::new(file)))?
 						}
-
-						// if it's a file, extract it to local filesystem
-						tar::EntryType::Regular => {
-							let mut file = match File::create(&entry_path) {
-								Ok(file) => file,
-								Err(err) => {
-									eprintln!(
-										"  Can't create file {}: {:?}",
-										&entry_path.to_string_lossy(),
-										&err
-									);
-									continue;
-								}
-							};
-							match io::copy(&mut entry, &mut file) {
-								Ok(size) => {
-									if context.verbose {
-										println!(
-											"  {} ({})",
-											&entry_path.to_string_lossy(),
-											ByteSize(size)
-										);
-									}
-								}
-								Err(err) => {
-									eprintln!(
-										"  Can't extract {}: {:?}",
-										&entry_path.to_string_lossy(),
-										&err
-									);
-									continue;
-								}
-							}
+						ArchiveFormat::TarBz2 => {
+							extractor.tar(tar::Archive::new(BzDecoder::new(file)))?
 						}
-						// TODO: support other types (links)
-						_ => {
-							eprintln!(
-								"  {} ({:?}) ignored",
-								&entry_path.to_string_lossy(),
-								&file_type
-							);
-							continue;
+						ArchiveFormat::Zip => extractor.zip(file)?,
+					}
+					let _ = remove_file(&archive_file);
+				} else {
+					// the archive is read as it is downloaded
+					let content = context.stream(&endpoint).with_context(|| {
+						format!("Failed to download {} archive of {}", tag, project)
+					})?;
+					match args.format {
+						ArchiveFormat::TarGz => {
+							extractor.tar(tar::Archive::new(GzDecoder::new(content)))?
+						}
+						ArchiveFormat::TarBz2 => {
+							extractor.tar(tar::Archive::new(BzDecoder::new(content)))?
 						}
+						ArchiveFormat::Zip => unreachable!(),
 					}
 				}
 
-				*lock.entry(project.to_owned()).or_default() = commit.to_owned();
+				if let Err(err) = write(&sha_file, &commit) {
+					eprintln!("  Can't write {}: {:?}", sha_file.to_string_lossy(), &err);
+				}
+				lock.insert(project.to_owned(), LockEntry::new(tag, &commit));
+			}
+			// the lockfile is left untouched when reproducing it
+			if !args.locked {
+				lock.save(args.update)?;
 			}
-			lock.save(args.update)?;
 
 			Ok(())
 		}
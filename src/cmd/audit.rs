@@ -0,0 +1,132 @@
+use crate::{
+	api::{access_requests::Source, audit_events::AuditEvents},
+	args::{self, AuditCmd, Format},
+	color::StyledStr,
+	context::CliContext,
+	utils::parse_date,
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use gitlab::{
+	api::{self, groups, Pagination, Query},
+	types,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Deserialize, Serialize)]
+struct AuditEvent {
+	author_id: u64,
+	created_at: DateTime<Utc>,
+	#[serde(default)]
+	details: Map<String, Value>,
+	/// other fields are kept as is for json and yaml outputs
+	#[serde(flatten)]
+	other: Map<String, Value>,
+}
+
+impl AuditEvent {
+	/// Get a detail of the event as a string
+	fn detail(&self, key: &str) -> Option<String> {
+		self.details.get(key).and_then(|value| match value {
+			Value::Null => None,
+			Value::String(value) => Some(value.to_owned()),
+			value => Some(value.to_string()),
+		})
+	}
+
+	/// Describe what has been changed
+	fn description(&self) -> String {
+		let mut description = if let Some(message) = self.detail("custom_message") {
+			message
+		} else if let Some(change) = self.detail("change") {
+			let mut description = format!("changed {}", change);
+			if let Some(from) = self.detail("from") {
+				description.push_str(&format!(" from {}", from));
+			}
+			if let Some(to) = self.detail("to") {
+				description.push_str(&format!(" to {}", to));
+			}
+			description
+		} else if let Some(add) = self.detail("add") {
+			format!("added {}", add)
+		} else if let Some(remove) = self.detail("remove") {
+			format!("removed {}", remove)
+		} else {
+			"unknown change".to_owned()
+		};
+		if let Some(target) = self.detail("target_details") {
+			description.push_str(&format!(" ({})", target));
+		}
+		description
+	}
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Audit) -> Result<()> {
+	match &args.cmd {
+		AuditCmd::List(cmd_args) => {
+			let (source, path) = if let Some(group) = &cmd_args.group {
+				let group: types::Group = groups::Group::builder()
+					.group(group.as_str())
+					.build()?
+					.query(&context.gitlab)
+					.with_context(|| format!("Can't find a group named {}", group))?;
+				(Source::Group(group.id.value().into()), group.full_path)
+			} else {
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				(
+					Source::Project(project.id.value().into()),
+					project.path_with_namespace,
+				)
+			};
+
+			let mut endpoint = AuditEvents::builder();
+			endpoint.source(source);
+			if let Some(since) = &cmd_args.since {
+				endpoint.created_after(parse_date(since)?);
+			}
+			let mut events: Vec<AuditEvent> = api::paged(endpoint.build()?, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to list audit events of {}", &path))?;
+			if let Some(author) = &cmd_args.author {
+				let user = context.get_user(author)?;
+				events.retain(|event| event.author_id == user.id.value());
+			}
+
+			match cmd_args.format {
+				Some(Format::Json) => println!("{}", serde_json::to_string_pretty(&events)?),
+				Some(Format::Yaml) => print!("{}", serde_yaml::to_string(&events)?),
+				Some(Format::Csv) => bail!("csv format is not supported for audit events"),
+				None => {
+					let mut msg = StyledStr::new();
+					if events.is_empty() {
+						msg.none("No audit events for ");
+						msg.literal(&path);
+						msg.none("\n");
+					}
+					for event in events.iter() {
+						msg.none("- [");
+						msg.literal(
+							timeago::Formatter::new().convert_chrono(event.created_at, Utc::now()),
+						);
+						msg.none("] ");
+						msg.literal(
+							event
+								.detail("author_name")
+								.unwrap_or_else(|| event.author_id.to_string()),
+						);
+						msg.none(format!(" {}", event.description()));
+						if let Some(ip) = event.detail("ip_address") {
+							msg.hint(format!(" from {}", ip));
+						}
+						msg.none("\n");
+					}
+					context.print_msg(msg)?;
+				}
+			}
+			Ok(())
+		}
+	}
+}
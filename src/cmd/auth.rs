@@ -0,0 +1,206 @@
+use crate::{
+	args::{self, AuthCmd, Opts},
+	config::{AuthType, Config, OAuth2Token},
+	git::GitProject,
+	oidc::login,
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::{Duration, Local, NaiveDate, Utc};
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize};
+
+#[derive(Deserialize)]
+struct User {
+	username: String,
+	name: String,
+}
+
+#[derive(Deserialize)]
+struct Job {
+	user: User,
+}
+
+/// Personal, project or group access token
+#[derive(Deserialize)]
+struct AccessToken {
+	name: String,
+	scopes: Vec<String>,
+	expires_at: Option<NaiveDate>,
+}
+
+#[derive(Deserialize)]
+struct OAuth2TokenInfo {
+	scope: Vec<String>,
+	expires_in: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct Version {
+	version: String,
+	revision: String,
+}
+
+/// Read the configuration of the selected host without connecting to it
+pub fn get_config(opts: &Opts) -> Result<Config> {
+	let repo = GitProject::detect(Config::remote(opts)?.as_deref());
+	Config::from_file(
+		opts.config.as_ref(),
+		opts.host.as_ref(),
+		&repo.as_ref().map(GitProject::hosts).unwrap_or_default(),
+		opts.verbose,
+	)?
+	.with_token_override(opts.token_stdin, opts.verbose)
+	.map(|config| config.with_connection_override(opts))
+}
+
+/// Authentication header to use for the configured host
+pub fn get_auth(config: &Config) -> Result<(&'static str, String)> {
+	Ok(match &config.auth {
+		AuthType::Token(token) => ("PRIVATE-TOKEN", token.to_owned()),
+		AuthType::OAuth2(_) => match OAuth2Token::from_cache(&config.host) {
+			Some(token) => ("Authorization", format!("Bearer {}", *token)),
+			None => bail!(
+				"Not logged in to {}. Use glctl auth login first",
+				&config.host
+			),
+		},
+		AuthType::JobToken(token) => ("JOB-TOKEN", token.to_owned()),
+	})
+}
+
+/// Query a path of the gitlab host, returning None if it doesn't succeed
+pub fn get<T: DeserializeOwned>(
+	config: &Config,
+	auth: &(&'static str, String),
+	path: &str,
+) -> Result<(StatusCode, Option<T>)> {
+	let rsp = config
+		.connection
+		.client()?
+		.get(format!("https://{}/{}", &config.host, path))
+		.header(auth.0, &auth.1)
+		.send()
+		.with_context(|| format!("Can't connect to {}", &config.host))?;
+	let status = rsp.status();
+	if status.is_success() {
+		let value = serde_json::from_reader(rsp)
+			.with_context(|| format!("Unexpected response from {}", path))?;
+		Ok((status, Some(value)))
+	} else {
+		Ok((status, None))
+	}
+}
+
+/// Command implementation. It runs before connecting to gitlab as the connection itself
+/// may need to login
+pub fn cmd(opts: &Opts, args: &args::Auth) -> Result<()> {
+	let config = get_config(opts)?;
+	match &args.cmd {
+		AuthCmd::Login(_) => {
+			let oauth2 = match &config.auth {
+				AuthType::OAuth2(oauth2) => oauth2,
+				AuthType::Token(_) | AuthType::JobToken(_) => bail!(
+					"{} is configured with an access token. Declare an OAuth application to login",
+					&config.host
+				),
+			};
+			if opts.no_cache {
+				bail!("The login can't be saved with --no-cache")
+			}
+
+			println!("Proceed to the login page https://{}", &config.host);
+			let token = login(&config.host, oauth2, opts)?;
+			if token.refresh_token().is_some() {
+				println!(
+					"Logged in to {}. The access token will be renewed automatically",
+					&config.host
+				);
+			} else {
+				println!("Logged in to {}", &config.host);
+			}
+			Ok(())
+		}
+
+		AuthCmd::Status(_) => {
+			let auth = get_auth(&config)?;
+			println!("Host:     {}", &config.host);
+
+			// check the token against the user it belongs to
+			let user = if let AuthType::JobToken(_) = &config.auth {
+				let (status, job) = get::<Job>(&config, &auth, "api/v4/job")?;
+				(status, job.map(|job| job.user))
+			} else {
+				get::<User>(&config, &auth, "api/v4/user")?
+			};
+			let user = match user {
+				(_, Some(user)) => user,
+				(StatusCode::UNAUTHORIZED, _) => bail!(
+					"The token is invalid, expired or revoked. {}",
+					match &config.auth {
+						AuthType::Token(_) =>
+							"Create a new access token and update the configuration",
+						AuthType::OAuth2(_) => "Login again with glctl auth login",
+						AuthType::JobToken(_) => "A job token is only valid while its job runs",
+					}
+				),
+				(StatusCode::FORBIDDEN, _) => bail!(
+					"The token is valid but not allowed to read the user. Check that it has the \
+					 api or read_user scope and that the user is not blocked"
+				),
+				(status, _) => bail!("Unable to check the token: {}", status),
+			};
+			println!("User:     {} ({})", &user.username, &user.name);
+
+			match &config.auth {
+				AuthType::Token(_) => {
+					if let (_, Some(token)) =
+						get::<AccessToken>(&config, &auth, "api/v4/personal_access_tokens/self")?
+					{
+						println!("Token:    {}", &token.name);
+						println!("Scopes:   {}", token.scopes.join(", "));
+						println!(
+							"Expires:  {}",
+							token
+								.expires_at
+								.map(|date| date.to_string())
+								.unwrap_or_else(|| "never".to_owned())
+						);
+					}
+				}
+				AuthType::OAuth2(_) => {
+					if let (_, Some(info)) =
+						get::<OAuth2TokenInfo>(&config, &auth, "oauth/token/info")?
+					{
+						println!("Token:    OAuth2");
+						println!("Scopes:   {}", info.scope.join(", "));
+						println!(
+							"Expires:  {}",
+							info.expires_in
+								.map(|seconds| (Utc::now() + Duration::seconds(seconds))
+									.with_timezone(&Local)
+									.format("%Y-%m-%d %H:%M")
+									.to_string())
+								.unwrap_or_else(|| "never".to_owned())
+						);
+					}
+				}
+				AuthType::JobToken(_) => println!("Token:    CI job token"),
+			}
+
+			if let (_, Some(version)) = get::<Version>(&config, &auth, "api/v4/version")? {
+				println!("Version:  {} ({})", &version.version, &version.revision);
+			}
+			Ok(())
+		}
+
+		AuthCmd::Logout(_) => {
+			if OAuth2Token::remove(&config.host)? {
+				println!("Logged out from {}", &config.host);
+			} else {
+				println!("Not logged in to {}", &config.host);
+			}
+			Ok(())
+		}
+	}
+}
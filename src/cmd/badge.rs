@@ -0,0 +1,113 @@
+use crate::{
+	api::badges::{Badges, CreateBadge, DeleteBadge},
+	args::{self, BadgeCmd},
+	color::StyledStr,
+	context::CliContext,
+};
+
+use anyhow::{Context, Result};
+use gitlab::api::{self, Pagination, Query};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Badge {
+	id: u64,
+	name: Option<String>,
+	kind: String,
+	link_url: String,
+	image_url: String,
+	rendered_link_url: String,
+	rendered_image_url: String,
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Badge) -> Result<()> {
+	match &args.cmd {
+		BadgeCmd::List(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = Badges::builder().project(project.id.value()).build()?;
+			let badges: Vec<Badge> = api::paged(endpoint, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to list badges of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+
+			let mut msg = StyledStr::new();
+			if badges.is_empty() {
+				msg.none("No badges found for ");
+				msg.literal(&project.path_with_namespace);
+				msg.none("\n");
+			}
+			for badge in badges.iter() {
+				msg.none("- Badge ");
+				msg.literal(badge.id.to_string());
+				if let Some(name) = &badge.name {
+					msg.none(format!(" {}", name));
+				}
+				msg.hint(format!(" ({})", badge.kind));
+				msg.none("\n");
+				// show the placeholders in verbose mode and the rendered urls otherwise
+				if context.verbose {
+					msg.none(format!("  link:  {}\n", badge.link_url));
+					msg.none(format!("  image: {}\n", badge.image_url));
+				} else {
+					msg.none(format!("  link:  {}\n", badge.rendered_link_url));
+					msg.none(format!("  image: {}\n", badge.rendered_image_url));
+				}
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/edit#js-general-settings", project.web_url));
+			}
+			Ok(())
+		}
+
+		BadgeCmd::Add(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut endpoint = CreateBadge::builder();
+			endpoint
+				.project(project.id.value())
+				.link_url(cmd_args.link_url.as_str())
+				.image_url(cmd_args.image_url.as_str());
+			if let Some(name) = &cmd_args.name {
+				endpoint.name(name.as_str());
+			}
+			let badge: Badge = endpoint.build()?.query(&context.gitlab).with_context(|| {
+				format!(
+					"Failed to add badge on project {}",
+					&project.path_with_namespace
+				)
+			})?;
+			println!(
+				"badge {} has been added on project {}",
+				badge.id, &project.path_with_namespace
+			);
+			Ok(())
+		}
+
+		BadgeCmd::Remove(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = DeleteBadge::builder()
+				.project(project.id.value())
+				.badge(cmd_args.id)
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to remove badge {} from project {}",
+						cmd_args.id, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"badge {} has been removed from project {}",
+				cmd_args.id, &project.path_with_namespace
+			);
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,101 @@
+use crate::{api::repository::FileBlame, args, color::StyledStr, context::CliContext};
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use gitlab::api::Query;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct BlameRange {
+	commit: BlameCommit,
+	lines: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct BlameCommit {
+	id: String,
+	author_name: String,
+	authored_date: DateTime<Utc>,
+}
+
+/// parse a line range like 10:20 given on the command line
+fn parse_range(range: &str) -> Result<(u64, u64)> {
+	let (start, end) = range
+		.split_once(':')
+		.ok_or_else(|| anyhow!("Invalid range {}. Use L1:L2", range))?;
+	let start: u64 = start
+		.parse()
+		.with_context(|| format!("Invalid range {}. Use L1:L2", range))?;
+	let end: u64 = end
+		.parse()
+		.with_context(|| format!("Invalid range {}. Use L1:L2", range))?;
+	if start == 0 || end < start {
+		bail!(
+			"Invalid range {}. Lines start at 1 and L2 can't be lower than L1",
+			range
+		)
+	}
+	Ok((start, end))
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Blame) -> Result<()> {
+	let project = context.get_project(args.project.as_ref())?;
+	let ref_ = args
+		.ref_
+		.as_deref()
+		.or(project.default_branch.as_deref())
+		.unwrap_or("HEAD");
+	let mut endpoint = FileBlame::builder();
+	endpoint
+		.project(project.id.value())
+		.file_path(args.path.as_str())
+		.ref_(ref_);
+	let first = match &args.range {
+		Some(range) => {
+			let range = parse_range(range)?;
+			endpoint.range(range);
+			range.0
+		}
+		None => 1,
+	};
+	let ranges: Vec<BlameRange> = endpoint.build()?.query(&context.gitlab).with_context(|| {
+		format!(
+			"Failed to blame {} at {} in project {}",
+			&args.path, ref_, &project.path_with_namespace
+		)
+	})?;
+
+	let width = ranges
+		.iter()
+		.map(|range| range.commit.author_name.chars().count())
+		.max()
+		.unwrap_or(0);
+	let count: usize = ranges.iter().map(|range| range.lines.len()).sum();
+	let number_width = (first as usize + count).to_string().len();
+	let mut number = first as usize;
+	let mut msg = StyledStr::new();
+	for range in ranges.iter() {
+		for line in range.lines.iter() {
+			msg.literal(&range.commit.id[..range.commit.id.len().min(8)]);
+			msg.hint(format!(
+				" ({:<width$} {} {:>number_width$}) ",
+				&range.commit.author_name,
+				range.commit.authored_date.format("%Y-%m-%d"),
+				number
+			));
+			msg.none(line);
+			msg.none("\n");
+			number += 1;
+		}
+	}
+	context.print_msg(msg)?;
+
+	if context.open {
+		let _ = open::that(format!(
+			"{}/-/blame/{}/{}",
+			&project.web_url, ref_, &args.path
+		));
+	}
+	Ok(())
+}
@@ -0,0 +1,221 @@
+use crate::{
+	api::ci::LintCi,
+	args::{self, CiCmd},
+	color::StyledStr,
+	context::CliContext,
+	utils::shell_quote,
+};
+
+use anyhow::{bail, Context, Result};
+use gitlab::{api::Query, types};
+use serde::Deserialize;
+use std::{
+	fs::{create_dir_all, read_to_string, write},
+	io::Write,
+	path::Path,
+	process::{Command, Stdio},
+};
+
+/// Marker identifying the hooks installed by glctl
+const HOOK_MARKER: &str = "# installed by glctl ci install-hook";
+
+#[derive(Deserialize)]
+struct LintResult {
+	valid: bool,
+	#[serde(default)]
+	errors: Vec<String>,
+	#[serde(default)]
+	warnings: Vec<String>,
+	merged_yaml: Option<String>,
+}
+
+/// Find the line of the configuration an error message refers to, either by an explicit
+/// line number or by the name of the job it is about
+fn error_line(content: &str, error: &str) -> Option<usize> {
+	if let Some((_, rest)) = error.split_once("line ") {
+		let number: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+		if let Ok(line) = number.parse::<usize>() {
+			return Some(line);
+		}
+	}
+	let job = error
+		.strip_prefix("jobs:")?
+		.split(|c: char| c == ':' || c.is_whitespace())
+		.next()?;
+	let job = format!("{}:", job);
+	content
+		.lines()
+		.position(|line| line.starts_with(&job))
+		.map(|i| i + 1)
+}
+
+/// Append an error or a warning to the message, followed by the line it refers to
+fn lint_msg(msg: &mut StyledStr, content: &str, text: &str, error: bool) {
+	if error {
+		msg.error("error: ");
+	} else {
+		msg.warning("warning: ");
+	}
+	msg.none(text);
+	msg.none("\n");
+	if let Some(line) = error_line(content, text) {
+		if let Some(source) = content.lines().nth(line.saturating_sub(1)) {
+			msg.hint(format!("{:>5} | ", line));
+			msg.literal(source);
+			msg.none("\n");
+		}
+	}
+}
+
+/// Validate a configuration against a project
+fn lint(
+	context: &CliContext,
+	project: &types::Project,
+	path: &str,
+	content: &str,
+	ref_: Option<&String>,
+) -> Result<LintResult> {
+	let mut endpoint = LintCi::builder();
+	endpoint
+		.project(project.id.value())
+		.content(content)
+		.include_merged_yaml(true);
+	if let Some(ref_) = ref_ {
+		endpoint.ref_(ref_.as_str());
+	}
+	endpoint.build()?.query(&context.gitlab).with_context(|| {
+		format!(
+			"Failed to lint {} for project {}",
+			path, &project.path_with_namespace
+		)
+	})
+}
+
+/// Print the errors and warnings of a validation
+fn print_lint(context: &CliContext, result: &LintResult, path: &str, content: &str) -> Result<()> {
+	let mut msg = StyledStr::new();
+	for error in result.errors.iter() {
+		lint_msg(&mut msg, content, error, true);
+	}
+	for warning in result.warnings.iter() {
+		lint_msg(&mut msg, content, warning, false);
+	}
+	if !result.valid {
+		context.print_msg(msg)?;
+		bail!("{} is invalid", path)
+	}
+	context.print_msg(msg)
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Ci) -> Result<()> {
+	match &args.cmd {
+		CiCmd::Lint(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let content = read_to_string(&cmd_args.path)
+				.with_context(|| format!("Can't read {}", &cmd_args.path))?;
+
+			let result = lint(
+				context,
+				&project,
+				&cmd_args.path,
+				&content,
+				cmd_args.ref_.as_ref(),
+			)?;
+			print_lint(context, &result, &cmd_args.path, &content)?;
+
+			let mut msg = StyledStr::new();
+			msg.literal(&cmd_args.path);
+			msg.good(" is valid\n");
+			context.print_msg(msg)
+		}
+
+		CiCmd::View(cmd_args) => {
+			let content = read_to_string(&cmd_args.path)
+				.with_context(|| format!("Can't read {}", &cmd_args.path))?;
+			if !cmd_args.merged && !cmd_args.diff {
+				print!("{}", content);
+				return Ok(());
+			}
+
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let result = lint(context, &project, &cmd_args.path, &content, None)?;
+			// warnings would be mixed with the configuration
+			if !result.valid {
+				print_lint(context, &result, &cmd_args.path, &content)?;
+			}
+			let merged = result.merged_yaml.unwrap_or_default();
+			if !cmd_args.diff {
+				print!("{}", merged);
+				return Ok(());
+			}
+
+			// diff exits with 1 when files differ and 2 on trouble
+			let mut child = Command::new("diff")
+				.args(["-u", "--label", &cmd_args.path, "--label", "merged"])
+				.arg(&cmd_args.path)
+				.arg("-")
+				.stdin(Stdio::piped())
+				.spawn()
+				.context("Failed to run diff")?;
+			if let Some(mut stdin) = child.stdin.take() {
+				stdin.write_all(merged.as_bytes())?;
+			}
+			let status = child.wait().context("Failed to run diff")?;
+			if status.code() == Some(2) {
+				bail!(
+					"Failed to compare {} with the merged configuration",
+					&cmd_args.path
+				)
+			}
+			Ok(())
+		}
+
+		CiCmd::InstallHook(cmd_args) => {
+			// ask git to honor core.hooksPath and worktrees
+			let output = Command::new("git")
+				.args(["rev-parse", "--git-path", "hooks"])
+				.output()
+				.context("Failed to run git")?;
+			if !output.status.success() {
+				bail!("Not inside a git repository")
+			}
+			let hooks = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+			let hook = Path::new(&hooks).join("pre-push");
+			if hook.exists() && !cmd_args.force {
+				let installed = read_to_string(&hook)
+					.map(|content| content.contains(HOOK_MARKER))
+					.unwrap_or_default();
+				if !installed {
+					bail!(
+						"{} already exists. Use --force to overwrite it",
+						hook.display()
+					)
+				}
+			}
+
+			let mut script = format!("#!/bin/sh\n{}\nset -e\n", HOOK_MARKER);
+			script.push_str(&format!(
+				"if [ -f {0} ]; then\n\tglctl ci lint {0}\nfi\n",
+				shell_quote(&cmd_args.path)
+			));
+			for command in cmd_args.command.iter() {
+				script.push_str(&format!("glctl {}\n", command));
+			}
+			create_dir_all(&hooks).with_context(|| format!("Can't create {}", &hooks))?;
+			write(&hook, script).with_context(|| format!("Can't write {}", hook.display()))?;
+			#[cfg(unix)]
+			{
+				use std::{
+					fs::{set_permissions, Permissions},
+					os::unix::fs::PermissionsExt,
+				};
+				set_permissions(&hook, Permissions::from_mode(0o755))
+					.with_context(|| format!("Can't make {} executable", hook.display()))?;
+			}
+
+			println!("pre-push hook installed in {}", hook.display());
+			Ok(())
+		}
+	}
+}
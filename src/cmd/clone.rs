@@ -0,0 +1,90 @@
+use crate::{
+	args::{self, Protocol},
+	context::CliContext,
+};
+
+use anyhow::{bail, Context, Result};
+use std::{fs, path::Path, process::Command};
+
+/// Credential helper answering git with the username and password of the environment, so the
+/// token appears neither in the command line nor in the remote url
+const CREDENTIAL_HELPER: &str =
+	"!f() { test \"$1\" = get && echo \"username=$GLCTL_GIT_USERNAME\" \
+	 && echo \"password=$GLCTL_GIT_PASSWORD\"; }; f";
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::GitClone) -> Result<()> {
+	let project = context.get_project(Some(&args.project))?;
+	let dir = Path::new(args.dir.as_deref().unwrap_or(&project.path));
+	let protocol = args
+		.protocol
+		.or(context.config.settings.clone_protocol)
+		.unwrap_or(Protocol::Ssh);
+
+	let mut cmd = Command::new("git");
+	let url = match protocol {
+		Protocol::Ssh => &project.ssh_url_to_repo,
+		Protocol::Https => {
+			let (username, password) = context.git_credentials();
+			let connection = &context.config.connection;
+			// replace the configured credential helpers by ours for the clone only
+			cmd.args(["-c", "credential.helper="])
+				.arg("-c")
+				.arg(format!("credential.helper={}", CREDENTIAL_HELPER))
+				.env("GLCTL_GIT_USERNAME", username)
+				.env("GLCTL_GIT_PASSWORD", password);
+			if let Some(ca_cert) = &connection.ca_cert {
+				cmd.arg("-c").arg(format!("http.sslCAInfo={}", ca_cert));
+			}
+			if connection.insecure {
+				cmd.args(["-c", "http.sslVerify=false"]);
+			}
+			&project.http_url_to_repo
+		}
+	};
+	cmd.arg("clone");
+	if let Some(branch) = &args.branch {
+		cmd.arg("--branch").arg(branch);
+	}
+	if let Some(depth) = args.depth {
+		cmd.arg("--depth").arg(depth.to_string());
+	}
+	cmd.arg(url).arg(dir);
+	if context.verbose {
+		println!("Cloning {} into {}", url, dir.display());
+	}
+	let status = cmd.status().with_context(|| "Unable to run git")?;
+	if !status.success() {
+		bail!("Failed to clone {}", &project.path_with_namespace)
+	}
+	println!(
+		"{} has been cloned into {}",
+		&project.path_with_namespace,
+		dir.display()
+	);
+
+	if args.save_defaults {
+		let path = dir.join(".glctl.yaml");
+		if path.exists() {
+			println!(
+				"{} already exists in the repository and has been kept",
+				path.display()
+			);
+		} else {
+			fs::write(
+				&path,
+				format!(
+					"# project used instead of the one of the git remote\nproject: {}\n",
+					&project.path_with_namespace
+				),
+			)
+			.with_context(|| format!("Can't write {}", path.display()))?;
+			println!("{} has been written", path.display());
+		}
+	}
+
+	if context.open {
+		let _ = open::that(&project.web_url);
+	}
+	Ok(())
+}
@@ -0,0 +1,610 @@
+use crate::{
+	api::repository::{CherryPickCommit, CommitDiff, Commits, RevertCommit},
+	args::{self, CommitsCmd, CommitsStatusCmd, Format},
+	color::StyledStr,
+	context::{status_style, CliContext},
+	utils::{csv_field, parse_date},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use gitlab::{
+	api::{
+		self,
+		projects::{
+			pipelines::Pipelines,
+			repository::commits::{self, CommitStatusState, CommitStatuses, CreateCommitStatus},
+		},
+		ApiError, Pagination, Query,
+	},
+	types, StatusState,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+struct Commit {
+	id: String,
+	short_id: String,
+	title: String,
+	message: String,
+	author_name: String,
+	author_email: String,
+	authored_date: DateTime<Utc>,
+	#[serde(default)]
+	stats: Option<CommitStats>,
+}
+
+#[derive(Deserialize)]
+struct CommitStats {
+	additions: u64,
+	deletions: u64,
+}
+
+/// Contribution of an author
+#[derive(Default, Serialize)]
+struct AuthorStats {
+	name: String,
+	email: String,
+	commits: u64,
+	additions: u64,
+	deletions: u64,
+}
+
+#[derive(Deserialize)]
+struct CommitStatus {
+	name: String,
+	status: StatusState,
+	#[serde(rename = "ref")]
+	ref_: Option<String>,
+	description: Option<String>,
+	target_url: Option<String>,
+	#[serde(default)]
+	allow_failure: bool,
+	created_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct FileDiff {
+	old_path: String,
+	new_path: String,
+	diff: String,
+	new_file: bool,
+	renamed_file: bool,
+	deleted_file: bool,
+}
+
+impl FileDiff {
+	/// Number of added and deleted lines
+	fn changes(&self) -> (usize, usize) {
+		self.diff.lines().fold((0, 0), |(added, deleted), line| {
+			if line.starts_with('+') {
+				(added + 1, deleted)
+			} else if line.starts_with('-') {
+				(added, deleted + 1)
+			} else {
+				(added, deleted)
+			}
+		})
+	}
+
+	/// Path of the file, showing both paths on renames
+	fn path(&self) -> String {
+		if self.renamed_file {
+			format!("{} => {}", &self.old_path, &self.new_path)
+		} else {
+			self.new_path.to_owned()
+		}
+	}
+}
+
+/// Append the number of changed lines per file to the message
+fn stat_msg(msg: &mut StyledStr, diffs: &[FileDiff]) {
+	let width = diffs
+		.iter()
+		.map(|diff| diff.path().len())
+		.max()
+		.unwrap_or(0);
+	for diff in diffs.iter() {
+		let (added, deleted) = diff.changes();
+		msg.none(format!(
+			" {:<width$} | {:>5} ",
+			diff.path(),
+			added + deleted
+		));
+		// keep the bar within a terminal line
+		let scale = ((added + deleted) as f64 / 50.0).max(1.0);
+		msg.good("+".repeat((added as f64 / scale).ceil() as usize));
+		msg.error("-".repeat((deleted as f64 / scale).ceil() as usize));
+		msg.none("\n");
+	}
+}
+
+/// Append the patch of the files to the message
+fn patch_msg(msg: &mut StyledStr, diffs: &[FileDiff]) {
+	for diff in diffs.iter() {
+		msg.literal(format!("diff a/{} b/{}", &diff.old_path, &diff.new_path));
+		if diff.new_file {
+			msg.hint(" (new file)");
+		} else if diff.deleted_file {
+			msg.hint(" (deleted file)");
+		}
+		msg.none("\n");
+		for line in diff.diff.lines() {
+			if line.starts_with("@@") {
+				msg.hint(line);
+			} else if line.starts_with('+') {
+				msg.good(line);
+			} else if line.starts_with('-') {
+				msg.error(line);
+			} else {
+				msg.none(line);
+			}
+			msg.none("\n");
+		}
+	}
+}
+
+/// parse a commit status state given on the command line
+fn parse_state(state: &str) -> Result<CommitStatusState> {
+	match state {
+		"pending" => Ok(CommitStatusState::Pending),
+		"running" => Ok(CommitStatusState::Running),
+		"success" => Ok(CommitStatusState::Success),
+		"failed" => Ok(CommitStatusState::Failed),
+		"canceled" => Ok(CommitStatusState::Canceled),
+		_ => bail!(
+			"{} not supported for --state. Use either \"pending\", \"running\", \"success\", \"failed\" or \"canceled\"",
+			state
+		),
+	}
+}
+
+/// Report the outcome of a cherry-pick or a revert, explaining how to handle conflicts
+fn apply_msg<E>(
+	result: Result<Commit, ApiError<E>>,
+	operation: &str,
+	sha: &str,
+	branch: &str,
+	project: &types::Project,
+	dry_run: bool,
+) -> Result<()>
+where
+	E: std::error::Error + Send + Sync + 'static,
+{
+	// a dry run returns {"dry_run": "success"} which is not a commit
+	let result = match result {
+		Err(ApiError::DataType { .. }) if dry_run => Ok(None),
+		result => result.map(Some),
+	};
+	match result {
+		Ok(Some(commit)) => {
+			println!(
+				"commit {} has been {}ed on branch {} of project {} as {}",
+				sha,
+				operation,
+				branch,
+				&project.path_with_namespace,
+				&commit.short_id
+			);
+			Ok(())
+		}
+		Ok(None) => {
+			println!(
+				"commit {} can be {}ed on branch {} of project {}",
+				sha,
+				operation,
+				branch,
+				&project.path_with_namespace
+			);
+			Ok(())
+		}
+		// conflicts and empty changes are reported as plain messages
+		Err(ApiError::Gitlab { msg }) if !msg.starts_with("404") => Err(anyhow!(
+			"Can't {} commit {} on branch {} of project {}: {}\nRun \"git {} {}\" in a local clone to resolve the conflicts",
+			operation,
+			sha,
+			branch,
+			&project.path_with_namespace,
+			msg,
+			operation,
+			sha
+		)),
+		Err(err) => Err(err).with_context(|| {
+			format!(
+				"Failed to {} commit {} on branch {} of project {}",
+				operation, sha, branch, &project.path_with_namespace
+			)
+		}),
+	}
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Commits) -> Result<()> {
+	match &args.cmd {
+		CommitsCmd::List(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut endpoint = Commits::builder();
+			endpoint.project(project.id.value());
+			if let Some(ref_) = &cmd_args.ref_ {
+				endpoint.ref_name(ref_.as_str());
+			}
+			if let Some(since) = &cmd_args.since {
+				endpoint.since(parse_date(since)?);
+			}
+			if let Some(author) = &cmd_args.author {
+				endpoint.author(author.as_str());
+			}
+			if let Some(path) = &cmd_args.path {
+				endpoint.path(path.as_str());
+			}
+			let commits: Vec<Commit> =
+				api::paged(endpoint.build()?, Pagination::Limit(cmd_args.limit))
+					.query(&context.gitlab)
+					.with_context(|| {
+						format!(
+							"Failed to list commits of project {}",
+							&project.path_with_namespace
+						)
+					})?;
+
+			let mut msg = StyledStr::new();
+			if commits.is_empty() {
+				msg.none("No commits for ");
+				msg.literal(&project.path_with_namespace);
+				msg.none("\n");
+			}
+			for commit in commits.iter() {
+				msg.literal(&commit.short_id);
+				msg.none(format!(" {}", &commit.title));
+				msg.hint(format!(
+					" ({}, {})",
+					&commit.author_name,
+					timeago::Formatter::new().convert_chrono(commit.authored_date, Utc::now())
+				));
+				msg.none("\n");
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let ref_ = cmd_args
+					.ref_
+					.as_deref()
+					.or(project.default_branch.as_deref())
+					.unwrap_or("HEAD");
+				let _ = open::that(format!("{}/-/commits/{}", &project.web_url, ref_));
+			}
+			Ok(())
+		}
+
+		CommitsCmd::Show(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let commit: Commit = commits::Commit::builder()
+				.project(project.id.value())
+				.commit(cmd_args.sha.as_str())
+				.stats(true)
+				.build()?
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to get commit {} of project {}",
+						&cmd_args.sha, &project.path_with_namespace
+					)
+				})?;
+			let endpoint = CommitDiff::builder()
+				.project(project.id.value())
+				.commit(commit.id.as_str())
+				.build()?;
+			let diffs: Vec<FileDiff> = api::paged(endpoint, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to get the diff of commit {} of project {}",
+						&commit.short_id, &project.path_with_namespace
+					)
+				})?;
+
+			let mut msg = StyledStr::new();
+			msg.none("commit ");
+			msg.literal(&commit.id);
+			msg.none("\n");
+			msg.none(format!(
+				"Author: {} <{}>\n",
+				&commit.author_name, &commit.author_email
+			));
+			msg.none(format!(
+				"Date:   {} ",
+				commit.authored_date.format("%Y-%m-%d %H:%M:%S")
+			));
+			msg.hint(format!(
+				"({})",
+				timeago::Formatter::new().convert_chrono(commit.authored_date, Utc::now())
+			));
+			msg.none("\n\n");
+			for line in commit.message.trim_end().lines() {
+				msg.none(format!("    {}\n", line));
+			}
+			msg.none("\n");
+
+			if cmd_args.stat {
+				stat_msg(&mut msg, &diffs);
+				msg.none(format!(" {} files changed", diffs.len()));
+				if let Some(stats) = &commit.stats {
+					msg.good(format!(", {} insertions(+)", stats.additions));
+					msg.error(format!(", {} deletions(-)", stats.deletions));
+				}
+				msg.none("\n");
+				if cmd_args.patch {
+					msg.none("\n");
+				}
+			}
+			if cmd_args.patch || !cmd_args.stat {
+				patch_msg(&mut msg, &diffs);
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/-/commit/{}", &project.web_url, &commit.id));
+			}
+			Ok(())
+		}
+
+		CommitsCmd::Status(args) => match &args.cmd {
+			CommitsStatusCmd::Set(cmd_args) => {
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				let mut endpoint = CreateCommitStatus::builder();
+				endpoint
+					.project(project.id.value())
+					.commit(cmd_args.sha.as_str())
+					.state(parse_state(&cmd_args.state)?)
+					.name(cmd_args.name.as_str());
+				if let Some(ref_) = &cmd_args.ref_ {
+					endpoint.ref_(ref_.as_str());
+				}
+				if let Some(url) = &cmd_args.target_url {
+					endpoint.target_url(url.as_str());
+				}
+				if let Some(description) = &cmd_args.description {
+					endpoint.description(description.as_str());
+				}
+				if let Some(coverage) = cmd_args.coverage {
+					endpoint.coverage(coverage);
+				}
+				api::ignore(endpoint.build()?)
+					.query(&context.gitlab)
+					.with_context(|| {
+						format!(
+							"Failed to set status {} on commit {} of project {}",
+							&cmd_args.name, &cmd_args.sha, &project.path_with_namespace
+						)
+					})?;
+				println!(
+					"status {} of commit {} of project {} is now {}",
+					&cmd_args.name, &cmd_args.sha, &project.path_with_namespace, &cmd_args.state
+				);
+				Ok(())
+			}
+
+			CommitsStatusCmd::List(cmd_args) => {
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				let endpoint = Pipelines::builder()
+					.project(project.id.value())
+					.sha(cmd_args.sha.as_str())
+					.build()?;
+				let pipelines: Vec<types::PipelineBasic> = api::paged(endpoint, Pagination::All)
+					.query(&context.gitlab)
+					.with_context(|| {
+						format!(
+							"Failed to get pipelines of commit {} of project {}",
+							&cmd_args.sha, &project.path_with_namespace
+						)
+					})?;
+				let endpoint = CommitStatuses::builder()
+					.project(project.id.value())
+					.commit(cmd_args.sha.as_str())
+					.all(cmd_args.all)
+					.build()?;
+				let statuses: Vec<CommitStatus> = api::paged(endpoint, Pagination::All)
+					.query(&context.gitlab)
+					.with_context(|| {
+						format!(
+							"Failed to get statuses of commit {} of project {}",
+							&cmd_args.sha, &project.path_with_namespace
+						)
+					})?;
+
+				let mut msg = StyledStr::new();
+				if pipelines.is_empty() && statuses.is_empty() {
+					msg.none("No statuses for commit ");
+					msg.literal(&cmd_args.sha);
+					msg.none("\n");
+				}
+				if !pipelines.is_empty() {
+					msg.hint("pipelines:\n");
+				}
+				for pipeline in pipelines.iter() {
+					msg.none("- ");
+					msg.literal(format!("#{}", pipeline.id.value()));
+					if let Some(ref_) = &pipeline.ref_ {
+						msg.none(format!(" ({})", ref_));
+					}
+					msg.none(" - ");
+					msg.stylize(
+						status_style(pipeline.status),
+						format!("{:?}", pipeline.status),
+					);
+					if let Some(created_at) = pipeline.created_at {
+						msg.none(format!(
+							" {}",
+							timeago::Formatter::new().convert_chrono(created_at, Utc::now())
+						));
+					}
+					if context.url {
+						msg.hint(format!(" ({})", pipeline.web_url));
+					}
+					msg.none("\n");
+				}
+				if !statuses.is_empty() {
+					msg.hint("statuses:\n");
+				}
+				for status in statuses.iter() {
+					msg.none("- ");
+					msg.literal(&status.name);
+					if let Some(ref_) = &status.ref_ {
+						msg.none(format!(" ({})", ref_));
+					}
+					msg.none(" - ");
+					msg.stylize(status_style(status.status), format!("{:?}", status.status));
+					if status.allow_failure {
+						msg.hint(" (allowed to fail)");
+					}
+					msg.none(format!(
+						" {}",
+						timeago::Formatter::new().convert_chrono(status.created_at, Utc::now())
+					));
+					if let Some(description) = &status.description {
+						msg.none(format!(": {}", description));
+					}
+					if context.url {
+						if let Some(url) = &status.target_url {
+							msg.hint(format!(" ({})", url));
+						}
+					}
+					msg.none("\n");
+				}
+				context.print_msg(msg)
+			}
+		},
+
+		CommitsCmd::CherryPick(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut endpoint = CherryPickCommit::builder();
+			endpoint
+				.project(project.id.value())
+				.commit(cmd_args.sha.as_str())
+				.branch(cmd_args.to.as_str())
+				.dry_run(cmd_args.dry_run);
+			if let Some(message) = &cmd_args.message {
+				endpoint.message(message.as_str());
+			}
+			apply_msg(
+				endpoint.build()?.query(&context.gitlab),
+				"cherry-pick",
+				&cmd_args.sha,
+				&cmd_args.to,
+				&project,
+				cmd_args.dry_run,
+			)
+		}
+
+		CommitsCmd::Revert(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = RevertCommit::builder()
+				.project(project.id.value())
+				.commit(cmd_args.sha.as_str())
+				.branch(cmd_args.to.as_str())
+				.dry_run(cmd_args.dry_run)
+				.build()?;
+			apply_msg(
+				endpoint.query(&context.gitlab),
+				"revert",
+				&cmd_args.sha,
+				&cmd_args.to,
+				&project,
+				cmd_args.dry_run,
+			)
+		}
+
+		CommitsCmd::Stats(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut endpoint = Commits::builder();
+			endpoint.project(project.id.value()).with_stats(true);
+			if let Some(ref_) = &cmd_args.ref_ {
+				endpoint.ref_name(ref_.as_str());
+			}
+			if let Some(since) = &cmd_args.since {
+				endpoint.since(parse_date(since)?);
+			}
+			let commits: Vec<Commit> = api::paged(endpoint.build()?, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to list commits of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+
+			// authors are identified by their email like in git shortlog -e
+			let mut authors: Vec<AuthorStats> = Vec::new();
+			for commit in commits.iter() {
+				let i = match authors
+					.iter()
+					.position(|author| author.email.eq_ignore_ascii_case(&commit.author_email))
+				{
+					Some(i) => i,
+					None => {
+						authors.push(AuthorStats {
+							name: commit.author_name.to_owned(),
+							email: commit.author_email.to_owned(),
+							..Default::default()
+						});
+						authors.len() - 1
+					}
+				};
+				let author = &mut authors[i];
+				author.commits += 1;
+				if let Some(stats) = &commit.stats {
+					author.additions += stats.additions;
+					author.deletions += stats.deletions;
+				}
+			}
+			authors.sort_by(|a, b| b.commits.cmp(&a.commits).then(a.name.cmp(&b.name)));
+
+			match cmd_args.format {
+				Some(Format::Json) => println!("{}", serde_json::to_string_pretty(&authors)?),
+				Some(Format::Yaml) => print!("{}", serde_yaml::to_string(&authors)?),
+				Some(Format::Csv) => {
+					println!("name,email,commits,additions,deletions");
+					for author in authors.iter() {
+						println!(
+							"{},{},{},{},{}",
+							csv_field(&author.name),
+							csv_field(&author.email),
+							author.commits,
+							author.additions,
+							author.deletions
+						);
+					}
+				}
+				None => {
+					let mut msg = StyledStr::new();
+					if authors.is_empty() {
+						msg.none("No commits for ");
+						msg.literal(&project.path_with_namespace);
+						msg.none("\n");
+						return context.print_msg(msg);
+					}
+					let name_width = authors
+						.iter()
+						.map(|author| author.name.chars().count())
+						.max()
+						.unwrap_or_default()
+						.max("AUTHOR".len());
+					msg.hint(format!(
+						"{:<name_width$}  {:>7}  {:>9}  {:>9}\n",
+						"AUTHOR", "COMMITS", "ADDITIONS", "DELETIONS"
+					));
+					for author in authors.iter() {
+						msg.literal(format!("{:<name_width$}", author.name));
+						msg.none(format!("  {:>7}  ", author.commits));
+						msg.good(format!("{:>9}", format!("+{}", author.additions)));
+						msg.none("  ");
+						msg.error(format!("{:>9}", format!("-{}", author.deletions)));
+						msg.none("\n");
+					}
+					context.print_msg(msg)?;
+				}
+			}
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,404 @@
+use crate::{
+	args::{self, ColorChoice, ConfigCmd, Opts},
+	client::GitlabClient,
+	cmd::auth::{get, get_auth, get_config},
+	config::{create_private_file, AuthType, Config, Connection, OAuth2, OAuth2Token, Settings},
+	oidc::login,
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::{Duration, Local, NaiveDate};
+use gitlab::api::{projects, users::CurrentUser, Query};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::{
+	io::{self, Write},
+	path::PathBuf,
+};
+
+/// Configuration written by the init wizard
+#[derive(Serialize)]
+struct NewConfig {
+	host: String,
+	auth: AuthType,
+	#[serde(flatten)]
+	connection: Connection,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	color: Option<ColorChoice>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	project: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct User {
+	username: String,
+}
+
+#[derive(Deserialize)]
+struct Project {
+	path_with_namespace: String,
+}
+
+#[derive(Deserialize)]
+struct AccessToken {
+	expires_at: Option<NaiveDate>,
+}
+
+/// Number of days before the expiration of an access token to warn about it
+const EXPIRATION_WARNING_DAYS: i64 = 7;
+
+/// Counters of the problems found while checking the configuration
+#[derive(Default)]
+struct Report {
+	errors: usize,
+	warnings: usize,
+}
+
+impl Report {
+	fn ok(&self, msg: &str) {
+		println!("  ok: {}", msg);
+	}
+
+	fn warning(&mut self, msg: &str) {
+		self.warnings += 1;
+		println!("  warning: {}", msg);
+	}
+
+	fn error(&mut self, msg: &str) {
+		self.errors += 1;
+		println!("  error: {}", msg);
+	}
+}
+
+/// Check that a host is reachable and that its authentication is valid
+fn check_host(config: &Config, report: &mut Report) -> Result<()> {
+	match &config.profile {
+		Some(profile) => println!("Host {} ({})", &config.host, profile),
+		None => println!("Host {}", &config.host),
+	}
+
+	match &config.auth {
+		AuthType::Token(token) if token.trim().is_empty() => {
+			report.error(&format!(
+				"the access token is missing. Create one with the api scope at \
+				 https://{}/-/user_settings/personal_access_tokens",
+				&config.host
+			));
+			return Ok(());
+		}
+		AuthType::OAuth2(_) => match OAuth2Token::from_cache(&config.host) {
+			None => {
+				report.error("not logged in. Use glctl auth login");
+				return Ok(());
+			}
+			Some(token) if token.is_expired() => {
+				if token.refresh_token().is_some() {
+					report.ok("the login has expired and will be renewed at the next use");
+				} else {
+					report.error("the login has expired. Use glctl auth login");
+				}
+				return Ok(());
+			}
+			Some(_) => (),
+		},
+		_ => (),
+	}
+
+	let auth = get_auth(config)?;
+	match get::<User>(config, &auth, "api/v4/user") {
+		Err(err) => {
+			report.error(&format!(
+				"{:#}. Check the host name, the network and the proxy",
+				err
+			));
+			return Ok(());
+		}
+		Ok((_, Some(user))) => report.ok(&format!("authenticated as {}", &user.username)),
+		Ok((StatusCode::UNAUTHORIZED, _)) => report.error(match &config.auth {
+			AuthType::OAuth2(_) => "the login is invalid or revoked. Use glctl auth login",
+			_ => {
+				"the access token is invalid, expired or revoked. Create a new one and update \
+				 the configuration"
+			}
+		}),
+		Ok((StatusCode::FORBIDDEN, _)) => report.error(
+			"the token is not allowed to read the user. Check that it has the api scope and \
+			 that the user is not blocked",
+		),
+		Ok((status, _)) => report.error(&format!("unexpected answer from the host: {}", status)),
+	}
+
+	if let AuthType::Token(_) = &config.auth {
+		if let Ok((
+			_,
+			Some(AccessToken {
+				expires_at: Some(expires_at),
+			}),
+		)) = get::<AccessToken>(config, &auth, "api/v4/personal_access_tokens/self")
+		{
+			if expires_at
+				<= Local::now().naive_local().date() + Duration::days(EXPIRATION_WARNING_DAYS)
+			{
+				report.warning(&format!(
+					"the access token expires on {}. Use glctl token rotate -w",
+					expires_at
+				));
+			}
+		}
+	}
+	Ok(())
+}
+
+/// Check that a default project exists on the selected host
+fn check_project(config: &Config, project: &str, source: &str, report: &mut Report) -> Result<()> {
+	println!("Project {} ({})", project, source);
+	let auth = match get_auth(config) {
+		Ok(auth) => auth,
+		Err(_) => {
+			report.error(&format!(
+				"can't be checked without a login to {}",
+				&config.host
+			));
+			return Ok(());
+		}
+	};
+	match get::<Project>(
+		config,
+		&auth,
+		&format!("api/v4/projects/{}", project.replace('/', "%2F")),
+	) {
+		Err(err) => report.error(&format!("{:#}", err)),
+		Ok((_, Some(found))) if found.path_with_namespace == project => report.ok("found"),
+		Ok((_, Some(found))) => report.warning(&format!(
+			"the project has moved to {}. Update the project path",
+			&found.path_with_namespace
+		)),
+		Ok((StatusCode::NOT_FOUND, _)) => report.error(&format!(
+			"not found on {}, or not visible to the user. Use the full path of the project \
+			 (group/project)",
+			&config.host
+		)),
+		Ok((StatusCode::UNAUTHORIZED, _)) => report.error(&format!(
+			"can't be checked without a login to {}",
+			&config.host
+		)),
+		Ok((status, _)) => report.error(&format!("unexpected answer from the host: {}", status)),
+	}
+	Ok(())
+}
+
+/// Ask a question on the terminal, returning the default answer if nothing is entered
+fn prompt(question: &str, default: Option<&str>) -> Result<String> {
+	match default {
+		Some(default) => print!("{} [{}]: ", question, default),
+		None => print!("{}: ", question),
+	}
+	io::stdout().flush()?;
+	let mut answer = String::new();
+	io::stdin()
+		.read_line(&mut answer)
+		.with_context(|| "Can't read the answer")?;
+	let answer = answer.trim();
+	if answer.is_empty() {
+		match default {
+			Some(default) => Ok(default.to_owned()),
+			None => bail!("An answer is required"),
+		}
+	} else {
+		Ok(answer.to_owned())
+	}
+}
+
+/// Command implementation. It runs before connecting to gitlab as there may be no
+/// configuration yet
+pub fn cmd(opts: &Opts, args: &args::Config) -> Result<()> {
+	match &args.cmd {
+		ConfigCmd::Init(cmd_args) => {
+			let path = match &opts.config {
+				Some(path) => PathBuf::from(path),
+				None => Config::user_path()?,
+			};
+			if path.exists() && !cmd_args.force {
+				bail!("{:?} already exists. Use --force to overwrite it", &path)
+			}
+
+			let url = prompt("GitLab URL", Some("https://gitlab.com"))?;
+			let host = url
+				.strip_prefix("https://")
+				.unwrap_or(&url)
+				.trim_end_matches('/')
+				.to_owned();
+
+			// the TLS parameters of the command line are kept in the configuration
+			let connection = Connection {
+				ca_cert: opts.ca_cert.clone(),
+				insecure: opts.insecure,
+				..Connection::default()
+			};
+
+			let (auth, gitlab) = match prompt("Authentication (token or oauth2)", Some("token"))?
+				.as_str()
+			{
+				"token" => {
+					let url = format!("https://{}/-/user_settings/personal_access_tokens", &host);
+					println!("Create a token with the api scope at {}", url);
+					let token = prompt("Access token", None)?;
+					let gitlab =
+						GitlabClient::new(&host, &connection, ("PRIVATE-TOKEN", token.clone()))?;
+					(AuthType::Token(token), gitlab)
+				}
+				"oauth2" => {
+					let url = format!("https://{}/-/user_settings/applications", &host);
+					println!("Declare an application with the api scope at {}", url);
+					let id = prompt("Application ID", None)?;
+					let secret = prompt("Secret", None)?;
+					let redirect_port = prompt("Redirect port", Some("8888"))?
+						.parse()
+						.with_context(|| "Invalid redirect port")?;
+					let oauth2 = OAuth2 {
+						id,
+						secret,
+						redirect_port,
+					};
+					println!("Proceed to the login page https://{}", &host);
+					let token: String = login(&host, &oauth2, opts)?.into();
+					let gitlab = GitlabClient::new(
+						&host,
+						&connection,
+						("Authorization", format!("Bearer {}", token)),
+					)?;
+					(AuthType::OAuth2(oauth2), gitlab)
+				}
+				auth => bail!(
+					"{} not supported for authentication. Use either \"token\" or \"oauth2\"",
+					auth
+				),
+			};
+			let user: User = CurrentUser::builder()
+				.build()?
+				.query(&gitlab)
+				.with_context(|| format!("The authentication has been rejected by {}", &host))?;
+			println!("Authenticated as {}", &user.username);
+
+			let color = match prompt("Color mode (auto, always or never)", Some("auto"))?.as_str() {
+				"auto" => None,
+				"always" => Some(ColorChoice::Always),
+				"never" => Some(ColorChoice::Never),
+				color => bail!(
+					"{} not supported for color mode. Use either \"auto\", \"always\" or \"never\"",
+					color
+				),
+			};
+
+			let project = prompt("Default project (empty for none)", Some(""))?;
+			let project = if project.is_empty() {
+				None
+			} else {
+				let project: Project = projects::Project::builder()
+					.project(project.as_str())
+					.build()?
+					.query(&gitlab)
+					.with_context(|| format!("Can't find project {}", &project))?;
+				Some(project.path_with_namespace)
+			};
+
+			let config = NewConfig {
+				host,
+				auth,
+				connection,
+				color,
+				project,
+			};
+			let file = create_private_file(&path)?;
+			serde_yaml::to_writer(file, &config)
+				.with_context(|| format!("Can't write {:?}", &path))?;
+			println!("The configuration has been written to {:?}", &path);
+			Ok(())
+		}
+
+		ConfigCmd::Check(_) => {
+			let mut report = Report::default();
+			match Config::find_path(opts.config.as_ref()) {
+				Some(path) => {
+					println!("Configuration {:?}", &path);
+					let problems = Config::lint(&path)?;
+					if problems.is_empty() {
+						report.ok("all keys are known");
+					}
+					for problem in problems {
+						report.warning(&problem);
+					}
+					let hosts = Config::all_from_file(&path)?;
+					if hosts.is_empty() {
+						report.error("no host is defined. Use glctl config init");
+					}
+					for config in hosts {
+						let config = config.with_connection_override(opts);
+						check_host(&config, &mut report)?;
+					}
+				}
+				None => match Config::from_ci() {
+					Some(config) => {
+						println!("Configuration from the CI job");
+						check_host(&config, &mut report)?;
+					}
+					None => bail!("No configuration file found. Create one with glctl config init"),
+				},
+			}
+
+			// default projects are checked against the host in use
+			match get_config(opts) {
+				Ok(config) => {
+					if let Some(project) = &config.settings.project {
+						check_project(
+							&config,
+							project,
+							"default of the configuration",
+							&mut report,
+						)?;
+					}
+					if let Some(project) =
+						Settings::from_repo()?.and_then(|settings| settings.project)
+					{
+						check_project(&config, &project, "default of .glctl.yaml", &mut report)?;
+					}
+				}
+				Err(err) => {
+					println!("Host selection");
+					report.error(&format!("{:#}. Use --host or define a default host", err));
+				}
+			}
+
+			if report.errors > 0 {
+				bail!(
+					"{} error(s) and {} warning(s) found in the configuration",
+					report.errors,
+					report.warnings
+				)
+			} else if report.warnings > 0 {
+				println!(
+					"The configuration is usable with {} warning(s)",
+					report.warnings
+				);
+			} else {
+				println!("The configuration is valid");
+			}
+			Ok(())
+		}
+
+		ConfigCmd::Path(_) => {
+			match Config::find_path(opts.config.as_ref()) {
+				Some(path) => println!("Config:      {}", path.display()),
+				None if Config::from_ci().is_some() => println!("Config:      CI job"),
+				None => println!("Config:      none"),
+			}
+			println!("User config: {}", Config::user_path()?.display());
+			println!("Cache:       {}", Config::cache_dir()?.display());
+			match Settings::repo_path()? {
+				Some(path) => println!("Repository:  {}", path.display()),
+				None => println!("Repository:  none"),
+			}
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,78 @@
+use crate::{
+	api::dependency_proxy::{DependencyProxyQuery, DependencyProxyVariables, PurgeDependencyProxy},
+	args::{self, DepProxyCmd},
+	color::StyledStr,
+	context::CliContext,
+};
+
+use anyhow::{Context, Result};
+use gitlab::{
+	api::{self, groups, Query},
+	types,
+};
+use graphql_client::GraphQLQuery;
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::DepProxy) -> Result<()> {
+	match &args.cmd {
+		DepProxyCmd::Status(cmd_args) => {
+			let query = DependencyProxyQuery::build_query(DependencyProxyVariables {
+				full_path: cmd_args.group.to_owned(),
+			});
+			let group = context
+				.gitlab
+				.graphql::<DependencyProxyQuery>(&query)
+				.with_context(|| {
+					format!(
+						"Failed to get the dependency proxy of group {}",
+						&cmd_args.group
+					)
+				})?
+				.group
+				.with_context(|| format!("Can't find a group named {}", &cmd_args.group))?;
+
+			let mut msg = StyledStr::new();
+			msg.literal(&cmd_args.group);
+			let enabled = group
+				.dependency_proxy_setting
+				.map(|setting| setting.enabled)
+				.unwrap_or_default();
+			if enabled {
+				msg.good(" [enabled]\n");
+			} else {
+				msg.warning(" [disabled]\n");
+			}
+			msg.none(format!(
+				"  cache size: {}\n  images: {}\n  blobs: {}\n",
+				group.dependency_proxy_total_size,
+				group.dependency_proxy_image_count,
+				group.dependency_proxy_blob_count
+			));
+			context.print_msg(msg)
+		}
+
+		DepProxyCmd::Purge(cmd_args) => {
+			let group: types::Group = groups::Group::builder()
+				.group(cmd_args.group.as_str())
+				.build()?
+				.query(&context.gitlab)
+				.with_context(|| format!("Can't find a group named {}", &cmd_args.group))?;
+			let endpoint = PurgeDependencyProxy::builder()
+				.group(group.id.value())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to purge the dependency proxy of group {}",
+						&group.full_path
+					)
+				})?;
+			println!(
+				"purge of the dependency proxy cache of group {} has been scheduled",
+				&group.full_path
+			);
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,423 @@
+use crate::{
+	api::environments::{
+		DeleteEnvironment, DeleteReviewApps, ProtectEnvironment, StopEnvironment,
+		UnprotectEnvironment,
+	},
+	args::{self, EnvCmd},
+	color::StyledStr,
+	context::CliContext,
+	utils::{parse_date, parse_protected_access_level},
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use gitlab::{
+	api::{
+		self, groups,
+		projects::{
+			environments::{self, EnvironmentState, Environments},
+			protected_branches::ProtectedAccess,
+		},
+		Pagination, Query,
+	},
+	types,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Environment {
+	id: u64,
+	name: String,
+	state: String,
+	external_url: Option<String>,
+	updated_at: DateTime<Utc>,
+	#[serde(default)]
+	last_deployment: Option<Deployment>,
+}
+
+#[derive(Deserialize)]
+struct Deployment {
+	iid: u64,
+	#[serde(rename = "ref")]
+	ref_: String,
+	sha: String,
+	status: String,
+	created_at: DateTime<Utc>,
+	user: Option<User>,
+}
+
+#[derive(Deserialize)]
+struct User {
+	username: String,
+}
+
+#[derive(Deserialize)]
+struct ReviewApp {
+	name: String,
+}
+
+#[derive(Deserialize)]
+struct DeletedReviewApps {
+	#[serde(default)]
+	scheduled_entries: Vec<ReviewApp>,
+	#[serde(default)]
+	unprocessable_entries: Vec<ReviewApp>,
+}
+
+/// parse an environment state given on the command line
+fn parse_state(state: &str) -> Result<EnvironmentState> {
+	match state {
+		"available" => Ok(EnvironmentState::Available),
+		"stopping" => Ok(EnvironmentState::Stopping),
+		"stopped" => Ok(EnvironmentState::Stopped),
+		_ => bail!(
+			"{} not supported for --state. Use either \"available\", \"stopping\" or \"stopped\"",
+			state
+		),
+	}
+}
+
+/// Find an environment of a project by its name
+fn get_environment(
+	context: &CliContext,
+	project: &types::Project,
+	name: &str,
+) -> Result<Environment> {
+	let endpoint = Environments::builder()
+		.project(project.id.value())
+		.name(name)
+		.build()?;
+	let environments: Vec<Environment> = endpoint.query(&context.gitlab).with_context(|| {
+		format!(
+			"Failed to get environment {} of project {}",
+			name, &project.path_with_namespace
+		)
+	})?;
+	environments
+		.into_iter()
+		.find(|environment| environment.name == name)
+		.with_context(|| {
+			format!(
+				"environment {} not found in project {}",
+				name, &project.path_with_namespace
+			)
+		})
+}
+
+/// Parse a deploy access given on the command line, either an access level or a group
+fn parse_deploy_access(context: &CliContext, access: &str) -> Result<ProtectedAccess> {
+	if ["none", "developer", "maintainer", "admin"].contains(&access) {
+		return Ok(ProtectedAccess::Level(parse_protected_access_level(
+			access,
+		)?));
+	}
+	let group: types::Group = groups::Group::builder()
+		.group(access)
+		.build()?
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"{} is neither an access level nor a group. Use either \"developer\", \"maintainer\", \"admin\" or a group path",
+				access
+			)
+		})?;
+	Ok(ProtectedAccess::Group(group.id.value()))
+}
+
+/// Get an environment with its last deployment, which is only returned for a single
+/// environment
+fn get_deployed_environment(
+	context: &CliContext,
+	project: &types::Project,
+	id: u64,
+	name: &str,
+) -> Result<Environment> {
+	environments::Environment::builder()
+		.project(project.id.value())
+		.environment(id)
+		.build()?
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to get environment {} of project {}",
+				name, &project.path_with_namespace
+			)
+		})
+}
+
+/// Append the status of a deployment to the message
+fn deployment_status_msg(msg: &mut StyledStr, status: &str) {
+	if status == "success" {
+		msg.good(status);
+	} else {
+		msg.warning(status);
+	}
+}
+
+/// Append the state of an environment to the message
+fn state_msg(msg: &mut StyledStr, state: &str) {
+	msg.none(" [");
+	if state == "available" {
+		msg.good(state);
+	} else {
+		msg.warning(state);
+	}
+	msg.none("]");
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Env) -> Result<()> {
+	match &args.cmd {
+		EnvCmd::List(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut endpoint = Environments::builder();
+			endpoint.project(project.id.value());
+			if let Some(state) = &cmd_args.state {
+				endpoint.states(parse_state(state)?);
+			}
+			if cmd_args.review_apps {
+				endpoint.search("review/");
+			}
+			let mut environments: Vec<Environment> = api::paged(endpoint.build()?, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to list environments of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+			// search matches anywhere in the name
+			if cmd_args.review_apps {
+				environments.retain(|environment| environment.name.starts_with("review/"));
+			}
+
+			let mut msg = StyledStr::new();
+			if environments.is_empty() {
+				msg.none("No environments for ");
+				msg.literal(&project.path_with_namespace);
+				msg.none("\n");
+			}
+			for environment in environments.iter() {
+				let deployment =
+					get_deployed_environment(context, &project, environment.id, &environment.name)?
+						.last_deployment;
+				msg.none("- ");
+				msg.literal(&environment.name);
+				state_msg(&mut msg, &environment.state);
+				msg.none(format!(
+					" updated {}",
+					timeago::Formatter::new().convert_chrono(environment.updated_at, Utc::now())
+				));
+				match &deployment {
+					Some(deployment) => {
+						msg.none(format!(", last deployment of {} ", &deployment.ref_));
+						deployment_status_msg(&mut msg, &deployment.status);
+						msg.none(format!(
+							" {}",
+							timeago::Formatter::new()
+								.convert_chrono(deployment.created_at, Utc::now())
+						));
+					}
+					None => msg.none(", never deployed"),
+				}
+				if let Some(url) = &environment.external_url {
+					msg.hint(format!(" ({})", url));
+				}
+				msg.none("\n");
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/-/environments", &project.web_url));
+			}
+			Ok(())
+		}
+
+		EnvCmd::Show(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let id = get_environment(context, &project, &cmd_args.name)?.id;
+			let environment = get_deployed_environment(context, &project, id, &cmd_args.name)?;
+
+			let mut msg = StyledStr::new();
+			msg.literal(&environment.name);
+			state_msg(&mut msg, &environment.state);
+			msg.none("\n");
+			if let Some(url) = &environment.external_url {
+				msg.none("  url: ");
+				msg.hint(url);
+				msg.none("\n");
+			}
+			msg.none("  last deployment: ");
+			match &environment.last_deployment {
+				Some(deployment) => {
+					msg.literal(format!("#{}", deployment.iid));
+					msg.none(format!(
+						" of {} ({}) ",
+						&deployment.ref_,
+						&deployment.sha[..deployment.sha.len().min(8)]
+					));
+					deployment_status_msg(&mut msg, &deployment.status);
+					if let Some(user) = &deployment.user {
+						msg.none(format!(" by {}", &user.username));
+					}
+					msg.none(format!(
+						" {}",
+						timeago::Formatter::new().convert_chrono(deployment.created_at, Utc::now())
+					));
+				}
+				None => msg.none("none"),
+			}
+			msg.none("\n");
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!(
+					"{}/-/environments/{}",
+					&project.web_url, environment.id
+				));
+			}
+			Ok(())
+		}
+
+		EnvCmd::Stop(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let environment = get_environment(context, &project, &cmd_args.name)?;
+			let endpoint = StopEnvironment::builder()
+				.project(project.id.value())
+				.environment(environment.id)
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to stop environment {} of project {}",
+						&cmd_args.name, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"environment {} of project {} has been stopped",
+				&cmd_args.name, &project.path_with_namespace
+			);
+			Ok(())
+		}
+
+		EnvCmd::Delete(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			if cmd_args.review_apps {
+				let mut endpoint = DeleteReviewApps::builder();
+				endpoint
+					.project(project.id.value())
+					.dry_run(cmd_args.dry_run);
+				if let Some(before) = &cmd_args.before {
+					endpoint.before(parse_date(before)?);
+				}
+				let deleted: DeletedReviewApps =
+					endpoint.build()?.query(&context.gitlab).with_context(|| {
+						format!(
+							"Failed to delete review apps of project {}",
+							&project.path_with_namespace
+						)
+					})?;
+				for app in deleted.scheduled_entries.iter() {
+					if cmd_args.dry_run {
+						println!("review app {} would be deleted", &app.name);
+					} else {
+						println!("review app {} is scheduled for deletion", &app.name);
+					}
+				}
+				for app in deleted.unprocessable_entries.iter() {
+					println!("review app {} can't be deleted", &app.name);
+				}
+				if deleted.scheduled_entries.is_empty() {
+					println!(
+						"No stopped review apps to delete in project {}",
+						&project.path_with_namespace
+					);
+				}
+				return Ok(());
+			}
+
+			let name = match &cmd_args.name {
+				Some(name) => name,
+				None => {
+					bail!("Specify either an environment name or --review-apps on the command line")
+				}
+			};
+			let environment = get_environment(context, &project, name)?;
+			let endpoint = DeleteEnvironment::builder()
+				.project(project.id.value())
+				.environment(environment.id)
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to delete environment {} of project {} (only stopped environments can be deleted)",
+						name, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"environment {} of project {} has been deleted",
+				name, &project.path_with_namespace
+			);
+			Ok(())
+		}
+
+		EnvCmd::Protect(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			if cmd_args.deploy_access.is_empty() {
+				bail!("Specify at least one --deploy-access on the command line")
+			}
+			let accesses = cmd_args
+				.deploy_access
+				.iter()
+				.map(|access| parse_deploy_access(context, access))
+				.collect::<Result<Vec<_>>>()?;
+			let endpoint = ProtectEnvironment::builder()
+				.project(project.id.value())
+				.name(cmd_args.name.as_str())
+				.deploy_access_levels(accesses)
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to protect environment {} of project {}",
+						&cmd_args.name, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"environment {} of project {} is now protected (deploy: {})",
+				&cmd_args.name,
+				&project.path_with_namespace,
+				cmd_args.deploy_access.join(", ")
+			);
+
+			if context.open {
+				let _ = open::that(format!("{}/-/settings/ci_cd", &project.web_url));
+			}
+			Ok(())
+		}
+
+		EnvCmd::Unprotect(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = UnprotectEnvironment::builder()
+				.project(project.id.value())
+				.name(cmd_args.name.as_str())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to unprotect environment {} of project {}",
+						&cmd_args.name, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"environment {} of project {} is no longer protected",
+				&cmd_args.name, &project.path_with_namespace
+			);
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,243 @@
+use crate::{
+	api::feature_flags::{EditFeatureFlag, FeatureFlag, FeatureFlagStrategy, FeatureFlags},
+	args::{self, FeatureFlagsCmd},
+	color::StyledStr,
+	context::CliContext,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use gitlab::{
+	api::{self, Pagination, Query},
+	types,
+};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// Strategies supported by gitlab feature flags
+const STRATEGIES: [&str; 4] = [
+	"default",
+	"gradualRolloutUserId",
+	"userWithId",
+	"flexibleRollout",
+];
+
+#[derive(Deserialize)]
+struct Flag {
+	name: String,
+	description: Option<String>,
+	active: bool,
+	#[serde(default)]
+	strategies: Vec<Strategy>,
+}
+
+#[derive(Deserialize)]
+struct Strategy {
+	id: u64,
+	name: String,
+	#[serde(default)]
+	parameters: Map<String, Value>,
+	#[serde(default)]
+	scopes: Vec<Scope>,
+}
+
+#[derive(Deserialize)]
+struct Scope {
+	environment_scope: String,
+}
+
+/// Describe a strategy as name(key=value, ...) on scope, ...
+fn strategy_description(strategy: &Strategy) -> String {
+	let parameters: Vec<String> = strategy
+		.parameters
+		.iter()
+		.map(|(key, value)| match value {
+			Value::String(value) => format!("{}={}", key, value),
+			value => format!("{}={}", key, value),
+		})
+		.collect();
+	let scopes: Vec<&str> = strategy
+		.scopes
+		.iter()
+		.map(|scope| scope.environment_scope.as_str())
+		.collect();
+	format!(
+		"{}({}) on {}",
+		&strategy.name,
+		parameters.join(", "),
+		scopes.join(", ")
+	)
+}
+
+/// Append a feature flag description to the message
+fn flag_msg(msg: &mut StyledStr, flag: &Flag) {
+	msg.none("- ");
+	msg.literal(&flag.name);
+	if flag.active {
+		msg.good(" [enabled]");
+	} else {
+		msg.warning(" [disabled]");
+	}
+	if let Some(description) = flag.description.as_ref().filter(|d| !d.is_empty()) {
+		msg.hint(format!(" {}", description));
+	}
+	msg.none("\n");
+	for strategy in flag.strategies.iter() {
+		msg.none(format!("    {}\n", strategy_description(strategy)));
+	}
+}
+
+/// Get a feature flag of a project
+fn get_flag(context: &CliContext, project: &types::Project, name: &str) -> Result<Flag> {
+	FeatureFlag::builder()
+		.project(project.id.value())
+		.name(name)
+		.build()?
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to get feature flag {} of project {}",
+				name, &project.path_with_namespace
+			)
+		})
+}
+
+/// Enable or disable a feature flag
+fn set_active(
+	context: &CliContext,
+	project: Option<&String>,
+	name: &str,
+	active: bool,
+) -> Result<()> {
+	let project = context.get_project(project)?;
+	let endpoint = EditFeatureFlag::builder()
+		.project(project.id.value())
+		.name(name)
+		.active(active)
+		.build()?;
+	api::ignore(endpoint)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to change feature flag {} of project {}",
+				name, &project.path_with_namespace
+			)
+		})?;
+	println!(
+		"feature flag {} of project {} has been {}",
+		name,
+		&project.path_with_namespace,
+		if active { "enabled" } else { "disabled" }
+	);
+	Ok(())
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::FeatureFlags) -> Result<()> {
+	match &args.cmd {
+		FeatureFlagsCmd::List(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut endpoint = FeatureFlags::builder();
+			endpoint.project(project.id.value());
+			if let Some(scope) = &cmd_args.scope {
+				if !["enabled", "disabled"].contains(&scope.as_str()) {
+					bail!(
+						"{} not supported for --scope. Use either \"enabled\" or \"disabled\"",
+						scope
+					)
+				}
+				endpoint.scope(scope.as_str());
+			}
+			let flags: Vec<Flag> = api::paged(endpoint.build()?, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to list feature flags of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+
+			let mut msg = StyledStr::new();
+			if flags.is_empty() {
+				msg.none("No feature flags for ");
+				msg.literal(&project.path_with_namespace);
+				msg.none("\n");
+			}
+			for flag in flags.iter() {
+				flag_msg(&mut msg, flag);
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/-/feature_flags", &project.web_url));
+			}
+			Ok(())
+		}
+
+		FeatureFlagsCmd::Enable(cmd_args) => {
+			set_active(context, cmd_args.project.as_ref(), &cmd_args.name, true)
+		}
+
+		FeatureFlagsCmd::Disable(cmd_args) => {
+			set_active(context, cmd_args.project.as_ref(), &cmd_args.name, false)
+		}
+
+		FeatureFlagsCmd::SetStrategy(cmd_args) => {
+			if !STRATEGIES.contains(&cmd_args.strategy.as_str()) {
+				bail!(
+					"{} not supported for --strategy. Use either \"{}\"",
+					&cmd_args.strategy,
+					STRATEGIES.join("\", \"")
+				)
+			}
+			let mut parameters = Map::new();
+			for param in cmd_args.param.iter() {
+				let (key, value) = param
+					.split_once('=')
+					.ok_or_else(|| anyhow!("Invalid parameter {}. Use key=value", param))?;
+				parameters.insert(key.trim().to_owned(), value.trim().into());
+			}
+			let scopes = if cmd_args.environment_scope.is_empty() {
+				vec!["*".to_owned()]
+			} else {
+				cmd_args.environment_scope.clone()
+			};
+
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let flag = get_flag(context, &project, &cmd_args.name)?;
+			// replace the current strategies by the new one
+			let mut strategies: Vec<FeatureFlagStrategy> = flag
+				.strategies
+				.iter()
+				.map(|strategy| FeatureFlagStrategy {
+					id: Some(strategy.id),
+					name: strategy.name.to_owned(),
+					parameters: Map::new(),
+					scopes: Vec::new(),
+					destroy: true,
+				})
+				.collect();
+			strategies.push(FeatureFlagStrategy {
+				id: None,
+				name: cmd_args.strategy.to_owned(),
+				parameters,
+				scopes,
+				destroy: false,
+			});
+			let endpoint = EditFeatureFlag::builder()
+				.project(project.id.value())
+				.name(cmd_args.name.as_str())
+				.strategies(strategies)
+				.build()?;
+			let flag: Flag = endpoint.query(&context.gitlab).with_context(|| {
+				format!(
+					"Failed to change the strategy of feature flag {} of project {}",
+					&cmd_args.name, &project.path_with_namespace
+				)
+			})?;
+
+			let mut msg = StyledStr::new();
+			flag_msg(&mut msg, &flag);
+			context.print_msg(msg)
+		}
+	}
+}
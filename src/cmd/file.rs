@@ -0,0 +1,180 @@
+use crate::{
+	args::{self, FileCmd},
+	context::CliContext,
+};
+
+use anyhow::{Context, Result};
+use gitlab::{
+	api::{
+		self,
+		projects::repository::files::{CreateFile, DeleteFile, FileRaw, UpdateFile},
+		ApiError, Query,
+	},
+	types,
+};
+use std::{
+	fs::{read, write},
+	io::{self, Write},
+};
+
+/// Get the reference to use, which is the default branch if none is given. Any commit is
+/// accepted, so the reference is not checked against tags and branches
+fn get_ref(ref_: Option<&String>, project: &types::Project) -> String {
+	match ref_ {
+		Some(ref_) => ref_.to_owned(),
+		None => project
+			.default_branch
+			.clone()
+			.unwrap_or_else(|| "HEAD".to_owned()),
+	}
+}
+
+/// Get the content of a file of the repository, or None if the file doesn't exist
+fn get_file(
+	context: &CliContext,
+	project: &types::Project,
+	path: &str,
+	ref_: &str,
+) -> Result<Option<Vec<u8>>> {
+	let endpoint = FileRaw::builder()
+		.project(project.id.value())
+		.file_path(path)
+		.ref_(ref_)
+		.build()?;
+	api::raw(endpoint)
+		.query(&context.gitlab)
+		.map(Some)
+		.or_else(|err| match err {
+			ApiError::Gitlab { msg } if msg.starts_with("404") => Ok(None),
+			err => Err(err),
+		})
+		.with_context(|| {
+			format!(
+				"Failed to get {} at {} from project {}",
+				path, ref_, &project.path_with_namespace
+			)
+		})
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::File) -> Result<()> {
+	match &args.cmd {
+		FileCmd::Get(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let ref_ = get_ref(cmd_args.ref_.as_ref(), &project);
+			let content =
+				get_file(context, &project, &cmd_args.path, &ref_)?.with_context(|| {
+					format!(
+						"{} not found at {} in project {}",
+						&cmd_args.path, &ref_, &project.path_with_namespace
+					)
+				})?;
+
+			match &cmd_args.output {
+				Some(output) => {
+					write(output, &content).with_context(|| format!("Can't write {}", output))?;
+					println!(
+						"{} at {} has been saved to {}",
+						&cmd_args.path, &ref_, output
+					);
+				}
+				None => io::stdout().write_all(&content)?,
+			}
+
+			if context.open {
+				let _ = open::that(format!(
+					"{}/-/blob/{}/{}",
+					&project.web_url, &ref_, &cmd_args.path
+				));
+			}
+			Ok(())
+		}
+
+		FileCmd::Put(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let branch = get_ref(cmd_args.branch.as_ref(), &project);
+			let content =
+				read(&cmd_args.from).with_context(|| format!("Can't open {}", &cmd_args.from))?;
+			let message = cmd_args
+				.message
+				.clone()
+				.unwrap_or_else(|| format!("Update {}", &cmd_args.path));
+
+			// an empty commit would be created when the content is the same
+			let current = get_file(context, &project, &cmd_args.path, &branch)?;
+			if current.as_ref() == Some(&content) {
+				println!(
+					"{} is already up to date on branch {} of project {}",
+					&cmd_args.path, &branch, &project.path_with_namespace
+				);
+				return Ok(());
+			}
+
+			let result = if current.is_some() {
+				let endpoint = UpdateFile::builder()
+					.project(project.id.value())
+					.file_path(cmd_args.path.as_str())
+					.branch(branch.as_str())
+					.content(content.as_slice())
+					.commit_message(message.as_str())
+					.build()?;
+				api::ignore(endpoint).query(&context.gitlab)
+			} else {
+				let endpoint = CreateFile::builder()
+					.project(project.id.value())
+					.file_path(cmd_args.path.as_str())
+					.branch(branch.as_str())
+					.content(content.as_slice())
+					.commit_message(message.as_str())
+					.build()?;
+				api::ignore(endpoint).query(&context.gitlab)
+			};
+			result.with_context(|| {
+				format!(
+					"Failed to commit {} on branch {} of project {}",
+					&cmd_args.path, &branch, &project.path_with_namespace
+				)
+			})?;
+			println!(
+				"{} has been {} on branch {} of project {}",
+				&cmd_args.path,
+				if current.is_some() {
+					"updated"
+				} else {
+					"created"
+				},
+				&branch,
+				&project.path_with_namespace
+			);
+			Ok(())
+		}
+
+		FileCmd::Rm(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let branch = get_ref(cmd_args.branch.as_ref(), &project);
+			let message = cmd_args
+				.message
+				.clone()
+				.unwrap_or_else(|| format!("Delete {}", &cmd_args.path));
+			let endpoint = DeleteFile::builder()
+				.project(project.id.value())
+				.file_path(cmd_args.path.as_str())
+				.branch(branch.as_str())
+				.commit_message(message.as_str())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to delete {} on branch {} of project {}",
+						&cmd_args.path, &branch, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"{} has been deleted on branch {} of project {}",
+				&cmd_args.path, &branch, &project.path_with_namespace
+			);
+			Ok(())
+		}
+	}
+}
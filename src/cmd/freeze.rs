@@ -0,0 +1,121 @@
+use crate::{
+	api::freeze_periods::{CreateFreezePeriod, DeleteFreezePeriod, FreezePeriods},
+	args::{self, FreezeCmd},
+	color::StyledStr,
+	context::CliContext,
+};
+
+use anyhow::{bail, Context, Result};
+use gitlab::api::{self, Pagination, Query};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct FreezePeriod {
+	id: u64,
+	freeze_start: String,
+	freeze_end: String,
+	cron_timezone: Option<String>,
+}
+
+/// Check that a cron expression has the 5 fields gitlab expects
+fn check_cron(option: &str, cron: &str) -> Result<()> {
+	if cron.split_whitespace().count() != 5 {
+		bail!(
+			"{} is not a valid cron expression for --{}. Use 5 fields (ex: \"0 23 * * 5\")",
+			cron,
+			option
+		)
+	}
+	Ok(())
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Freeze) -> Result<()> {
+	match &args.cmd {
+		FreezeCmd::List(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = FreezePeriods::builder()
+				.project(project.id.value())
+				.build()?;
+			let periods: Vec<FreezePeriod> = api::paged(endpoint, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to list freeze periods of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+
+			let mut msg = StyledStr::new();
+			if periods.is_empty() {
+				msg.none("No freeze periods for ");
+				msg.literal(&project.path_with_namespace);
+				msg.none("\n");
+			}
+			for period in periods.iter() {
+				msg.none(format!("- #{} from ", period.id));
+				msg.literal(&period.freeze_start);
+				msg.none(" to ");
+				msg.literal(&period.freeze_end);
+				msg.hint(format!(
+					" ({})",
+					period.cron_timezone.as_deref().unwrap_or("UTC")
+				));
+				msg.none("\n");
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/-/settings/ci_cd", &project.web_url));
+			}
+			Ok(())
+		}
+
+		FreezeCmd::Add(cmd_args) => {
+			check_cron("start", &cmd_args.start)?;
+			check_cron("end", &cmd_args.end)?;
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut endpoint = CreateFreezePeriod::builder();
+			endpoint
+				.project(project.id.value())
+				.freeze_start(cmd_args.start.as_str())
+				.freeze_end(cmd_args.end.as_str());
+			if let Some(timezone) = &cmd_args.timezone {
+				endpoint.cron_timezone(timezone.as_str());
+			}
+			let period: FreezePeriod =
+				endpoint.build()?.query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to add freeze period to project {}",
+						&project.path_with_namespace
+					)
+				})?;
+			println!(
+				"freeze period #{} from {} to {} has been added to project {}",
+				period.id, &period.freeze_start, &period.freeze_end, &project.path_with_namespace
+			);
+			Ok(())
+		}
+
+		FreezeCmd::Remove(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = DeleteFreezePeriod::builder()
+				.project(project.id.value())
+				.freeze_period(cmd_args.id)
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to remove freeze period #{} from project {}",
+						cmd_args.id, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"freeze period #{} has been removed from project {}",
+				cmd_args.id, &project.path_with_namespace
+			);
+			Ok(())
+		}
+	}
+}
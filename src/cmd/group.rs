@@ -0,0 +1,240 @@
+mod clone;
+mod members;
+mod tokens;
+
+use crate::{
+	api::groups::DeleteGroup,
+	args::{self, GroupCmd},
+	color::StyledStr,
+	context::CliContext,
+	utils::parse_visibility,
+};
+
+use anyhow::{bail, Context, Result};
+use gitlab::{
+	api::{
+		self,
+		groups::{self, BranchProtection, CreateGroup, EditGroup},
+		Pagination, Query,
+	},
+	types::{self, VisibilityLevel},
+};
+use serde::Deserialize;
+use std::io::{self, Write};
+
+/// Deletion status of a group
+#[derive(Deserialize)]
+struct DeletionStatus {
+	marked_for_deletion_on: Option<String>,
+}
+
+/// Parse a default branch protection level
+fn parse_branch_protection(protection: &str) -> Result<BranchProtection> {
+	match protection {
+		"none" => Ok(BranchProtection::None),
+		"partial" => Ok(BranchProtection::Partial),
+		"full" => Ok(BranchProtection::Full),
+		"push" => Ok(BranchProtection::Push),
+		protection => bail!(
+			"{} not supported for --branch-protection. Use either \"none\", \"partial\", \"full\" or \"push\"",
+			protection
+		),
+	}
+}
+
+/// Append a visibility marker to the message
+fn msg_visibility(msg: &mut StyledStr, visibility: VisibilityLevel) {
+	match visibility {
+		VisibilityLevel::Public => msg.good(" [public]"),
+		VisibilityLevel::Internal => msg.warning(" [internal]"),
+		VisibilityLevel::Private => msg.hint(" [private]"),
+	}
+}
+
+/// Recursively append subgroups and projects of a group to the message
+fn msg_tree(
+	context: &CliContext,
+	msg: &mut StyledStr,
+	group: &types::Group,
+	depth: usize,
+) -> Result<()> {
+	let indent = "  ".repeat(depth);
+
+	let endpoint = groups::subgroups::GroupSubgroups::builder()
+		.group(group.id.value())
+		.build()?;
+	let subgroups: Vec<types::Group> = api::paged(endpoint, Pagination::All)
+		.query(&context.gitlab)
+		.with_context(|| format!("Failed to list subgroups of {}", &group.full_path))?;
+	for subgroup in subgroups.iter() {
+		msg.none(format!("{}+ ", indent));
+		msg.literal(&subgroup.path);
+		msg.none("/");
+		msg_visibility(msg, subgroup.visibility);
+		if context.url {
+			msg.hint(format!(" ({})", subgroup.web_url));
+		}
+		msg.none("\n");
+		msg_tree(context, msg, subgroup, depth + 1)?;
+	}
+
+	let endpoint = groups::projects::GroupProjects::builder()
+		.group(group.id.value())
+		.build()?;
+	let projects: Vec<types::Project> = api::paged(endpoint, Pagination::All)
+		.query(&context.gitlab)
+		.with_context(|| format!("Failed to list projects of {}", &group.full_path))?;
+	for project in projects.iter() {
+		msg.none(format!("{}- ", indent));
+		msg.none(&project.path);
+		msg_visibility(msg, project.visibility);
+		if context.url {
+			msg.hint(format!(" ({})", project.web_url));
+		}
+		msg.none("\n");
+	}
+
+	Ok(())
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Group) -> Result<()> {
+	match &args.cmd {
+		GroupCmd::Tree(cmd_args) => {
+			let group: types::Group = groups::Group::builder()
+				.group(cmd_args.group.as_str())
+				.build()?
+				.query(&context.gitlab)
+				.with_context(|| format!("Can't find a group named {}", &cmd_args.group))?;
+
+			let mut msg = StyledStr::new();
+			msg.literal(&group.full_path);
+			msg.none("/");
+			msg_visibility(&mut msg, group.visibility);
+			if context.url {
+				msg.hint(format!(" ({})", group.web_url));
+			}
+			msg.none("\n");
+			msg_tree(context, &mut msg, &group, 1)?;
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(&group.web_url);
+			}
+			Ok(())
+		}
+
+		GroupCmd::Create(cmd_args) => {
+			let mut endpoint = CreateGroup::builder();
+			endpoint
+				.path(cmd_args.path.as_str())
+				.name(cmd_args.name.as_deref().unwrap_or(&cmd_args.path))
+				.visibility(parse_visibility(&cmd_args.visibility)?);
+			// the parent id is needed to create a subgroup
+			if let Some(parent) = &cmd_args.parent {
+				let parent: types::Group = groups::Group::builder()
+					.group(parent.as_str())
+					.build()?
+					.query(&context.gitlab)
+					.with_context(|| format!("Can't find a group named {}", parent))?;
+				endpoint.parent_id(parent.id.value());
+			}
+			if let Some(description) = &cmd_args.description {
+				endpoint.description(description.as_str());
+			}
+			let group: types::Group = endpoint
+				.build()?
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to create group {}", &cmd_args.path))?;
+
+			print!("group {} has been created", &group.full_path);
+			if context.url {
+				print!(" ({})", group.web_url);
+			}
+			println!();
+
+			if context.open {
+				let _ = open::that(&group.web_url);
+			}
+			Ok(())
+		}
+
+		GroupCmd::Edit(cmd_args) => {
+			if cmd_args.description.is_none()
+				&& cmd_args.branch_protection.is_none()
+				&& cmd_args.visibility.is_none()
+			{
+				bail!("Nothing to change on group {}", &cmd_args.group)
+			}
+
+			let mut endpoint = EditGroup::builder();
+			endpoint.group(cmd_args.group.as_str());
+			if let Some(description) = &cmd_args.description {
+				endpoint.description(description.as_str());
+			}
+			if let Some(protection) = &cmd_args.branch_protection {
+				endpoint.default_branch_protection(parse_branch_protection(protection)?);
+			}
+			if let Some(visibility) = &cmd_args.visibility {
+				endpoint.visibility(parse_visibility(visibility)?);
+			}
+			let group: types::Group = endpoint
+				.build()?
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to edit group {}", &cmd_args.group))?;
+			println!("group {} has been updated", &group.full_path);
+
+			if context.open {
+				let _ = open::that(&group.web_url);
+			}
+			Ok(())
+		}
+
+		GroupCmd::Members(cmd_args) => members::cmd(context, cmd_args),
+		GroupCmd::Tokens(cmd_args) => tokens::cmd(context, cmd_args),
+
+		GroupCmd::Clone(cmd_args) => clone::cmd(context, cmd_args),
+
+		GroupCmd::Delete(cmd_args) => {
+			let group: types::Group = groups::Group::builder()
+				.group(cmd_args.group.as_str())
+				.build()?
+				.query(&context.gitlab)
+				.with_context(|| format!("Can't find a group named {}", &cmd_args.group))?;
+
+			// ask for the group path unless explicitly told not to
+			if !(cmd_args.yes && cmd_args.i_know_what_i_am_doing) {
+				print!(
+					"Type the group path ({}) to confirm its deletion: ",
+					&group.full_path
+				);
+				io::stdout().flush()?;
+				let mut answer = String::new();
+				io::stdin().read_line(&mut answer)?;
+				if answer.trim() != group.full_path {
+					bail!("Deletion of {} aborted", &group.full_path)
+				}
+			}
+
+			let endpoint = DeleteGroup::builder().group(group.id.value()).build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to delete group {}", &group.full_path))?;
+
+			// with delayed deletion, the group still exists but is marked for deletion
+			let status: Option<DeletionStatus> = groups::Group::builder()
+				.group(group.id.value())
+				.build()?
+				.query(&context.gitlab)
+				.ok();
+			match status.and_then(|status| status.marked_for_deletion_on) {
+				Some(date) => println!(
+					"group {} is marked for deletion and will be deleted on {}",
+					&group.full_path, date
+				),
+				None => println!("group {} has been deleted", &group.full_path),
+			}
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,143 @@
+use crate::{args, context::CliContext, utils::glob_match};
+
+use anyhow::{bail, Context, Result};
+use gitlab::{
+	api::{self, groups, Pagination, Query},
+	types,
+};
+use std::{
+	path::{Path, PathBuf},
+	process::Command,
+	sync::Mutex,
+	thread,
+};
+
+/// A project to clone or update
+struct Job {
+	/// path of the project relative to the group
+	path: String,
+	/// url to clone from
+	url: String,
+	/// local directory
+	dir: PathBuf,
+}
+
+impl Job {
+	/// Clone the project, or pull it if it already exists and update is requested. Return
+	/// a message describing what has been done
+	fn run(&self, args: &args::GroupClone) -> Result<String, String> {
+		let mut cmd = Command::new("git");
+		let action = if self.dir.exists() {
+			if !args.update {
+				return Ok(format!("{} already exists, skipped", self.path));
+			}
+			cmd.arg("-C").arg(&self.dir).args(["pull", "--ff-only"]);
+			"updated"
+		} else {
+			cmd.arg("clone");
+			if let Some(depth) = args.depth {
+				cmd.arg("--depth").arg(depth.to_string());
+			}
+			cmd.arg(&self.url).arg(&self.dir);
+			"cloned"
+		};
+		// capture the output to not interleave the output of parallel jobs
+		match cmd.output() {
+			Ok(output) if output.status.success() => Ok(format!("{} {}", self.path, action)),
+			Ok(output) => Err(format!(
+				"{} failed: {}",
+				self.path,
+				String::from_utf8_lossy(&output.stderr).trim()
+			)),
+			Err(err) => Err(format!("{} failed: {}", self.path, err)),
+		}
+	}
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::GroupClone) -> Result<()> {
+	let group: types::Group = groups::Group::builder()
+		.group(args.group.as_str())
+		.build()?
+		.query(&context.gitlab)
+		.with_context(|| format!("Can't find a group named {}", &args.group))?;
+
+	let endpoint = groups::projects::GroupProjects::builder()
+		.group(group.id.value())
+		.include_subgroups(args.recursive)
+		.archived(false)
+		.build()?;
+	let projects: Vec<types::Project> = api::paged(endpoint, Pagination::All)
+		.query(&context.gitlab)
+		.with_context(|| format!("Failed to list projects of group {}", &group.full_path))?;
+
+	// mirror the group structure inside the destination directory
+	let dest = Path::new(args.dest.as_deref().unwrap_or(&group.path));
+	let prefix = format!("{}/", &group.full_path);
+	let mut jobs: Vec<Job> = projects
+		.into_iter()
+		.filter_map(|project| {
+			let path = project
+				.path_with_namespace
+				.strip_prefix(&prefix)
+				.unwrap_or(&project.path_with_namespace)
+				.to_owned();
+			let included =
+				args.include.is_empty() || args.include.iter().any(|glob| glob_match(glob, &path));
+			let excluded = args.exclude.iter().any(|glob| glob_match(glob, &path));
+			(included && !excluded).then(|| Job {
+				dir: dest.join(&path),
+				url: if args.https {
+					project.http_url_to_repo
+				} else {
+					project.ssh_url_to_repo
+				},
+				path,
+			})
+		})
+		.collect();
+	jobs.sort_by(|a, b| b.path.cmp(&a.path));
+
+	if jobs.is_empty() {
+		println!("No projects to clone in group {}", &group.full_path);
+		return Ok(());
+	}
+	if context.verbose {
+		println!(
+			"{} projects of group {} to clone into {}",
+			jobs.len(),
+			&group.full_path,
+			dest.display()
+		);
+	}
+
+	// workers take jobs from a shared queue until it's empty
+	let total = jobs.len();
+	let queue = Mutex::new(jobs);
+	let failures = Mutex::new(0);
+	thread::scope(|scope| {
+		for _ in 0..args.jobs.max(1) {
+			scope.spawn(|| loop {
+				let job = queue.lock().unwrap().pop();
+				match job.map(|job| job.run(args)) {
+					Some(Ok(msg)) => println!("{}", msg),
+					Some(Err(msg)) => {
+						eprintln!("{}", msg);
+						*failures.lock().unwrap() += 1;
+					}
+					None => break,
+				}
+			});
+		}
+	});
+
+	let failures = failures.into_inner().unwrap();
+	if failures != 0 {
+		bail!(
+			"{} of {} projects failed to clone or update",
+			failures,
+			total
+		)
+	}
+	Ok(())
+}
@@ -0,0 +1,129 @@
+use crate::{
+	api::groups::AllGroupMembers,
+	args::{self, GroupMembersCmd},
+	context::CliContext,
+	utils::{access_level_name, parse_access_level, parse_date},
+};
+
+use anyhow::{Context, Result};
+use gitlab::{
+	api::{
+		self,
+		groups::members::{AddGroupMember, EditGroupMember, GroupMembers, RemoveGroupMember},
+		Pagination, Query,
+	},
+	types,
+};
+use std::collections::HashSet;
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::GroupMembers) -> Result<()> {
+	match &args.cmd {
+		GroupMembersCmd::List(cmd_args) => {
+			let group = cmd_args.group.as_str();
+			let endpoint = GroupMembers::builder().group(group).build()?;
+			let direct: Vec<types::Member> = api::paged(endpoint, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to list members of group {}", group))?;
+			let direct: HashSet<u64> = direct.iter().map(|member| member.id.value()).collect();
+
+			// members of parent groups are only returned by the members/all endpoint
+			let endpoint = AllGroupMembers::builder().group(group).build()?;
+			let mut members: Vec<types::Member> = api::paged(endpoint, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to list members of group {}", group))?;
+			members.sort_by(|a, b| a.username.cmp(&b.username));
+			let members: Vec<(types::Member, bool)> = members
+				.into_iter()
+				.map(|member| {
+					let inherited = !direct.contains(&member.id.value());
+					(member, inherited)
+				})
+				.collect();
+			context.print_members(&members)?;
+
+			if context.open {
+				let _ = open::that(format!(
+					"https://{}/groups/{}/-/group_members",
+					&context.config.host, group
+				));
+			}
+			Ok(())
+		}
+
+		GroupMembersCmd::Add(cmd_args) => {
+			let user = context.get_user(&cmd_args.username)?;
+			let access = parse_access_level(&cmd_args.access)?;
+			let mut endpoint = AddGroupMember::builder();
+			endpoint
+				.group(cmd_args.group.as_str())
+				.user(user.id.value())
+				.access_level(access);
+			if let Some(expires) = &cmd_args.expires {
+				endpoint.expires_at(parse_date(expires)?);
+			}
+			let member: types::Member =
+				endpoint.build()?.query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to add {} to group {}",
+						&cmd_args.username, &cmd_args.group
+					)
+				})?;
+			println!(
+				"{} has been added to group {} as {}",
+				&member.username,
+				&cmd_args.group,
+				access_level_name(member.access_level)
+			);
+			Ok(())
+		}
+
+		GroupMembersCmd::Remove(cmd_args) => {
+			let user = context.get_user(&cmd_args.username)?;
+			let endpoint = RemoveGroupMember::builder()
+				.group(cmd_args.group.as_str())
+				.user(user.id.value())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to remove {} from group {}",
+						&cmd_args.username, &cmd_args.group
+					)
+				})?;
+			println!(
+				"{} has been removed from group {}",
+				&cmd_args.username, &cmd_args.group
+			);
+			Ok(())
+		}
+
+		GroupMembersCmd::Update(cmd_args) => {
+			let user = context.get_user(&cmd_args.username)?;
+			let access = parse_access_level(&cmd_args.access)?;
+			let mut endpoint = EditGroupMember::builder();
+			endpoint
+				.group(cmd_args.group.as_str())
+				.user(user.id.value())
+				.access_level(access);
+			if let Some(expires) = &cmd_args.expires {
+				endpoint.expires_at(parse_date(expires)?);
+			}
+			let member: types::Member =
+				endpoint.build()?.query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to update {} in group {}",
+						&cmd_args.username, &cmd_args.group
+					)
+				})?;
+			println!(
+				"{} is now {} of group {}",
+				&member.username,
+				access_level_name(member.access_level),
+				&cmd_args.group
+			);
+			Ok(())
+		}
+	}
+}
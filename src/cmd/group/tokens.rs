@@ -0,0 +1,155 @@
+use crate::{
+	api::{
+		access_requests::Source,
+		access_tokens::{AccessTokens, CreateAccessToken, RevokeAccessToken},
+	},
+	args::{self, GroupTokensCmd},
+	color::StyledStr,
+	context::CliContext,
+	utils::{access_level_name, parse_access_level, parse_date},
+};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use gitlab::api::{self, Pagination, Query};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct AccessToken {
+	id: u64,
+	name: String,
+	active: bool,
+	revoked: bool,
+	scopes: Vec<String>,
+	access_level: u64,
+	expires_at: Option<NaiveDate>,
+	last_used_at: Option<DateTime<Utc>>,
+	/// only returned on creation
+	token: Option<String>,
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::GroupTokens) -> Result<()> {
+	match &args.cmd {
+		GroupTokensCmd::List(cmd_args) => {
+			let group = cmd_args.group.as_str();
+			let mut endpoint = AccessTokens::builder();
+			endpoint.source(Source::Group(group.into()));
+			if !cmd_args.all {
+				endpoint.state("active");
+			}
+			let tokens: Vec<AccessToken> = api::paged(endpoint.build()?, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to list access tokens of group {}", group))?;
+
+			let mut msg = StyledStr::new();
+			if tokens.is_empty() {
+				msg.none("No access tokens for ");
+				msg.literal(group);
+				msg.none("\n");
+			}
+			for token in tokens.iter() {
+				msg.none(format!("- #{} ", token.id));
+				msg.literal(&token.name);
+				msg.none(format!(
+					" as {} [{}]",
+					access_level_name(token.access_level),
+					token.scopes.join(", ")
+				));
+				if token.revoked {
+					msg.error(" revoked");
+				} else if !token.active {
+					msg.warning(" expired");
+				}
+				msg.hint(format!(
+					" (expires {}, last used {})",
+					token
+						.expires_at
+						.map(|date| date.to_string())
+						.unwrap_or_else(|| "never".to_owned()),
+					token
+						.last_used_at
+						.map(|date| timeago::Formatter::new().convert_chrono(date, Utc::now()))
+						.unwrap_or_else(|| "never".to_owned())
+				));
+				msg.none("\n");
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!(
+					"https://{}/groups/{}/-/settings/access_tokens",
+					&context.config.host, group
+				));
+			}
+			Ok(())
+		}
+
+		GroupTokensCmd::Create(cmd_args) => {
+			let group = cmd_args.group.as_str();
+			let scopes = if cmd_args.scopes.is_empty() {
+				vec!["api".into()]
+			} else {
+				cmd_args
+					.scopes
+					.iter()
+					.map(|scope| scope.as_str().into())
+					.collect()
+			};
+			let mut endpoint = CreateAccessToken::builder();
+			endpoint
+				.source(Source::Group(group.into()))
+				.name(cmd_args.name.as_str())
+				.scopes(scopes)
+				.access_level(parse_access_level(&cmd_args.access)?);
+			if let Some(expires) = &cmd_args.expires {
+				endpoint.expires_at(parse_date(expires)?);
+			}
+			let token: AccessToken =
+				endpoint.build()?.query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to create access token {} on group {}",
+						&cmd_args.name, group
+					)
+				})?;
+
+			let mut msg = StyledStr::new();
+			msg.none(format!("access token #{} ", token.id));
+			msg.literal(&token.name);
+			msg.none(format!(
+				" has been created on group {} as {}",
+				group,
+				access_level_name(token.access_level)
+			));
+			if let Some(expires_at) = token.expires_at {
+				msg.none(format!(" and expires on {}", expires_at));
+			}
+			msg.none("\n");
+			msg.hint("copy it now as it can't be shown again: ");
+			msg.good(token.token.as_deref().unwrap_or_default());
+			msg.none("\n");
+			context.print_msg(msg)
+		}
+
+		GroupTokensCmd::Revoke(cmd_args) => {
+			let group = cmd_args.group.as_str();
+			let endpoint = RevokeAccessToken::builder()
+				.source(Source::Group(group.into()))
+				.token(cmd_args.id)
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to revoke access token #{} of group {}",
+						cmd_args.id, group
+					)
+				})?;
+			println!(
+				"access token #{} of group {} has been revoked",
+				cmd_args.id, group
+			);
+			Ok(())
+		}
+	}
+}
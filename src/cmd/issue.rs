@@ -0,0 +1,477 @@
+use crate::{
+	args::{self, Format, IssueCmd},
+	context::CliContext,
+	utils::{csv_field, csv_records},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use gitlab::{
+	api::{
+		self,
+		projects::{
+			issues::{CreateIssue, IssueState, Issues},
+			merge_requests::IssuesClosedBy,
+			repository::{files::FileRaw, Tree},
+		},
+		users::Users,
+		ApiError, Pagination, Query,
+	},
+	types,
+};
+use serde::{ser::SerializeMap, Deserialize, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::{collections::BTreeMap, fs::read_to_string, path::Path, thread::sleep, time::Duration};
+
+/// Location of the issue description templates in a repository
+const TEMPLATES_DIR: &str = ".gitlab/issue_templates";
+
+/// Maximum number of retries when the api rate limit is hit
+const MAX_RETRIES: u32 = 5;
+
+/// Issue description in an import file
+#[derive(Deserialize, Default)]
+struct ImportIssue {
+	title: String,
+	#[serde(default)]
+	description: Option<String>,
+	#[serde(default)]
+	labels: Vec<String>,
+	#[serde(default)]
+	assignees: Vec<String>,
+}
+
+impl ImportIssue {
+	/// Read a list of issues from a yaml or a csv file (depending on the file extension)
+	fn from_file(path: &str) -> Result<Vec<Self>> {
+		let content = read_to_string(path).with_context(|| format!("Can't open {}", path))?;
+		if Path::new(path)
+			.extension()
+			.filter(|ext| *ext == "csv")
+			.is_some()
+		{
+			let mut records = csv_records(&content).into_iter();
+			let (_, header) = records
+				.next()
+				.ok_or_else(|| anyhow!("Can't find a header line in {}", path))?;
+			records
+				.map(|(line, record)| {
+					let mut issue = Self::default();
+					for (column, value) in header.iter().zip(record) {
+						let list = || {
+							value
+								.split(';')
+								.map(|s| s.trim().to_owned())
+								.filter(|s| !s.is_empty())
+								.collect()
+						};
+						match column.trim() {
+							"title" => issue.title = value.to_owned(),
+							"description" => {
+								issue.description = Some(value.to_owned()).filter(|s| !s.is_empty())
+							}
+							"labels" => issue.labels = list(),
+							"assignees" => issue.assignees = list(),
+							_ => {}
+						}
+					}
+					if issue.title.is_empty() {
+						bail!("Missing title at line {} of {}", line, path)
+					}
+					Ok(issue)
+				})
+				.collect()
+		} else {
+			serde_yaml::from_str(&content).with_context(|| format!("Can't read {}", path))
+		}
+	}
+}
+
+/// Exported issue, serialized as a map keeping the order of the columns
+struct Row(Vec<(String, Value)>);
+
+impl Serialize for Row {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut map = serializer.serialize_map(Some(self.0.len()))?;
+		for (column, value) in self.0.iter() {
+			map.serialize_entry(column, value)?;
+		}
+		map.end()
+	}
+}
+
+/// Turn a json value into a flat value (nested objects are replaced by their most
+/// meaningful attribute)
+fn flatten(value: Value) -> Value {
+	match value {
+		Value::Object(mut map) => ["username", "title", "name", "id"]
+			.iter()
+			.find_map(|key| map.remove(*key))
+			.unwrap_or(Value::Null),
+		Value::Array(values) => Value::Array(values.into_iter().map(flatten).collect()),
+		value => value,
+	}
+}
+
+/// Turn a flat json value into a csv cell
+fn to_cell(value: &Value) -> String {
+	match value {
+		Value::Null => String::new(),
+		Value::String(s) => s.to_owned(),
+		Value::Array(values) => values
+			.iter()
+			.map(to_cell)
+			.collect::<Vec<String>>()
+			.join(";"),
+		value => value.to_string(),
+	}
+}
+
+/// Print the reference of a newly created issue
+fn print_created(context: &CliContext, issue: &types::Issue, project: &types::Project) {
+	print!(
+		"issue #{} '{}' has been created on project {}",
+		issue.iid.value(),
+		issue.title,
+		&project.path_with_namespace
+	);
+	if context.url {
+		print!(" ({})", issue.web_url);
+	}
+	println!();
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Issue) -> Result<()> {
+	match &args.cmd {
+		IssueCmd::Export(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let columns: Vec<&str> = cmd_args
+				.columns
+				.split(',')
+				.map(|column| column.trim())
+				.filter(|column| !column.is_empty())
+				.collect();
+
+			let mut endpoint = Issues::builder();
+			endpoint.project(project.path_with_namespace.as_str());
+			match cmd_args.state.as_str() {
+				"opened" => {
+					endpoint.state(IssueState::Opened);
+				}
+				"closed" => {
+					endpoint.state(IssueState::Closed);
+				}
+				"all" => {}
+				state => bail!(
+					"{} not supported for --state. Use either \"opened\", \"closed\" or \"all\"",
+					state
+				),
+			}
+			let issues: Vec<Map<String, Value>> = api::paged(endpoint.build()?, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!("Failed to list issues for {}", &project.path_with_namespace)
+				})?;
+
+			// only keep the requested columns
+			let rows: Vec<Row> = issues
+				.into_iter()
+				.map(|mut issue| {
+					Row(columns
+						.iter()
+						.map(|column| {
+							(
+								column.to_string(),
+								issue.remove(*column).map(flatten).unwrap_or(Value::Null),
+							)
+						})
+						.collect())
+				})
+				.collect();
+
+			match cmd_args.format {
+				Format::Csv => {
+					println!(
+						"{}",
+						columns
+							.iter()
+							.map(|column| csv_field(column))
+							.collect::<Vec<String>>()
+							.join(",")
+					);
+					for row in rows.iter() {
+						println!(
+							"{}",
+							row.0
+								.iter()
+								.map(|(_, value)| csv_field(&to_cell(value)))
+								.collect::<Vec<String>>()
+								.join(",")
+						);
+					}
+				}
+				Format::Json => {
+					println!("{}", serde_json::to_string_pretty(&rows)?);
+				}
+				Format::Yaml => {
+					print!("{}", serde_yaml::to_string(&rows)?);
+				}
+			}
+
+			if context.verbose {
+				eprintln!(
+					"{} issues exported from {}",
+					rows.len(),
+					&project.path_with_namespace
+				);
+			}
+			Ok(())
+		}
+
+		IssueCmd::Import(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let issues = ImportIssue::from_file(&cmd_args.file)?;
+
+			// resolve assignees usernames to user ids only once
+			let mut users: BTreeMap<&str, u64> = BTreeMap::new();
+			if !cmd_args.dry_run {
+				for username in issues.iter().flat_map(|issue| issue.assignees.iter()) {
+					if !users.contains_key(username.as_str()) {
+						let endpoint = Users::builder().username(username.as_str()).build()?;
+						let found: Vec<types::UserBasic> = endpoint
+							.query(&context.gitlab)
+							.with_context(|| format!("Failed to find user {}", username))?;
+						let user = found
+							.first()
+							.ok_or_else(|| anyhow!("Can't find a user named {}", username))?;
+						users.insert(username, user.id.value());
+					}
+				}
+			}
+
+			for (i, issue) in issues.iter().enumerate() {
+				if cmd_args.dry_run {
+					println!(
+						"issue '{}' would be created on project {} (labels: [{}], assignees: [{}])",
+						issue.title,
+						&project.path_with_namespace,
+						issue.labels.join(", "),
+						issue.assignees.join(", ")
+					);
+					continue;
+				}
+
+				// pace the requests to stay below the api rate limit
+				if i > 0 {
+					sleep(Duration::from_millis(cmd_args.delay));
+				}
+
+				let mut endpoint = CreateIssue::builder();
+				endpoint
+					.project(project.path_with_namespace.as_str())
+					.title(issue.title.as_str())
+					.labels(issue.labels.iter().map(|label| label.as_str()))
+					.assignee_ids(
+						issue
+							.assignees
+							.iter()
+							.map(|username| users[username.as_str()]),
+					);
+				if let Some(description) = &issue.description {
+					endpoint.description(description.as_str());
+				}
+				let endpoint = endpoint.build()?;
+
+				let mut retries = 0;
+				let created: types::Issue = loop {
+					match endpoint.query(&context.gitlab) {
+						// back off when the rate limit has been hit
+						Err(ApiError::GitlabService { status, .. })
+							if status.as_u16() == 429 && retries < MAX_RETRIES =>
+						{
+							retries += 1;
+							let delay = cmd_args.delay.max(1000) * 2u64.pow(retries);
+							if context.verbose {
+								println!("Rate limit hit, waiting {}ms", delay);
+							}
+							sleep(Duration::from_millis(delay));
+						}
+						res => {
+							break res.with_context(|| {
+								format!(
+									"Failed to create issue '{}' on project {}",
+									issue.title, &project.path_with_namespace
+								)
+							})?
+						}
+					}
+				};
+				print_created(context, &created, &project);
+			}
+
+			Ok(())
+		}
+
+		IssueCmd::Templates(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut endpoint = Tree::builder();
+			endpoint
+				.project(project.path_with_namespace.as_str())
+				.path(TEMPLATES_DIR);
+			if let Some(branch) = &project.default_branch {
+				endpoint.ref_(branch.as_str());
+			}
+			// a missing directory is reported as a 404 error
+			let objects: Vec<types::RepoTreeObject> =
+				api::paged(endpoint.build()?, Pagination::All)
+					.query(&context.gitlab)
+					.or_else(|err| match err {
+						ApiError::Gitlab { msg } if msg.starts_with("404") => Ok(Vec::new()),
+						err => Err(err),
+					})
+					.with_context(|| {
+						format!(
+							"Failed to list issue templates for {}",
+							&project.path_with_namespace
+						)
+					})?;
+
+			let templates: Vec<_> = objects
+				.iter()
+				.filter_map(|object| {
+					object
+						.name
+						.strip_suffix(".md")
+						.map(|name| (name, &object.path))
+				})
+				.collect();
+			if templates.is_empty() {
+				println!(
+					"No issue templates found for project {}",
+					&project.path_with_namespace
+				);
+			} else {
+				for (name, path) in templates {
+					if context.verbose {
+						println!("- {} ({})", name, path);
+					} else {
+						println!("- {}", name);
+					}
+				}
+			}
+
+			if context.open {
+				let _ = open::that(format!(
+					"{}/-/tree/{}/{}",
+					&project.web_url,
+					project.default_branch.as_deref().unwrap_or("HEAD"),
+					TEMPLATES_DIR
+				));
+			}
+			Ok(())
+		}
+
+		IssueCmd::Create(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+
+			// get the template content from the default branch
+			let template = if let Some(name) = &cmd_args.template {
+				let path = format!("{}/{}.md", TEMPLATES_DIR, name);
+				let endpoint = FileRaw::builder()
+					.project(project.path_with_namespace.as_str())
+					.file_path(path.as_str())
+					.ref_(project.default_branch.as_deref().unwrap_or("HEAD"))
+					.build()?;
+				let content = api::raw(endpoint).query(&context.gitlab).with_context(|| {
+					format!(
+						"Can't find template {} in project {}",
+						name, &project.path_with_namespace
+					)
+				})?;
+				Some(String::from_utf8_lossy(&content).into_owned())
+			} else {
+				None
+			};
+			// the description given on the command line is put before the template
+			let description = match (&cmd_args.description, template) {
+				(Some(description), Some(template)) => {
+					Some(format!("{}\n\n{}", description, template))
+				}
+				(description, template) => description.clone().or(template),
+			};
+
+			let mut endpoint = CreateIssue::builder();
+			endpoint
+				.project(project.path_with_namespace.as_str())
+				.title(cmd_args.title.as_str());
+			if let Some(description) = &description {
+				endpoint.description(description.as_str());
+			}
+			if let Some(labels) = &cmd_args.labels {
+				endpoint.labels(labels.split(',').map(|label| label.trim()));
+			}
+			let issue: types::Issue =
+				endpoint.build()?.query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to create issue '{}' on project {}",
+						&cmd_args.title, &project.path_with_namespace
+					)
+				})?;
+			print_created(context, &issue, &project);
+
+			if context.open {
+				let _ = open::that(&issue.web_url);
+			}
+			Ok(())
+		}
+
+		IssueCmd::ClosedBy(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mr = context.get_mr(cmd_args.mr, &project)?;
+			let endpoint = IssuesClosedBy::builder()
+				.project(project.path_with_namespace.as_str())
+				.merge_request(mr.iid.value())
+				.build()?;
+			let issues: Vec<types::Issue> = api::paged(endpoint, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to list the issues closed by merge request !{} of project {}",
+						mr.iid.value(),
+						&project.path_with_namespace
+					)
+				})?;
+
+			if issues.is_empty() {
+				println!(
+					"Merge request !{} '{}' doesn't close any issue",
+					mr.iid.value(),
+					mr.title
+				);
+			} else {
+				println!(
+					"Issues closed by merge request !{} '{}'",
+					mr.iid.value(),
+					mr.title
+				);
+			}
+			for issue in issues.iter() {
+				print!(
+					"- #{} {} ({:?})",
+					issue.iid.value(),
+					issue.title,
+					issue.state
+				);
+				if context.url {
+					print!(" ({})", issue.web_url);
+				}
+				println!();
+			}
+
+			if context.open {
+				let _ = open::that(&mr.web_url);
+			}
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,98 @@
+use crate::{
+	api::job_token_scope::{AddJobTokenAllowlist, JobTokenAllowlist, RemoveJobTokenAllowlist},
+	args::{self, JobTokenAllowlistCmd},
+	color::StyledStr,
+	context::CliContext,
+};
+
+use anyhow::{Context, Result};
+use gitlab::{
+	api::{self, Pagination, Query},
+	types,
+};
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::JobTokenAllowlist) -> Result<()> {
+	match &args.cmd {
+		JobTokenAllowlistCmd::List(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = JobTokenAllowlist::builder()
+				.project(project.id.value())
+				.build()?;
+			let mut projects: Vec<types::Project> = api::paged(endpoint, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to get the job token allowlist of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+			projects.sort_by(|a, b| a.path_with_namespace.cmp(&b.path_with_namespace));
+
+			let mut msg = StyledStr::new();
+			if projects.is_empty() {
+				msg.none("No projects allowed to access ");
+				msg.literal(&project.path_with_namespace);
+				msg.none(" with their job token\n");
+			}
+			for allowed in projects.iter() {
+				msg.none("- ");
+				msg.literal(&allowed.path_with_namespace);
+				if context.url {
+					msg.hint(format!(" {}", &allowed.web_url));
+				}
+				msg.none("\n");
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/-/settings/ci_cd", &project.web_url));
+			}
+			Ok(())
+		}
+
+		JobTokenAllowlistCmd::Add(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let target = context.get_project(Some(&cmd_args.target))?;
+			let endpoint = AddJobTokenAllowlist::builder()
+				.project(project.id.value())
+				.target_project(target.id.value())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to add {} to the job token allowlist of project {}",
+						&target.path_with_namespace, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"project {} can now access project {} with its job token",
+				&target.path_with_namespace, &project.path_with_namespace
+			);
+			Ok(())
+		}
+
+		JobTokenAllowlistCmd::Remove(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let target = context.get_project(Some(&cmd_args.target))?;
+			let endpoint = RemoveJobTokenAllowlist::builder()
+				.project(project.id.value())
+				.target_project(target.id.value())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to remove {} from the job token allowlist of project {}",
+						&target.path_with_namespace, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"project {} can no longer access project {} with its job token",
+				&target.path_with_namespace, &project.path_with_namespace
+			);
+			Ok(())
+		}
+	}
+}
@@ -1,5 +1,36 @@
+pub mod access_requests;
+pub mod admin;
+pub mod alias;
 pub mod archive;
+pub mod audit;
+pub mod auth;
+pub mod badge;
+pub mod blame;
 pub mod branches;
+pub mod ci;
+pub mod clone;
+pub mod commits;
+pub mod config;
+pub mod dep_proxy;
+pub mod env;
+pub mod feature_flags;
+pub mod file;
+pub mod freeze;
+pub mod group;
+pub mod issue;
+pub mod job_token_allowlist;
+pub mod mr;
+pub mod package;
+pub mod pages;
 pub mod pipeline;
 pub mod project;
+pub mod registry;
+pub mod runner;
+pub mod search;
+pub mod secure_files;
 pub mod tags;
+pub mod tf_state;
+pub mod todo;
+pub mod token;
+pub mod tree;
+pub mod variables;
@@ -0,0 +1,28 @@
+use crate::{
+	args::{self, MrCmd},
+	context::CliContext,
+};
+
+use anyhow::Result;
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Mr) -> Result<()> {
+	match &args.cmd {
+		MrCmd::Status(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mr = context.get_mr(cmd_args.iid, &project)?;
+
+			context.print_mr(&mr, &project)?;
+			if let Some(pipeline) = &mr.pipeline {
+				context.print_pipeline(pipeline, &project)?;
+				let jobs = context.get_jobs(&project, pipeline.id.value())?;
+				context.print_jobs(&jobs)?;
+			}
+
+			if context.open {
+				let _ = open::that(&mr.web_url);
+			}
+			Ok(())
+		}
+	}
+}
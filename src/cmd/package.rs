@@ -0,0 +1,408 @@
+use crate::{
+	api::packages::{
+		DeletePackage, DownloadPackageFile, Package, PackageFiles, Packages,
+		UploadGenericPackageFile,
+	},
+	args::{self, PackageCmd},
+	color::StyledStr,
+	context::CliContext,
+	utils::{parse_age, sha256_hex},
+};
+
+use anyhow::{bail, Context, Result};
+use bytesize::ByteSize;
+use chrono::{DateTime, Utc};
+use gitlab::{
+	api::{self, common, Pagination, Query},
+	types,
+};
+use serde::Deserialize;
+use std::{
+	fs::{create_dir_all, read, write},
+	io::{self, Write},
+	path::Path,
+};
+
+#[derive(Deserialize)]
+struct PackageInfo {
+	id: u64,
+	name: String,
+	version: String,
+	package_type: String,
+	status: Option<String>,
+	created_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct PackageFile {
+	file_name: String,
+	size: u64,
+	created_at: DateTime<Utc>,
+	file_sha256: Option<String>,
+}
+
+/// Get the packages of a project, most recent first
+fn get_packages(
+	context: &CliContext,
+	project: &types::Project,
+	package_type: Option<&String>,
+	name: Option<&String>,
+) -> Result<Vec<PackageInfo>> {
+	let mut endpoint = Packages::builder();
+	endpoint.project(project.id.value());
+	if let Some(package_type) = package_type {
+		endpoint.package_type(package_type.as_str());
+	}
+	if let Some(name) = name {
+		endpoint.package_name(name.as_str());
+	}
+	api::paged(endpoint.build()?, Pagination::All)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to list packages of project {}",
+				&project.path_with_namespace
+			)
+		})
+}
+
+/// Delete a package of a project
+fn delete_package(
+	context: &CliContext,
+	project: &types::Project,
+	package: &PackageInfo,
+) -> Result<()> {
+	let endpoint = DeletePackage::builder()
+		.project(project.id.value())
+		.package(package.id)
+		.build()?;
+	api::ignore(endpoint)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to delete package {} {} of project {}",
+				&package.name, &package.version, &project.path_with_namespace
+			)
+		})?;
+	println!(
+		"package {} {} has been deleted from project {}",
+		&package.name, &package.version, &project.path_with_namespace
+	);
+	Ok(())
+}
+
+/// Get a package of a project
+fn get_package(context: &CliContext, project: &types::Project, id: u64) -> Result<PackageInfo> {
+	Package::builder()
+		.project(project.id.value())
+		.package(id)
+		.build()?
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to get package #{} of project {}",
+				id, &project.path_with_namespace
+			)
+		})
+}
+
+/// Get the files of a package, keeping only the last upload of files with the same name
+fn get_package_files(
+	context: &CliContext,
+	project: &types::Project,
+	package: &PackageInfo,
+) -> Result<Vec<PackageFile>> {
+	let endpoint = PackageFiles::builder()
+		.project(project.id.value())
+		.package(package.id)
+		.build()?;
+	let mut files: Vec<PackageFile> = api::paged(endpoint, Pagination::All)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to list files of package {} {}",
+				&package.name, &package.version
+			)
+		})?;
+	files.sort_by(|a, b| (&a.file_name, b.created_at).cmp(&(&b.file_name, a.created_at)));
+	files.dedup_by(|a, b| a.file_name == b.file_name);
+	Ok(files)
+}
+
+/// Path of a package file relative to the packages api of its type
+fn package_file_path(package: &PackageInfo, file: &PackageFile) -> Result<String> {
+	match package.package_type.as_str() {
+		"generic" => Ok(format!(
+			"generic/{}/{}/{}",
+			common::path_escaped(&package.name),
+			common::path_escaped(&package.version),
+			common::path_escaped(&file.file_name)
+		)),
+		// maven package names are already paths (ex: com/example/app)
+		"maven" => Ok(format!(
+			"maven/{}/{}/{}",
+			&package.name,
+			common::path_escaped(&package.version),
+			common::path_escaped(&file.file_name)
+		)),
+		package_type => bail!(
+			"downloading {} packages is not supported. Use either generic or maven packages",
+			package_type
+		),
+	}
+}
+
+/// Append a package description to the message
+fn package_msg(msg: &mut StyledStr, package: &PackageInfo) {
+	msg.none(format!("- #{} ", package.id));
+	msg.literal(&package.name);
+	msg.none(format!(" {} ", &package.version));
+	msg.hint(format!("({})", &package.package_type));
+	if let Some(status) = package
+		.status
+		.as_ref()
+		.filter(|status| *status != "default")
+	{
+		msg.warning(format!(" {}", status));
+	}
+	msg.none(format!(
+		" [{}]\n",
+		timeago::Formatter::new().convert_chrono(package.created_at, Utc::now())
+	));
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Package) -> Result<()> {
+	match &args.cmd {
+		PackageCmd::List(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let packages = get_packages(
+				context,
+				&project,
+				cmd_args.package_type.as_ref(),
+				cmd_args.name.as_ref(),
+			)?;
+
+			let mut msg = StyledStr::new();
+			if packages.is_empty() {
+				msg.none("No packages for ");
+				msg.literal(&project.path_with_namespace);
+				msg.none("\n");
+			}
+			for package in packages.iter() {
+				package_msg(&mut msg, package);
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/-/packages", &project.web_url));
+			}
+			Ok(())
+		}
+
+		PackageCmd::Show(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let package = get_package(context, &project, cmd_args.id)?;
+			let files = get_package_files(context, &project, &package)?;
+
+			let mut msg = StyledStr::new();
+			package_msg(&mut msg, &package);
+			for file in files.iter() {
+				msg.none("    ");
+				msg.literal(&file.file_name);
+				msg.none(format!(" {}", ByteSize(file.size)));
+				if let Some(sha256) = &file.file_sha256 {
+					msg.hint(format!(" sha256:{}", sha256));
+				}
+				msg.none("\n");
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/-/packages/{}", &project.web_url, package.id));
+			}
+			Ok(())
+		}
+
+		PackageCmd::Download(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let package = get_package(context, &project, cmd_args.id)?;
+			let files = get_package_files(context, &project, &package)?;
+			create_dir_all(&cmd_args.dest)
+				.with_context(|| format!("Can't create dir {}", &cmd_args.dest))?;
+			let mut checksums = String::new();
+
+			for file in files.iter() {
+				let endpoint = DownloadPackageFile::builder()
+					.project(project.id.value())
+					.path(package_file_path(&package, file)?)
+					.build()?;
+				let content = api::raw(endpoint).query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to download {} of package {} {}",
+						&file.file_name, &package.name, &package.version
+					)
+				})?;
+				// never leave a corrupted file behind
+				if content.len() as u64 != file.size {
+					bail!(
+						"{} of package {} {} is corrupted: expected {} bytes, got {}",
+						&file.file_name,
+						&package.name,
+						&package.version,
+						file.size,
+						content.len()
+					)
+				}
+				let sha256 = sha256_hex(&content);
+				if let Some(expected) = &file.file_sha256 {
+					if !expected.eq_ignore_ascii_case(&sha256) {
+						bail!(
+							"{} of package {} {} is corrupted: expected sha256 {}, got {}",
+							&file.file_name,
+							&package.name,
+							&package.version,
+							expected,
+							&sha256
+						)
+					}
+				}
+
+				let output = Path::new(&cmd_args.dest).join(&file.file_name);
+				write(&output, &content)
+					.with_context(|| format!("Can't write {}", output.display()))?;
+				println!(
+					"{} has been saved to {} ({}, sha256 {})",
+					&file.file_name,
+					output.display(),
+					ByteSize(content.len() as u64),
+					if file.file_sha256.is_some() {
+						"verified"
+					} else {
+						"not provided"
+					}
+				);
+				checksums.push_str(&format!("{}  {}\n", sha256, &file.file_name));
+			}
+
+			if let Some(checksum_file) = &cmd_args.checksum_file {
+				write(checksum_file, checksums)
+					.with_context(|| format!("Can't write {}", checksum_file))?;
+				println!("checksums have been saved to {}", checksum_file);
+			}
+			Ok(())
+		}
+
+		PackageCmd::Publish(cmd_args) => {
+			if cmd_args.files.is_empty() {
+				bail!("Specify at least one file to publish on the command line")
+			}
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let total = cmd_args.files.len();
+			for (i, path) in cmd_args.files.iter().enumerate() {
+				let content = read(path).with_context(|| format!("Can't open {}", path))?;
+				let file_name = Path::new(path)
+					.file_name()
+					.map(|name| name.to_string_lossy().to_string())
+					.unwrap_or_else(|| path.to_owned());
+				print!(
+					"[{}/{}] uploading {} ({})... ",
+					i + 1,
+					total,
+					&file_name,
+					ByteSize(content.len() as u64)
+				);
+				let _ = io::stdout().flush();
+
+				let endpoint = UploadGenericPackageFile::builder()
+					.project(project.id.value())
+					.name(cmd_args.name.as_str())
+					.version(cmd_args.version.as_str())
+					.file_name(file_name.as_str())
+					.content(content.as_slice())
+					.build()?;
+				let result = api::ignore(endpoint).query(&context.gitlab);
+				println!("{}", if result.is_ok() { "done" } else { "failed" });
+				result.with_context(|| {
+					format!(
+						"Failed to publish {} to package {} {} of project {}",
+						&file_name, &cmd_args.name, &cmd_args.version, &project.path_with_namespace
+					)
+				})?;
+			}
+			println!(
+				"package {} {} has been published to project {}",
+				&cmd_args.name, &cmd_args.version, &project.path_with_namespace
+			);
+			Ok(())
+		}
+
+		PackageCmd::Delete(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let package = get_package(context, &project, cmd_args.id)?;
+			delete_package(context, &project, &package)
+		}
+
+		PackageCmd::Prune(cmd_args) => {
+			let older_than = cmd_args
+				.older_than
+				.as_ref()
+				.map(|age| parse_age(age))
+				.transpose()?;
+			if cmd_args.keep_n.is_none() && older_than.is_none() {
+				bail!("Specify at least --keep-n or --older-than on the command line")
+			}
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut packages = get_packages(
+				context,
+				&project,
+				cmd_args.package_type.as_ref(),
+				cmd_args.name.as_ref(),
+			)?;
+			// group the versions of each package, most recent first
+			packages.sort_by(|a, b| {
+				(&a.package_type, &a.name, b.created_at).cmp(&(
+					&b.package_type,
+					&b.name,
+					a.created_at,
+				))
+			});
+
+			let mut pruned = Vec::new();
+			let mut rank = 0;
+			for (i, package) in packages.iter().enumerate() {
+				let same = i > 0
+					&& packages[i - 1].package_type == package.package_type
+					&& packages[i - 1].name == package.name;
+				rank = if same { rank + 1 } else { 0 };
+				if rank < cmd_args.keep_n.unwrap_or_default() {
+					continue;
+				}
+				if let Some(age) = older_than {
+					if package.created_at >= Utc::now() - age {
+						continue;
+					}
+				}
+				pruned.push(package);
+			}
+
+			if pruned.is_empty() {
+				println!(
+					"No packages to prune in project {}",
+					&project.path_with_namespace
+				);
+			}
+			for package in pruned.into_iter() {
+				if cmd_args.dry_run {
+					println!(
+						"package {} {} would be deleted",
+						&package.name, &package.version
+					);
+				} else {
+					delete_package(context, &project, package)?;
+				}
+			}
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,191 @@
+use crate::{
+	api::pages::{CreatePagesDomain, DeletePagesDomain, PagesDomains, VerifyPagesDomain},
+	args::{self, PagesCmd, PagesDomainsCmd},
+	color::StyledStr,
+	context::CliContext,
+};
+
+use anyhow::{bail, Context, Result};
+use gitlab::api::{self, Pagination, Query};
+use serde::Deserialize;
+use std::fs::read_to_string;
+
+#[derive(Deserialize)]
+struct Domain {
+	domain: String,
+	url: String,
+	project_id: Option<u64>,
+	#[serde(default)]
+	verified: bool,
+	verification_code: Option<String>,
+	#[serde(default)]
+	auto_ssl_enabled: bool,
+	certificate: Option<Certificate>,
+}
+
+#[derive(Deserialize)]
+struct Certificate {
+	#[serde(default)]
+	expired: bool,
+	expiration: Option<String>,
+}
+
+/// Append a domain description to the message
+fn domain_msg(msg: &mut StyledStr, domain: &Domain, verbose: bool) {
+	msg.none("- ");
+	msg.literal(&domain.domain);
+	if let Some(project) = domain.project_id {
+		msg.none(format!(" (project #{})", project));
+	}
+	if domain.verified {
+		msg.good(" verified");
+	} else {
+		msg.error(" unverified");
+	}
+	match &domain.certificate {
+		Some(certificate) if certificate.expired => msg.error(" certificate expired"),
+		Some(certificate) => {
+			msg.good(" certificate");
+			if let Some(expiration) = &certificate.expiration {
+				msg.none(format!(" until {}", expiration));
+			}
+		}
+		None => msg.warning(" no certificate"),
+	}
+	if domain.auto_ssl_enabled {
+		msg.hint(" (let's encrypt)");
+	}
+	msg.hint(format!(" {}", &domain.url));
+	msg.none("\n");
+	if !domain.verified || verbose {
+		if let Some(code) = &domain.verification_code {
+			msg.none(format!(
+				"    TXT _gitlab-pages-verification-code.{} {}\n",
+				&domain.domain, code
+			));
+		}
+	}
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Pages) -> Result<()> {
+	match &args.cmd {
+		PagesCmd::Domains(args) => match &args.cmd {
+			PagesDomainsCmd::List(cmd_args) => {
+				let mut endpoint = PagesDomains::builder();
+				let project = if cmd_args.all {
+					None
+				} else {
+					let project = context.get_project(cmd_args.project.as_ref())?;
+					endpoint.project(project.id.value());
+					Some(project)
+				};
+				let domains: Vec<Domain> = api::paged(endpoint.build()?, Pagination::All)
+					.query(&context.gitlab)
+					.with_context(|| match &project {
+						Some(project) => format!(
+							"Failed to list pages domains of project {}",
+							&project.path_with_namespace
+						),
+						None => "Failed to list pages domains".to_owned(),
+					})?;
+
+				let mut msg = StyledStr::new();
+				if domains.is_empty() {
+					msg.none("No pages domains found\n");
+				}
+				for domain in domains.iter() {
+					domain_msg(&mut msg, domain, context.verbose);
+				}
+				context.print_msg(msg)?;
+
+				if context.open {
+					if let Some(project) = &project {
+						let _ = open::that(format!("{}/pages", &project.web_url));
+					}
+				}
+				Ok(())
+			}
+
+			PagesDomainsCmd::Add(cmd_args) => {
+				if cmd_args.auto_ssl && cmd_args.certificate.is_some() {
+					bail!("Use either --auto-ssl or --certificate, not both")
+				}
+				if cmd_args.certificate.is_some() != cmd_args.key.is_some() {
+					bail!("--certificate and --key must be given together")
+				}
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				let mut endpoint = CreatePagesDomain::builder();
+				endpoint
+					.project(project.id.value())
+					.domain(cmd_args.domain.as_str())
+					.auto_ssl_enabled(cmd_args.auto_ssl);
+				if let (Some(certificate), Some(key)) = (&cmd_args.certificate, &cmd_args.key) {
+					endpoint
+						.certificate(
+							read_to_string(certificate)
+								.with_context(|| format!("Can't read {}", certificate))?,
+						)
+						.key(read_to_string(key).with_context(|| format!("Can't read {}", key))?);
+				}
+				let domain: Domain =
+					endpoint.build()?.query(&context.gitlab).with_context(|| {
+						format!(
+							"Failed to add pages domain {} to project {}",
+							&cmd_args.domain, &project.path_with_namespace
+						)
+					})?;
+
+				let mut msg = StyledStr::new();
+				domain_msg(&mut msg, &domain, true);
+				context.print_msg(msg)
+			}
+
+			PagesDomainsCmd::Remove(cmd_args) => {
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				let endpoint = DeletePagesDomain::builder()
+					.project(project.id.value())
+					.domain(cmd_args.domain.as_str())
+					.build()?;
+				api::ignore(endpoint)
+					.query(&context.gitlab)
+					.with_context(|| {
+						format!(
+							"Failed to remove pages domain {} from project {}",
+							&cmd_args.domain, &project.path_with_namespace
+						)
+					})?;
+				println!(
+					"pages domain {} has been removed from project {}",
+					&cmd_args.domain, &project.path_with_namespace
+				);
+				Ok(())
+			}
+
+			PagesDomainsCmd::Verify(cmd_args) => {
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				let endpoint = VerifyPagesDomain::builder()
+					.project(project.id.value())
+					.domain(cmd_args.domain.as_str())
+					.build()?;
+				let domain: Domain = endpoint.query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to verify pages domain {} of project {}",
+						&cmd_args.domain, &project.path_with_namespace
+					)
+				})?;
+
+				let mut msg = StyledStr::new();
+				domain_msg(&mut msg, &domain, false);
+				context.print_msg(msg)?;
+				if !domain.verified {
+					bail!(
+						"pages domain {} is not verified. Add the TXT record above to its DNS zone",
+						&domain.domain
+					)
+				}
+				Ok(())
+			}
+		},
+	}
+}
@@ -1,31 +1,57 @@
 use crate::{
 	args::{self, PipelineCmd},
 	context::CliContext,
+	utils::take_from_vec,
 };
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use gitlab::{
 	api::{
 		self,
 		projects::{
 			jobs::{self, JobScope},
-			pipelines,
+			merge_requests, pipelines,
 		},
 		Pagination, Query,
 	},
 	types,
 };
 
+/// List the latest pipelines of a merge request
+fn mr_pipelines(
+	context: &CliContext,
+	project: &types::Project,
+	mr: &types::MergeRequest,
+	limit: usize,
+) -> Result<Vec<types::PipelineBasic>> {
+	let endpoint = merge_requests::pipelines::MergeRequestPipelines::builder()
+		.project(project.path_with_namespace.as_str())
+		.merge_request(mr.iid.value())
+		.build()?;
+	api::paged(endpoint, Pagination::Limit(limit))
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to list pipelines for merge request !{} of {}",
+				mr.iid.value(),
+				&project.name_with_namespace
+			)
+		})
+}
+
 /// Command implementation
 pub fn cmd(context: &CliContext, args: &args::Pipeline) -> Result<()> {
 	match &args.cmd {
 		PipelineCmd::List(cmd_args) => {
 			// get project from command line or context
 			let project = context.get_project(cmd_args.project.as_ref())?;
-			let endpoint = pipelines::Pipelines::builder()
-				.project(project.path_with_namespace.to_owned())
-				.build()?;
-			let pipelines: Vec<types::PipelineBasic> =
+			let pipelines = if cmd_args.mr {
+				let mr = context.get_current_mr(&project)?;
+				mr_pipelines(context, &project, &mr, cmd_args.limit)?
+			} else {
+				let endpoint = pipelines::Pipelines::builder()
+					.project(project.path_with_namespace.to_owned())
+					.build()?;
 				api::paged(endpoint, Pagination::Limit(cmd_args.limit))
 					.query(&context.gitlab)
 					.with_context(|| {
@@ -33,7 +59,8 @@ pub fn cmd(context: &CliContext, args: &args::Pipeline) -> Result<()> {
 							"Failed to list pipelines for {}",
 							&project.name_with_namespace
 						)
-					})?;
+					})?
+			};
 
 			context.print_pipelines(&pipelines, &project)?;
 
@@ -74,9 +101,21 @@ pub fn cmd(context: &CliContext, args: &args::Pipeline) -> Result<()> {
 		PipelineCmd::Status(cmd_args) => {
 			// get project from command line or context
 			let project = context.get_project(cmd_args.project.as_ref())?;
-			// get a reference (a tag or a branch)
-			let ref_ = context.check_ref(cmd_args.ref_.as_ref(), &project)?;
-			let pipeline = context.get_pipeline(cmd_args.id, &project, &ref_)?;
+			let pipeline = if cmd_args.mr && cmd_args.id.is_none() {
+				// get the latest pipeline of the merge request
+				let mr = context.get_current_mr(&project)?;
+				take_from_vec(mr_pipelines(context, &project, &mr, 1)?, 0).ok_or_else(|| {
+					anyhow!(
+						"Can't find a pipeline for merge request !{} of project {}",
+						mr.iid.value(),
+						&project.path_with_namespace
+					)
+				})?
+			} else {
+				// get a reference (a tag or a branch)
+				let ref_ = context.check_ref(cmd_args.ref_.as_ref(), &project)?;
+				context.get_pipeline(cmd_args.id, &project, &ref_)?
+			};
 
 			context.print_pipeline(&pipeline, &project)?;
 			let jobs = context.get_jobs(&project, pipeline.id.value())?;
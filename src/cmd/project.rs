@@ -1,15 +1,900 @@
-use crate::{args, context::CliContext};
+mod avatar;
+mod fork_relation;
+mod members;
+mod mirror;
+mod push_rules;
+mod settings;
+mod tokens;
+mod topics;
 
-use anyhow::Result;
+use crate::{
+	api::{
+		groups::GroupProjectsStatistics,
+		projects::{
+			Contributors, DeleteProject, EditProjectSettings, ExportDownload, ExportStatus,
+			ForkProject, Housekeeping, ImportProject, Languages, ScheduleExport, StarProject,
+			UnstarProject,
+		},
+	},
+	args::{self, Format, ProjectCmd, ProjectSettingsCmd},
+	color::StyledStr,
+	context::CliContext,
+	utils::{parse_access_level, parse_date, parse_visibility, percent_bar},
+};
 
-pub fn cmd(context: &CliContext, args: &args::Project) -> Result<()> {
-	let project = context.get_project(args.project.as_ref())?;
-	// get a reference (a tag or a branch)
-	let ref_ = context.get_ref(args.ref_.as_ref(), &project)?;
+use anyhow::{anyhow, bail, Context, Result};
+use bytesize::ByteSize;
+use gitlab::{
+	api::{
+		self,
+		common::AccessLevel,
+		groups,
+		projects::{self, CreateProject, Projects},
+		ApiError, Pagination, Query,
+	},
+	types,
+};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::{
+	cmp::Reverse,
+	collections::BTreeMap,
+	env::temp_dir,
+	fs::{read, remove_dir_all, write},
+	io::{self, Write},
+	path::Path,
+	process::Command,
+	thread::sleep,
+	time::Duration,
+};
+
+/// Width of the percentage bars
+const BAR_WIDTH: usize = 30;
+
+/// Delay between two import or export status checks
+const POLL_DELAY: Duration = Duration::from_secs(2);
+
+/// Number of import or export status checks before giving up (one hour)
+const POLL_ATTEMPTS: u32 = 1800;
+
+#[derive(Deserialize)]
+struct ImportedProject {
+	id: u64,
+	path_with_namespace: String,
+}
+
+#[derive(Deserialize)]
+struct ImportStatus {
+	import_status: String,
+	import_error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ExportState {
+	export_status: String,
+}
 
-	context.print_project(&project, &ref_)?;
-	if context.open {
-		let _ = open::that(format!("{}/-/tree/{}", &project.web_url, &ref_));
+#[derive(Deserialize, Default)]
+struct Statistics {
+	storage_size: u64,
+	repository_size: u64,
+	lfs_objects_size: u64,
+	job_artifacts_size: u64,
+	#[serde(default)]
+	packages_size: u64,
+	#[serde(default)]
+	container_registry_size: u64,
+}
+
+impl Statistics {
+	/// Add the statistics of another project
+	fn add(&mut self, other: &Self) {
+		self.storage_size += other.storage_size;
+		self.repository_size += other.repository_size;
+		self.lfs_objects_size += other.lfs_objects_size;
+		self.job_artifacts_size += other.job_artifacts_size;
+		self.packages_size += other.packages_size;
+		self.container_registry_size += other.container_registry_size;
+	}
+
+	/// Append the statistics to the message
+	fn msg(&self, msg: &mut StyledStr) {
+		msg.literal(ByteSize(self.storage_size).to_string());
+		msg.none(" (repository ");
+		msg.literal(ByteSize(self.repository_size).to_string());
+		msg.none(", artifacts ");
+		msg.literal(ByteSize(self.job_artifacts_size).to_string());
+		msg.none(", lfs ");
+		msg.literal(ByteSize(self.lfs_objects_size).to_string());
+		msg.none(", packages ");
+		msg.literal(ByteSize(self.packages_size).to_string());
+		msg.none(", registry ");
+		msg.literal(ByteSize(self.container_registry_size).to_string());
+		msg.none(")\n");
+	}
+}
+
+#[derive(Deserialize)]
+struct ProjectStatistics {
+	path_with_namespace: String,
+	#[serde(default)]
+	statistics: Statistics,
+}
+
+/// Get the storage statistics of a project
+fn get_statistics(context: &CliContext, id: u64) -> Result<ProjectStatistics> {
+	projects::Project::builder()
+		.project(id)
+		.statistics(true)
+		.build()?
+		.query(&context.gitlab)
+		.with_context(|| format!("Failed to get statistics of project {}", id))
+}
+
+#[derive(Deserialize)]
+struct Contributor {
+	name: String,
+	email: String,
+	commits: u64,
+}
+
+#[derive(Deserialize)]
+struct DeletionStatus {
+	marked_for_deletion_on: Option<String>,
+}
+
+/// Print the clone urls of a project
+fn print_urls(project: &types::Project) {
+	println!("  ssh:  {}", project.ssh_url_to_repo);
+	println!("  http: {}", project.http_url_to_repo);
+}
+
+/// Copy the repository of a project (all branches and tags) into an empty project with git
+fn copy_repository(context: &CliContext, from: &types::Project, to: &types::Project) -> Result<()> {
+	let dir = temp_dir().join(format!("glctl-{}.git", to.id.value()));
+	let cloned = Command::new("git")
+		.args(["clone", "--bare", "--quiet"])
+		.arg(&from.ssh_url_to_repo)
+		.arg(&dir)
+		.status()
+		.context("Failed to run git")?
+		.success();
+	let pushed = cloned
+		&& Command::new("git")
+			.arg("-C")
+			.arg(&dir)
+			.args(["push", "--mirror", "--quiet"])
+			.arg(&to.ssh_url_to_repo)
+			.status()
+			.context("Failed to run git")?
+			.success();
+	let _ = remove_dir_all(&dir);
+	if !pushed {
+		bail!(
+			"Failed to copy the repository of {} to {}",
+			&from.path_with_namespace,
+			&to.path_with_namespace
+		)
+	}
+
+	// the first pushed branch is not necessarily the default branch of the template
+	if let Some(branch) = to.default_branch.as_ref().or(from.default_branch.as_ref()) {
+		let endpoint = EditProjectSettings::builder()
+			.project(to.id.value())
+			.settings(vec![("default_branch".into(), branch.into())])
+			.build()?;
+		api::ignore(endpoint)
+			.query(&context.gitlab)
+			.with_context(|| {
+				format!(
+					"Failed to change the default branch of project {}",
+					&to.path_with_namespace
+				)
+			})?;
 	}
 	Ok(())
 }
+
+/// Wait for a project to finish importing
+fn wait_import(context: &CliContext, id: u64, path: &str) -> Result<()> {
+	println!("waiting for {} to finish importing", path);
+	let endpoint = projects::Project::builder().project(id).build()?;
+	for _ in 0..POLL_ATTEMPTS {
+		let status: ImportStatus = endpoint
+			.query(&context.gitlab)
+			.with_context(|| format!("Failed to get import status of {}", path))?;
+		match status.import_status.as_str() {
+			"finished" | "none" => return Ok(()),
+			"failed" => bail!(
+				"Import of {} failed: {}",
+				path,
+				status.import_error.unwrap_or_default()
+			),
+			_ => sleep(POLL_DELAY),
+		}
+	}
+	bail!("Import of {} is still not finished. Giving up", path)
+}
+
+pub fn cmd(context: &CliContext, args: &args::Project) -> Result<()> {
+	match &args.cmd {
+		None => {
+			let project = context.get_project(args.project.as_ref())?;
+			// get a reference (a tag or a branch)
+			let ref_ = context.get_ref(args.ref_.as_ref(), &project)?;
+
+			context.print_project(&project, &ref_)?;
+			if context.open {
+				let _ = open::that(format!("{}/-/tree/{}", &project.web_url, &ref_));
+			}
+			Ok(())
+		}
+
+		Some(ProjectCmd::Create(cmd_args)) => {
+			let mut endpoint = CreateProject::builder();
+			endpoint
+				.name(cmd_args.name.as_str())
+				.visibility(parse_visibility(&cmd_args.visibility)?)
+				.initialize_with_readme(cmd_args.readme);
+			// the namespace id is needed to create a project inside a group
+			if let Some(namespace) = &cmd_args.namespace {
+				let group: types::Group = groups::Group::builder()
+					.group(namespace.as_str())
+					.build()?
+					.query(&context.gitlab)
+					.with_context(|| format!("Can't find a group named {}", namespace))?;
+				endpoint.namespace_id(group.id.value());
+			}
+			if let Some(branch) = &cmd_args.default_branch {
+				endpoint.default_branch(branch.as_str());
+			}
+			if let Some(description) = &cmd_args.description {
+				endpoint.description(description.as_str());
+			}
+			let template = match &cmd_args.from_template {
+				Some(path) => Some(context.get_project(Some(path))?),
+				None => None,
+			};
+			if template.is_some() && cmd_args.readme {
+				bail!("--readme can't be used with --from-template")
+			}
+
+			// use the template project mechanism of gitlab when available
+			let mut created: Option<types::Project> = None;
+			if let Some(template) = &template {
+				let mut template_endpoint = endpoint.clone();
+				template_endpoint
+					.template_project_id(template.id.value())
+					.use_custom_template(true);
+				let result: Result<types::Project, _> =
+					template_endpoint.build()?.query(&context.gitlab);
+				match result {
+					Ok(project) => {
+						wait_import(context, project.id.value(), &project.path_with_namespace)?;
+						created = Some(project);
+					}
+					Err(err) => {
+						// fall back only when the feature is not available on this instance or
+						// to this user
+						let unavailable = match &err {
+							ApiError::Gitlab { msg } => {
+								msg.starts_with("403") || msg.starts_with("404")
+							}
+							ApiError::GitlabService { status, .. } => {
+								matches!(status.as_u16(), 403 | 404)
+							}
+							_ => false,
+						};
+						if !unavailable {
+							return Err(err).with_context(|| {
+								format!(
+									"Failed to create project {} from template {}",
+									&cmd_args.name, &template.path_with_namespace
+								)
+							});
+						}
+						if context.verbose {
+							println!(
+								"custom project templates are not available ({}), copying the repository instead",
+								err
+							);
+						}
+					}
+				}
+			}
+			let project = match created {
+				Some(project) => project,
+				None => {
+					let project: types::Project = endpoint
+						.build()?
+						.query(&context.gitlab)
+						.with_context(|| format!("Failed to create project {}", &cmd_args.name))?;
+					if let Some(template) = &template {
+						copy_repository(context, template, &project)?;
+					}
+					project
+				}
+			};
+
+			match &template {
+				Some(template) => println!(
+					"project {} has been created from template {}",
+					&project.path_with_namespace, &template.path_with_namespace
+				),
+				None => println!("project {} has been created", &project.path_with_namespace),
+			}
+			print_urls(&project);
+
+			if context.open {
+				let _ = open::that(&project.web_url);
+			}
+			Ok(())
+		}
+
+		Some(ProjectCmd::Fork(cmd_args)) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut endpoint = ForkProject::builder();
+			endpoint.project(project.id.value());
+			if let Some(namespace) = &cmd_args.namespace {
+				endpoint.namespace_path(namespace.as_str());
+			}
+			if let Some(name) = &cmd_args.name {
+				endpoint.name(name.as_str()).path(name.as_str());
+			}
+			let fork: types::Project =
+				endpoint.build()?.query(&context.gitlab).with_context(|| {
+					format!("Failed to fork project {}", &project.path_with_namespace)
+				})?;
+
+			if cmd_args.wait {
+				wait_import(context, fork.id.value(), &fork.path_with_namespace)?;
+			}
+
+			println!(
+				"project {} has been forked to {}",
+				&project.path_with_namespace, &fork.path_with_namespace
+			);
+			print_urls(&fork);
+
+			if context.open {
+				let _ = open::that(&fork.web_url);
+			}
+			Ok(())
+		}
+
+		Some(ProjectCmd::Delete(cmd_args)) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+
+			// ask for the project path unless explicitly told not to
+			if !(cmd_args.yes && cmd_args.i_know_what_i_am_doing) {
+				print!(
+					"Type the project path ({}) to confirm its deletion: ",
+					&project.path_with_namespace
+				);
+				io::stdout().flush()?;
+				let mut answer = String::new();
+				io::stdin().read_line(&mut answer)?;
+				if answer.trim() != project.path_with_namespace {
+					bail!("Deletion of {} aborted", &project.path_with_namespace)
+				}
+			}
+
+			let endpoint = DeleteProject::builder()
+				.project(project.id.value())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!("Failed to delete project {}", &project.path_with_namespace)
+				})?;
+
+			// with delayed deletion, the project still exists but is marked for deletion
+			let status: Option<DeletionStatus> = projects::Project::builder()
+				.project(project.id.value())
+				.build()?
+				.query(&context.gitlab)
+				.ok();
+			match status.and_then(|status| status.marked_for_deletion_on) {
+				Some(date) => println!(
+					"project {} is marked for deletion and will be deleted on {}",
+					&project.path_with_namespace, date
+				),
+				None => println!("project {} has been deleted", &project.path_with_namespace),
+			}
+			Ok(())
+		}
+
+		Some(ProjectCmd::Settings(cmd_args)) => match &cmd_args.cmd {
+			ProjectSettingsCmd::Get(cmd_args) => {
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				let mut settings: Map<String, Value> = projects::Project::builder()
+					.project(project.id.value())
+					.build()?
+					.query(&context.gitlab)
+					.with_context(|| {
+						format!(
+							"Failed to get settings of project {}",
+							&project.path_with_namespace
+						)
+					})?;
+				// only keep the requested settings
+				if !cmd_args.keys.is_empty() {
+					settings.retain(|key, _| cmd_args.keys.contains(key));
+				}
+
+				match cmd_args.format {
+					Format::Yaml => print!("{}", serde_yaml::to_string(&settings)?),
+					Format::Json => println!("{}", serde_json::to_string_pretty(&settings)?),
+					Format::Csv => bail!("csv format is not supported for settings"),
+				}
+				Ok(())
+			}
+
+			ProjectSettingsCmd::Set(cmd_args) => {
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				let settings = cmd_args
+					.settings
+					.iter()
+					.map(|setting| {
+						setting
+							.split_once('=')
+							.map(|(key, value)| (key.trim().into(), value.trim().into()))
+							.ok_or_else(|| anyhow!("Invalid setting {}. Use key=value", setting))
+					})
+					.collect::<Result<Vec<_>>>()?;
+				if settings.is_empty() {
+					bail!("Specify at least one setting (key=value) on the command line")
+				}
+
+				let endpoint = EditProjectSettings::builder()
+					.project(project.id.value())
+					.settings(settings)
+					.build()?;
+				let updated: Map<String, Value> =
+					endpoint.query(&context.gitlab).with_context(|| {
+						format!(
+							"Failed to change settings of project {}",
+							&project.path_with_namespace
+						)
+					})?;
+
+				// show the values as returned by gitlab
+				for setting in cmd_args.settings.iter() {
+					if let Some((key, _)) = setting.split_once('=') {
+						let key = key.trim();
+						println!(
+							"{} = {} on project {}",
+							key,
+							updated.get(key).unwrap_or(&Value::Null),
+							&project.path_with_namespace
+						);
+					}
+				}
+
+				if context.open {
+					let _ = open::that(format!("{}/edit", &project.web_url));
+				}
+				Ok(())
+			}
+
+			ProjectSettingsCmd::Export(cmd_args) => settings::export(context, cmd_args),
+			ProjectSettingsCmd::Apply(cmd_args) => settings::apply(context, cmd_args),
+		},
+
+		Some(ProjectCmd::List(cmd_args)) => {
+			let pagination = Pagination::Limit(cmd_args.limit);
+			let projects: Vec<types::Project> = if let Some(group) = &cmd_args.group {
+				let mut endpoint = groups::projects::GroupProjects::builder();
+				endpoint
+					.group(group.as_str())
+					.include_subgroups(true)
+					.starred(cmd_args.starred);
+				// being at least guest is the same as being a member
+				if cmd_args.membership {
+					endpoint.min_access_level(AccessLevel::Guest);
+				}
+				if let Some(search) = &cmd_args.search {
+					endpoint.search(search.as_str());
+				}
+				// group projects can't be filtered by topic on the server side, so all of them
+				// are listed before applying the limit
+				let pagination = if cmd_args.topic.is_some() {
+					Pagination::All
+				} else {
+					pagination
+				};
+				let projects: Vec<types::Project> = api::paged(endpoint.build()?, pagination)
+					.query(&context.gitlab)
+					.with_context(|| format!("Failed to list projects of group {}", group))?;
+				if let Some(topic) = &cmd_args.topic {
+					projects
+						.into_iter()
+						.filter(|project| project.tag_list.contains(topic))
+						.take(cmd_args.limit)
+						.collect()
+				} else {
+					projects
+				}
+			} else {
+				let mut endpoint = Projects::builder();
+				endpoint
+					.membership(cmd_args.membership)
+					.starred(cmd_args.starred);
+				if let Some(search) = &cmd_args.search {
+					endpoint.search(search.as_str());
+				}
+				if let Some(topic) = &cmd_args.topic {
+					endpoint.topic(topic.as_str());
+				}
+				api::paged(endpoint.build()?, pagination)
+					.query(&context.gitlab)
+					.with_context(|| "Failed to list projects")?
+			};
+
+			context.print_projects(&projects)?;
+			Ok(())
+		}
+
+		Some(ProjectCmd::Mirror(cmd_args)) => mirror::cmd(context, cmd_args),
+		Some(ProjectCmd::Topics(cmd_args)) => topics::cmd(context, cmd_args),
+		Some(ProjectCmd::Avatar(cmd_args)) => avatar::cmd(context, cmd_args),
+		Some(ProjectCmd::ForkRelation(cmd_args)) => fork_relation::cmd(context, cmd_args),
+
+		Some(ProjectCmd::Members(cmd_args)) => members::cmd(context, cmd_args),
+		Some(ProjectCmd::PushRules(cmd_args)) => push_rules::cmd(context, cmd_args),
+		Some(ProjectCmd::Tokens(cmd_args)) => tokens::cmd(context, cmd_args),
+
+		Some(ProjectCmd::Share(cmd_args)) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let group: types::Group = groups::Group::builder()
+				.group(cmd_args.group.as_str())
+				.build()?
+				.query(&context.gitlab)
+				.with_context(|| format!("Can't find a group named {}", &cmd_args.group))?;
+			let mut endpoint = projects::ShareProject::builder();
+			endpoint
+				.project(project.id.value())
+				.group_id(group.id.value())
+				.group_access(parse_access_level(&cmd_args.access)?);
+			if let Some(expires) = &cmd_args.expires {
+				endpoint.expires_at(parse_date(expires)?);
+			}
+			api::ignore(endpoint.build()?)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to share project {} with group {}",
+						&project.path_with_namespace, &group.full_path
+					)
+				})?;
+			println!(
+				"project {} is now shared with group {} as {}",
+				&project.path_with_namespace, &group.full_path, &cmd_args.access
+			);
+			Ok(())
+		}
+
+		Some(ProjectCmd::Unshare(cmd_args)) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let group: types::Group = groups::Group::builder()
+				.group(cmd_args.group.as_str())
+				.build()?
+				.query(&context.gitlab)
+				.with_context(|| format!("Can't find a group named {}", &cmd_args.group))?;
+			let endpoint = projects::UnshareProject::builder()
+				.project(project.id.value())
+				.group_id(group.id.value())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to unshare project {} with group {}",
+						&project.path_with_namespace, &group.full_path
+					)
+				})?;
+			println!(
+				"project {} is no longer shared with group {}",
+				&project.path_with_namespace, &group.full_path
+			);
+			Ok(())
+		}
+
+		Some(ProjectCmd::Star(cmd_args)) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = StarProject::builder().project(project.id.value()).build()?;
+			match api::ignore(endpoint).query(&context.gitlab) {
+				// gitlab answers 304 when the project is already starred
+				Err(ApiError::GitlabService { status, .. }) if status.as_u16() == 304 => {
+					println!(
+						"project {} is already starred",
+						&project.path_with_namespace
+					)
+				}
+				res => {
+					res.with_context(|| {
+						format!("Failed to star project {}", &project.path_with_namespace)
+					})?;
+					println!("project {} has been starred", &project.path_with_namespace)
+				}
+			}
+			Ok(())
+		}
+
+		Some(ProjectCmd::Unstar(cmd_args)) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = UnstarProject::builder()
+				.project(project.id.value())
+				.build()?;
+			match api::ignore(endpoint).query(&context.gitlab) {
+				// gitlab answers 304 when the project is not starred
+				Err(ApiError::GitlabService { status, .. }) if status.as_u16() == 304 => {
+					println!("project {} is not starred", &project.path_with_namespace)
+				}
+				res => {
+					res.with_context(|| {
+						format!("Failed to unstar project {}", &project.path_with_namespace)
+					})?;
+					println!(
+						"project {} has been unstarred",
+						&project.path_with_namespace
+					)
+				}
+			}
+			Ok(())
+		}
+
+		Some(ProjectCmd::Export(cmd_args)) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = ScheduleExport::builder()
+				.project(project.id.value())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to schedule export of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+
+			println!(
+				"waiting for {} export to finish",
+				&project.path_with_namespace
+			);
+			let endpoint = ExportStatus::builder()
+				.project(project.id.value())
+				.build()?;
+			let mut attempt = 0;
+			loop {
+				let state: ExportState = endpoint.query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to get export status of {}",
+						&project.path_with_namespace
+					)
+				})?;
+				match state.export_status.as_str() {
+					"finished" => break,
+					"failed" => bail!("Export of {} failed", &project.path_with_namespace),
+					// there is no export although one has just been scheduled
+					"none" => bail!(
+						"Export of {} has not been scheduled",
+						&project.path_with_namespace
+					),
+					_ if attempt == POLL_ATTEMPTS => bail!(
+						"Export of {} is still not finished. Giving up",
+						&project.path_with_namespace
+					),
+					_ => sleep(POLL_DELAY),
+				}
+				attempt += 1;
+			}
+
+			let endpoint = ExportDownload::builder()
+				.project(project.id.value())
+				.build()?;
+			let tarball = api::raw(endpoint).query(&context.gitlab).with_context(|| {
+				format!(
+					"Failed to download export of {}",
+					&project.path_with_namespace
+				)
+			})?;
+			let output = cmd_args
+				.output
+				.clone()
+				.unwrap_or_else(|| format!("{}_export.tar.gz", &project.path));
+			write(&output, &tarball).with_context(|| format!("Can't write {}", &output))?;
+			println!(
+				"project {} has been exported to {} ({})",
+				&project.path_with_namespace,
+				&output,
+				ByteSize(tarball.len() as u64)
+			);
+			Ok(())
+		}
+
+		Some(ProjectCmd::Import(cmd_args)) => {
+			let file =
+				read(&cmd_args.file).with_context(|| format!("Can't open {}", &cmd_args.file))?;
+			let filename = Path::new(&cmd_args.file)
+				.file_name()
+				.map(|name| name.to_string_lossy().to_string())
+				.unwrap_or_else(|| cmd_args.file.to_owned());
+
+			let mut endpoint = ImportProject::builder();
+			endpoint
+				.path(cmd_args.path.as_str())
+				.filename(filename)
+				.file(file);
+			if let Some(namespace) = &cmd_args.namespace {
+				endpoint.namespace(namespace.as_str());
+			}
+			if let Some(name) = &cmd_args.name {
+				endpoint.name(name.as_str());
+			}
+			let imported: ImportedProject = endpoint
+				.build()?
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to import {}", &cmd_args.file))?;
+
+			wait_import(context, imported.id, &imported.path_with_namespace)?;
+			let project = context.get_project(Some(&imported.path_with_namespace))?;
+			println!(
+				"{} has been imported to project {}",
+				&cmd_args.file, &project.path_with_namespace
+			);
+			print_urls(&project);
+
+			if context.open {
+				let _ = open::that(&project.web_url);
+			}
+			Ok(())
+		}
+
+		Some(ProjectCmd::Stats(cmd_args)) => {
+			let mut msg = StyledStr::new();
+			if let Some(group) = &cmd_args.group {
+				let endpoint = GroupProjectsStatistics::builder()
+					.group(group.as_str())
+					.build()?;
+				let mut stats: Vec<ProjectStatistics> = api::paged(endpoint, Pagination::All)
+					.query(&context.gitlab)
+					.with_context(|| format!("Failed to list projects of group {}", group))?;
+				// biggest projects first
+				stats.sort_by_key(|stat| Reverse(stat.statistics.storage_size));
+
+				let mut total = Statistics::default();
+				stats.iter().for_each(|stat| total.add(&stat.statistics));
+				msg.none("Group ");
+				msg.literal(group);
+				msg.none(format!(" ({} projects) ", stats.len()));
+				total.msg(&mut msg);
+				for stat in stats.iter().take(cmd_args.limit) {
+					msg.none("- ");
+					msg.literal(&stat.path_with_namespace);
+					msg.none(" ");
+					stat.statistics.msg(&mut msg);
+				}
+			} else {
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				let stat = get_statistics(context, project.id.value())?;
+				msg.none("Project ");
+				msg.literal(&stat.path_with_namespace);
+				msg.none(" ");
+				stat.statistics.msg(&mut msg);
+			}
+			context.print_msg(msg)?;
+			Ok(())
+		}
+
+		Some(ProjectCmd::Housekeeping(cmd_args)) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut endpoint = Housekeeping::builder();
+			endpoint.project(project.id.value());
+			if let Some(task) = &cmd_args.task {
+				if task != "eager" && task != "prune" {
+					bail!(
+						"{} not supported for --task. Use either \"eager\" or \"prune\"",
+						task
+					)
+				}
+				endpoint.task(task.as_str());
+			}
+			api::ignore(endpoint.build()?)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to start housekeeping on project {}",
+						&project.path_with_namespace
+					)
+				})?;
+			println!(
+				"housekeeping has been started on project {}",
+				&project.path_with_namespace
+			);
+			Ok(())
+		}
+
+		Some(ProjectCmd::Languages(cmd_args)) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = Languages::builder().project(project.id.value()).build()?;
+			let languages: BTreeMap<String, f64> =
+				endpoint.query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to get languages of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+			// most used languages first
+			let mut languages: Vec<_> = languages.into_iter().collect();
+			languages.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+			let width = languages
+				.iter()
+				.map(|(name, _)| name.len())
+				.max()
+				.unwrap_or_default();
+			let mut msg = StyledStr::new();
+			msg.none("Languages of ");
+			msg.literal(&project.path_with_namespace);
+			msg.none("\n");
+			for (name, percent) in languages.iter() {
+				msg.none(format!("{:width$} ", name, width = width));
+				msg.good(percent_bar(*percent, BAR_WIDTH));
+				msg.literal(format!(" {:5.1}%\n", percent));
+			}
+			context.print_msg(msg)?;
+			Ok(())
+		}
+
+		Some(ProjectCmd::Contributors(cmd_args)) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = Contributors::builder()
+				.project(project.id.value())
+				.order_by("commits")
+				.sort("desc")
+				.build()?;
+			let contributors: Vec<Contributor> = api::paged(endpoint, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to get contributors of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+
+			let total: u64 = contributors.iter().map(|c| c.commits).sum();
+			let width = contributors
+				.iter()
+				.take(cmd_args.limit)
+				.map(|c| c.name.len())
+				.max()
+				.unwrap_or_default();
+			let mut msg = StyledStr::new();
+			msg.none("Contributors of ");
+			msg.literal(&project.path_with_namespace);
+			msg.none(format!(" ({} commits)\n", total));
+			for (i, contributor) in contributors.iter().take(cmd_args.limit).enumerate() {
+				let percent = if total > 0 {
+					contributor.commits as f64 * 100.0 / total as f64
+				} else {
+					0.0
+				};
+				msg.none(format!(
+					"{:2}. {:width$} ",
+					i + 1,
+					contributor.name,
+					width = width
+				));
+				msg.good(percent_bar(percent, BAR_WIDTH));
+				msg.literal(format!(" {}", contributor.commits));
+				if context.verbose {
+					msg.hint(format!(" <{}>", contributor.email));
+				}
+				msg.none("\n");
+			}
+			context.print_msg(msg)?;
+			Ok(())
+		}
+	}
+}
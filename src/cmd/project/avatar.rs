@@ -0,0 +1,79 @@
+use crate::{
+	api::projects::{Avatar, UploadAvatar},
+	args::{self, ProjectAvatarCmd},
+	context::CliContext,
+};
+
+use anyhow::{bail, Context, Result};
+use bytesize::ByteSize;
+use gitlab::api::{self, Query};
+use std::{
+	fs::{read, write},
+	path::Path,
+};
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::ProjectAvatar) -> Result<()> {
+	match &args.cmd {
+		ProjectAvatarCmd::Set(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let file =
+				read(&cmd_args.file).with_context(|| format!("Can't open {}", &cmd_args.file))?;
+			let filename = Path::new(&cmd_args.file)
+				.file_name()
+				.map(|name| name.to_string_lossy().to_string())
+				.unwrap_or_else(|| cmd_args.file.to_owned());
+
+			let endpoint = UploadAvatar::builder()
+				.project(project.id.value())
+				.filename(filename)
+				.file(file)
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to change avatar of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+			println!(
+				"avatar of project {} has been set to {}",
+				&project.path_with_namespace, &cmd_args.file
+			);
+			Ok(())
+		}
+
+		ProjectAvatarCmd::Get(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			// use the file name of the avatar url as default output
+			let output = if let Some(output) = &cmd_args.output {
+				output.to_owned()
+			} else if let Some(name) = project
+				.avatar_url
+				.as_ref()
+				.and_then(|url| url.rsplit('/').next())
+			{
+				name.to_owned()
+			} else {
+				bail!("Project {} has no avatar", &project.path_with_namespace)
+			};
+
+			let endpoint = Avatar::builder().project(project.id.value()).build()?;
+			let image = api::raw(endpoint).query(&context.gitlab).with_context(|| {
+				format!(
+					"Failed to download avatar of project {}",
+					&project.path_with_namespace
+				)
+			})?;
+			write(&output, &image).with_context(|| format!("Can't write {}", &output))?;
+			println!(
+				"avatar of project {} has been saved to {} ({})",
+				&project.path_with_namespace,
+				&output,
+				ByteSize(image.len() as u64)
+			);
+			Ok(())
+		}
+	}
+}
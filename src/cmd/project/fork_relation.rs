@@ -0,0 +1,61 @@
+use crate::{
+	api::projects::{CreateForkRelation, DeleteForkRelation},
+	args::{self, ProjectForkRelationCmd},
+	context::CliContext,
+};
+
+use anyhow::{Context, Result};
+use gitlab::api::{self, Query};
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::ProjectForkRelation) -> Result<()> {
+	match &args.cmd {
+		ProjectForkRelationCmd::Create(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let source = context.get_project(Some(&cmd_args.source))?;
+			let endpoint = CreateForkRelation::builder()
+				.project(project.id.value())
+				.forked_from(source.id.value())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to mark project {} as a fork of {}",
+						&project.path_with_namespace, &source.path_with_namespace
+					)
+				})?;
+			println!(
+				"project {} is now a fork of {}",
+				&project.path_with_namespace, &source.path_with_namespace
+			);
+			Ok(())
+		}
+
+		ProjectForkRelationCmd::Delete(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = DeleteForkRelation::builder()
+				.project(project.id.value())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to remove fork relationship of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+			match &project.forked_from_project {
+				Some(source) => println!(
+					"project {} is no longer a fork of {}",
+					&project.path_with_namespace, &source.path_with_namespace
+				),
+				None => println!(
+					"project {} has no fork relationship",
+					&project.path_with_namespace
+				),
+			}
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,193 @@
+use crate::{
+	api::projects::InviteProjectMember,
+	args::{self, ProjectMembersCmd},
+	context::CliContext,
+	utils::{access_level_name, parse_access_level, parse_date},
+};
+
+use anyhow::{bail, Context, Result};
+use gitlab::{
+	api::{
+		self,
+		projects::members::{
+			AddProjectMember, AllProjectMembers, EditProjectMember, ProjectMembers,
+			RemoveProjectMember,
+		},
+		Pagination, Query,
+	},
+	types,
+};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Result of an invitation
+#[derive(Deserialize)]
+struct Invitation {
+	status: String,
+	message: Option<Value>,
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::ProjectMembers) -> Result<()> {
+	match &args.cmd {
+		ProjectMembersCmd::List(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = ProjectMembers::builder()
+				.project(project.id.value())
+				.build()?;
+			let direct: Vec<types::Member> = api::paged(endpoint, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to list members of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+			let direct: HashSet<u64> = direct.iter().map(|member| member.id.value()).collect();
+
+			// members of the parent groups are only returned by the members/all endpoint
+			let endpoint = AllProjectMembers::builder()
+				.project(project.id.value())
+				.build()?;
+			let mut members: Vec<types::Member> = api::paged(endpoint, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to list members of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+			members.sort_by(|a, b| a.username.cmp(&b.username));
+			let members: Vec<(types::Member, bool)> = members
+				.into_iter()
+				.map(|member| {
+					let inherited = !direct.contains(&member.id.value());
+					(member, inherited)
+				})
+				.collect();
+			context.print_members(&members)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/-/project_members", &project.web_url));
+			}
+			Ok(())
+		}
+
+		ProjectMembersCmd::Add(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let access = parse_access_level(&cmd_args.access)?;
+			let expires = cmd_args.expires.as_deref().map(parse_date).transpose()?;
+			match (&cmd_args.username, &cmd_args.invite) {
+				(Some(username), None) => {
+					let user = context.get_user(username)?;
+					let mut endpoint = AddProjectMember::builder();
+					endpoint
+						.project(project.id.value())
+						.user(user.id.value())
+						.access_level(access);
+					if let Some(expires) = expires {
+						endpoint.expires_at(expires);
+					}
+					let member: types::Member =
+						endpoint.build()?.query(&context.gitlab).with_context(|| {
+							format!(
+								"Failed to add {} to project {}",
+								username, &project.path_with_namespace
+							)
+						})?;
+					println!(
+						"{} has been added to project {} as {}",
+						&member.username,
+						&project.path_with_namespace,
+						access_level_name(member.access_level)
+					);
+				}
+				(None, Some(email)) => {
+					let mut endpoint = InviteProjectMember::builder();
+					endpoint
+						.project(project.id.value())
+						.email(email.as_str())
+						.access_level(access);
+					if let Some(expires) = expires {
+						endpoint.expires_at(expires);
+					}
+					// errors are reported in the body of a successful response
+					let invitation: Invitation =
+						endpoint.build()?.query(&context.gitlab).with_context(|| {
+							format!(
+								"Failed to invite {} to project {}",
+								email, &project.path_with_namespace
+							)
+						})?;
+					if invitation.status != "success" {
+						bail!(
+							"Failed to invite {} to project {}: {}",
+							email,
+							&project.path_with_namespace,
+							invitation.message.unwrap_or(Value::Null)
+						)
+					}
+					println!(
+						"{} has been invited to project {} as {}",
+						email, &project.path_with_namespace, &cmd_args.access
+					);
+				}
+				_ => {
+					bail!("Specify either a username or --invite with an email on the command line")
+				}
+			}
+			Ok(())
+		}
+
+		ProjectMembersCmd::Remove(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let user = context.get_user(&cmd_args.username)?;
+			let endpoint = RemoveProjectMember::builder()
+				.project(project.id.value())
+				.user(user.id.value())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to remove {} from project {}",
+						&cmd_args.username, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"{} has been removed from project {}",
+				&cmd_args.username, &project.path_with_namespace
+			);
+			Ok(())
+		}
+
+		ProjectMembersCmd::Update(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let user = context.get_user(&cmd_args.username)?;
+			let access = parse_access_level(&cmd_args.access)?;
+			let mut endpoint = EditProjectMember::builder();
+			endpoint
+				.project(project.id.value())
+				.user(user.id.value())
+				.access_level(access);
+			if let Some(expires) = &cmd_args.expires {
+				endpoint.expires_at(parse_date(expires)?);
+			}
+			let member: types::Member =
+				endpoint.build()?.query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to update {} in project {}",
+						&cmd_args.username, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"{} is now {} of project {}",
+				&member.username,
+				access_level_name(member.access_level),
+				&project.path_with_namespace
+			);
+			Ok(())
+		}
+	}
+}
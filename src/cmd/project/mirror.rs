@@ -0,0 +1,222 @@
+use crate::{
+	api::{
+		mirrors::{CreateRemoteMirror, EditRemoteMirror, RemoteMirrors},
+		projects::EditProjectSettings,
+	},
+	args::{self, ProjectMirrorCmd},
+	color::StyledStr,
+	context::CliContext,
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use gitlab::api::{self, projects, Pagination, Query};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct RemoteMirror {
+	id: u64,
+	url: String,
+	enabled: bool,
+	update_status: String,
+	last_update_at: Option<DateTime<Utc>>,
+	last_error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PullMirror {
+	#[serde(default)]
+	mirror: bool,
+	import_url: Option<String>,
+	import_status: Option<String>,
+}
+
+/// Url without the credentials, which are masked in the urls of the push mirrors
+fn without_credentials(url: &str) -> String {
+	if let Some((scheme, rest)) = url.split_once("://") {
+		let authority = rest.split('/').next().unwrap_or_default();
+		if let Some((_, host)) = authority.rsplit_once('@') {
+			return format!("{}://{}{}", scheme, host, &rest[authority.len()..]);
+		}
+	}
+	url.to_owned()
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::ProjectMirror) -> Result<()> {
+	match &args.cmd {
+		ProjectMirrorCmd::Set(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			match cmd_args.direction.as_str() {
+				"push" => {
+					// update the push mirror of the same url instead of adding another one
+					let mirrors: Vec<RemoteMirror> = api::paged(
+						RemoteMirrors::builder()
+							.project(project.id.value())
+							.build()?,
+						Pagination::All,
+					)
+					.query(&context.gitlab)
+					.with_context(|| {
+						format!(
+							"Failed to list mirrors of project {}",
+							&project.path_with_namespace
+						)
+					})?;
+					let url = without_credentials(&cmd_args.url);
+					if let Some(mirror) = mirrors
+						.iter()
+						.find(|mirror| without_credentials(&mirror.url) == url)
+					{
+						let endpoint = EditRemoteMirror::builder()
+							.project(project.id.value())
+							.mirror(mirror.id)
+							.enabled(true)
+							.keep_divergent_refs(cmd_args.keep_divergent_refs)
+							.only_protected_branches(cmd_args.only_protected_branches)
+							.build()?;
+						api::ignore(endpoint)
+							.query(&context.gitlab)
+							.with_context(|| {
+								format!(
+									"Failed to update push mirror {} on project {}",
+									mirror.id, &project.path_with_namespace
+								)
+							})?;
+						println!(
+							"push mirror {} has been updated on project {}",
+							mirror.id, &project.path_with_namespace
+						);
+					} else {
+						let endpoint = CreateRemoteMirror::builder()
+							.project(project.id.value())
+							.url(cmd_args.url.as_str())
+							.enabled(true)
+							.keep_divergent_refs(cmd_args.keep_divergent_refs)
+							.only_protected_branches(cmd_args.only_protected_branches)
+							.build()?;
+						api::ignore(endpoint)
+							.query(&context.gitlab)
+							.with_context(|| {
+								format!(
+									"Failed to add push mirror on project {}",
+									&project.path_with_namespace
+								)
+							})?;
+						println!(
+							"push mirror has been added on project {}",
+							&project.path_with_namespace
+						);
+					}
+				}
+				"pull" => {
+					if cmd_args.keep_divergent_refs {
+						bail!("--keep-divergent-refs is only supported by push mirrors")
+					}
+					let endpoint = EditProjectSettings::builder()
+						.project(project.id.value())
+						.settings(vec![
+							("import_url".into(), cmd_args.url.as_str().into()),
+							("mirror".into(), "true".into()),
+							(
+								"only_mirror_protected_branches".into(),
+								cmd_args.only_protected_branches.to_string().into(),
+							),
+						])
+						.build()?;
+					api::ignore(endpoint)
+						.query(&context.gitlab)
+						.with_context(|| {
+							format!(
+								"Failed to set pull mirror on project {}",
+								&project.path_with_namespace
+							)
+						})?;
+					println!(
+						"pull mirror has been set on project {}",
+						&project.path_with_namespace
+					);
+				}
+				direction => bail!(
+					"{} not supported for --direction. Use either \"push\" or \"pull\"",
+					direction
+				),
+			}
+
+			if context.open {
+				let _ = open::that(format!("{}/-/settings/repository", project.web_url));
+			}
+			Ok(())
+		}
+
+		ProjectMirrorCmd::Status(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = RemoteMirrors::builder()
+				.project(project.id.value())
+				.build()?;
+			let mirrors: Vec<RemoteMirror> = api::paged(endpoint, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to list mirrors of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+			let pull: PullMirror = projects::Project::builder()
+				.project(project.id.value())
+				.build()?
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to get pull mirror of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+
+			let mut msg = StyledStr::new();
+			msg.none("Mirrors of ");
+			msg.literal(&project.path_with_namespace);
+			msg.none("\n");
+			if pull.mirror {
+				msg.none("- Pull ");
+				msg.literal(pull.import_url.unwrap_or_default());
+				msg.none(" - ");
+				msg.none(pull.import_status.unwrap_or_default());
+				msg.none("\n");
+			}
+			for mirror in mirrors.iter() {
+				msg.none("- Push ");
+				msg.literal(mirror.id.to_string());
+				msg.none(format!(" {}", mirror.url));
+				if let Some(last_update_at) = mirror.last_update_at {
+					msg.none(" [");
+					msg.literal(
+						timeago::Formatter::new().convert_chrono(last_update_at, Utc::now()),
+					);
+					msg.none("]");
+				}
+				msg.none(" - ");
+				if !mirror.enabled {
+					msg.hint("disabled");
+				} else if mirror.update_status == "failed" {
+					msg.error(&mirror.update_status);
+				} else {
+					msg.good(&mirror.update_status);
+				}
+				msg.none("\n");
+				if let Some(error) = &mirror.last_error {
+					msg.error(format!("  {}\n", error));
+				}
+			}
+			if !pull.mirror && mirrors.is_empty() {
+				msg.none("No mirrors found\n");
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/-/settings/repository", project.web_url));
+			}
+			Ok(())
+		}
+	}
+}
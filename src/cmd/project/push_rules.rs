@@ -0,0 +1,106 @@
+use crate::{
+	api::projects::{EditPushRule, PushRule},
+	args::{self, Format, ProjectPushRulesCmd},
+	context::CliContext,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use gitlab::{
+	api::{ApiError, Query},
+	types,
+};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+
+/// Get the push rules of a project, or None if the project has no push rules yet
+fn get_push_rules(
+	context: &CliContext,
+	project: &types::Project,
+) -> Result<Option<Map<String, Value>>> {
+	// a project without push rules is reported as a 404 error or null depending on versions
+	PushRule::builder()
+		.project(project.id.value())
+		.build()?
+		.query(&context.gitlab)
+		.or_else(|err| match err {
+			ApiError::Gitlab { msg } if msg.starts_with("404") => Ok(None),
+			err => Err(err),
+		})
+		.with_context(|| {
+			format!(
+				"Failed to get push rules of project {}",
+				&project.path_with_namespace
+			)
+		})
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::ProjectPushRules) -> Result<()> {
+	match &args.cmd {
+		ProjectPushRulesCmd::Get(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let rules = get_push_rules(context, &project)?.unwrap_or_default();
+			match cmd_args.format {
+				Format::Yaml => print!("{}", serde_yaml::to_string(&rules)?),
+				Format::Json => println!("{}", serde_json::to_string_pretty(&rules)?),
+				Format::Csv => bail!("csv format is not supported for push rules"),
+			}
+			Ok(())
+		}
+
+		ProjectPushRulesCmd::Set(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut rules: Vec<(Cow<str>, Cow<str>)> = Vec::new();
+			if let Some(regex) = &cmd_args.commit_message_regex {
+				rules.push(("commit_message_regex".into(), regex.into()));
+			}
+			if let Some(deny) = cmd_args.deny_delete_tag {
+				rules.push(("deny_delete_tag".into(), deny.to_string().into()));
+			}
+			if let Some(size) = cmd_args.max_file_size {
+				rules.push(("max_file_size".into(), size.to_string().into()));
+			}
+			if let Some(prevent) = cmd_args.prevent_secrets {
+				rules.push(("prevent_secrets".into(), prevent.to_string().into()));
+			}
+			for rule in cmd_args.rules.iter() {
+				let (key, value) = rule
+					.split_once('=')
+					.ok_or_else(|| anyhow!("Invalid push rule {}. Use key=value", rule))?;
+				rules.push((key.trim().into(), value.trim().into()));
+			}
+			if rules.is_empty() {
+				bail!("Specify at least one push rule on the command line")
+			}
+			let keys: Vec<String> = rules.iter().map(|(key, _)| key.to_string()).collect();
+
+			let endpoint = EditPushRule::builder()
+				.project(project.id.value())
+				.create(get_push_rules(context, &project)?.is_none())
+				.rules(rules)
+				.build()?;
+			let updated: Map<String, Value> =
+				endpoint.query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to change push rules of project {}",
+						&project.path_with_namespace
+					)
+				})?;
+
+			// show the values as returned by gitlab
+			for key in keys.iter() {
+				println!(
+					"{} = {} on project {}",
+					key,
+					updated.get(key).unwrap_or(&Value::Null),
+					&project.path_with_namespace
+				);
+			}
+
+			if context.open {
+				let _ = open::that(format!("{}/-/settings/repository", &project.web_url));
+			}
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,677 @@
+use crate::{
+	api::projects::{
+		EditProjectApprovals, EditProjectSettings, ProjectApprovals, ProjectProtectedTags,
+		ProjectVariables,
+	},
+	args::{self, Format},
+	context::CliContext,
+	utils::{access_level_name, parse_protected_access_level},
+};
+
+use anyhow::{bail, Context, Result};
+use gitlab::{
+	api::{
+		self, groups,
+		projects::{
+			self,
+			protected_branches::{ProtectBranch, ProtectedBranches, UnprotectBranch},
+			protected_tags::{ProtectTag, ProtectedAccess, UnprotectTag},
+			variables::{ProjectVariableFilter, UpdateProjectVariable},
+		},
+		Pagination, Query,
+	},
+	types,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{borrow::Cow, collections::BTreeSet, fmt, fs::read_to_string};
+
+/// Project attributes exported in a settings document
+const SETTINGS_KEYS: &[&str] = &[
+	"default_branch",
+	"visibility",
+	"merge_method",
+	"squash_option",
+	"merge_requests_enabled",
+	"issues_enabled",
+	"wiki_enabled",
+	"jobs_enabled",
+	"snippets_enabled",
+	"container_registry_enabled",
+	"packages_enabled",
+	"lfs_enabled",
+	"request_access_enabled",
+	"shared_runners_enabled",
+	"auto_devops_enabled",
+	"ci_config_path",
+	"build_timeout",
+	"only_allow_merge_if_pipeline_succeeds",
+	"only_allow_merge_if_all_discussions_are_resolved",
+	"allow_merge_on_skipped_pipeline",
+	"remove_source_branch_after_merge",
+	"resolve_outdated_diff_discussions",
+	"printing_merge_request_link_enabled",
+];
+
+/// Merge request approvals attributes exported in a settings document
+const APPROVALS_KEYS: &[&str] = &[
+	"approvals_before_merge",
+	"reset_approvals_on_push",
+	"disable_overriding_approvers_per_merge_request",
+	"merge_requests_author_approval",
+	"merge_requests_disable_committers_approval",
+	"require_password_to_approve",
+];
+
+#[derive(Deserialize)]
+struct AccessLevelEntry {
+	access_level: Option<u64>,
+	user_id: Option<u64>,
+	group_id: Option<u64>,
+}
+
+impl AccessLevelEntry {
+	/// Name of the access in a settings document
+	fn name(&self) -> String {
+		match (self.user_id, self.group_id) {
+			(Some(id), _) => format!("user:{}", id),
+			(_, Some(id)) => format!("group:{}", id),
+			_ => access_level_name(self.access_level.unwrap_or_default()).to_owned(),
+		}
+	}
+}
+
+#[derive(Deserialize)]
+struct ProtectedBranch {
+	name: String,
+	push_access_levels: Vec<AccessLevelEntry>,
+	merge_access_levels: Vec<AccessLevelEntry>,
+	#[serde(default)]
+	allow_force_push: bool,
+}
+
+#[derive(Deserialize)]
+struct ProtectedTag {
+	name: String,
+	create_access_levels: Vec<AccessLevelEntry>,
+}
+
+#[derive(Deserialize)]
+struct Variable {
+	key: String,
+	value: String,
+	environment_scope: String,
+	protected: bool,
+	masked: bool,
+}
+
+/// Protection of a branch in a settings document
+#[derive(Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct BranchProtection {
+	name: String,
+	#[serde(default = "default_level")]
+	push: String,
+	#[serde(default = "default_level")]
+	merge: String,
+	#[serde(default)]
+	allow_force_push: bool,
+}
+
+/// Accesses allowed to create a protected tag: a single access level, or a list of access
+/// levels, users (user:<id>) and groups (group:<id>)
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum CreateAccess {
+	One(String),
+	Many(Vec<String>),
+}
+
+impl CreateAccess {
+	fn set(&self) -> BTreeSet<&str> {
+		match self {
+			Self::One(access) => BTreeSet::from([access.as_str()]),
+			Self::Many(accesses) => accesses.iter().map(String::as_str).collect(),
+		}
+	}
+}
+
+/// The order of the accesses doesn't matter
+impl PartialEq for CreateAccess {
+	fn eq(&self, other: &Self) -> bool {
+		self.set() == other.set()
+	}
+}
+
+impl fmt::Display for CreateAccess {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{}",
+			self.set().into_iter().collect::<Vec<_>>().join(", ")
+		)
+	}
+}
+
+/// Protection of a tag in a settings document
+#[derive(Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct TagProtection {
+	name: String,
+	#[serde(default = "default_create")]
+	create: CreateAccess,
+}
+
+/// Reference to a variable in a settings document. Values are never exported nor applied
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct VariableRef {
+	key: String,
+	#[serde(default = "default_scope")]
+	environment_scope: String,
+	#[serde(default)]
+	protected: bool,
+	#[serde(default)]
+	masked: bool,
+}
+
+/// Declarative description of the settings of a project. Missing sections are left untouched
+/// when applied
+#[derive(Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct SettingsDocument {
+	#[serde(default, skip_serializing_if = "Map::is_empty")]
+	settings: Map<String, Value>,
+	#[serde(default, skip_serializing_if = "Map::is_empty")]
+	approvals: Map<String, Value>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	protected_branches: Option<Vec<BranchProtection>>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	protected_tags: Option<Vec<TagProtection>>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	variables: Option<Vec<VariableRef>>,
+}
+
+fn default_level() -> String {
+	"maintainer".to_owned()
+}
+
+fn default_create() -> CreateAccess {
+	CreateAccess::One(default_level())
+}
+
+fn default_scope() -> String {
+	"*".to_owned()
+}
+
+/// Name of the first access level of a protection
+fn level_name(levels: &[AccessLevelEntry]) -> String {
+	access_level_name(
+		levels
+			.first()
+			.and_then(|level| level.access_level)
+			.unwrap_or_default(),
+	)
+	.to_owned()
+}
+
+/// Parse an access of a protection: an access level, a user (user:<id>) or a group
+/// (group:<id>)
+fn parse_protected_access(access: &str) -> Result<ProtectedAccess> {
+	let id = |id: &str| {
+		id.parse::<u64>()
+			.with_context(|| format!("{} is not a valid id in {}", id, access))
+	};
+	if let Some(user) = access.strip_prefix("user:") {
+		Ok(ProtectedAccess::User(id(user)?))
+	} else if let Some(group) = access.strip_prefix("group:") {
+		Ok(ProtectedAccess::Group(id(group)?))
+	} else {
+		Ok(ProtectedAccess::Level(parse_protected_access_level(
+			access,
+		)?))
+	}
+}
+
+/// Value of a setting as sent to the api
+fn param_value(value: &Value) -> String {
+	match value {
+		Value::String(value) => value.to_owned(),
+		Value::Null => String::new(),
+		value => value.to_string(),
+	}
+}
+
+/// Get the project attributes managed by settings documents
+fn get_settings(context: &CliContext, project: &types::Project) -> Result<Map<String, Value>> {
+	let mut settings: Map<String, Value> = projects::Project::builder()
+		.project(project.id.value())
+		.build()?
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to get settings of project {}",
+				&project.path_with_namespace
+			)
+		})?;
+	settings.retain(|key, _| SETTINGS_KEYS.contains(&key.as_str()));
+	Ok(settings)
+}
+
+/// Get the merge request approvals configuration of a project
+fn get_approvals(context: &CliContext, project: &types::Project) -> Result<Map<String, Value>> {
+	let mut approvals: Map<String, Value> = ProjectApprovals::builder()
+		.project(project.id.value())
+		.build()?
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to get approvals configuration of project {}",
+				&project.path_with_namespace
+			)
+		})?;
+	approvals.retain(|key, _| APPROVALS_KEYS.contains(&key.as_str()));
+	Ok(approvals)
+}
+
+fn get_protected_branches(
+	context: &CliContext,
+	project: &types::Project,
+) -> Result<Vec<BranchProtection>> {
+	let endpoint = ProtectedBranches::builder()
+		.project(project.id.value())
+		.build()?;
+	let branches: Vec<ProtectedBranch> = api::paged(endpoint, Pagination::All)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to get protected branches of project {}",
+				&project.path_with_namespace
+			)
+		})?;
+	Ok(branches
+		.into_iter()
+		.map(|branch| BranchProtection {
+			push: level_name(&branch.push_access_levels),
+			merge: level_name(&branch.merge_access_levels),
+			allow_force_push: branch.allow_force_push,
+			name: branch.name,
+		})
+		.collect())
+}
+
+fn get_protected_tags(
+	context: &CliContext,
+	project: &types::Project,
+) -> Result<Vec<TagProtection>> {
+	let endpoint = ProjectProtectedTags::builder()
+		.project(project.id.value())
+		.build()?;
+	let tags: Vec<ProtectedTag> = api::paged(endpoint, Pagination::All)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to get protected tags of project {}",
+				&project.path_with_namespace
+			)
+		})?;
+	Ok(tags
+		.into_iter()
+		.map(|tag| {
+			let mut accesses: Vec<String> = tag
+				.create_access_levels
+				.iter()
+				.map(AccessLevelEntry::name)
+				.collect();
+			accesses.sort();
+			TagProtection {
+				create: if accesses.len() == 1 {
+					CreateAccess::One(accesses.remove(0))
+				} else {
+					CreateAccess::Many(accesses)
+				},
+				name: tag.name,
+			}
+		})
+		.collect())
+}
+
+fn get_variables(context: &CliContext, project: &types::Project) -> Result<Vec<Variable>> {
+	let endpoint = ProjectVariables::builder()
+		.project(project.id.value())
+		.build()?;
+	let mut variables: Vec<Variable> = api::paged(endpoint, Pagination::All)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to get variables of project {}",
+				&project.path_with_namespace
+			)
+		})?;
+	variables.sort_by(|a, b| (&a.key, &a.environment_scope).cmp(&(&b.key, &b.environment_scope)));
+	Ok(variables)
+}
+
+/// Print the settings of a project as a settings document
+pub fn export(context: &CliContext, args: &args::ProjectSettingsExport) -> Result<()> {
+	let project = context.get_project(args.project.as_ref())?;
+	let variables = get_variables(context, &project)?
+		.into_iter()
+		.map(|variable| VariableRef {
+			key: variable.key,
+			environment_scope: variable.environment_scope,
+			protected: variable.protected,
+			masked: variable.masked,
+		})
+		.collect();
+	let document = SettingsDocument {
+		settings: get_settings(context, &project)?,
+		// approvals are not available on all gitlab tiers
+		approvals: get_approvals(context, &project).unwrap_or_default(),
+		protected_branches: Some(get_protected_branches(context, &project)?),
+		protected_tags: Some(get_protected_tags(context, &project)?),
+		variables: Some(variables),
+	};
+
+	match args.format {
+		Format::Yaml => print!("{}", serde_yaml::to_string(&document)?),
+		Format::Json => println!("{}", serde_json::to_string_pretty(&document)?),
+		Format::Csv => bail!("csv format is not supported for settings"),
+	}
+	Ok(())
+}
+
+/// Reconcile a project with a settings document. Return the number of changes
+fn reconcile(
+	context: &CliContext,
+	project: &types::Project,
+	document: &SettingsDocument,
+	dry_run: bool,
+) -> Result<usize> {
+	let path = &project.path_with_namespace;
+	let mut changes = 0;
+
+	if !document.settings.is_empty() {
+		let current = get_settings(context, project)?;
+		let settings: Vec<(Cow<str>, Cow<str>)> = document
+			.settings
+			.iter()
+			.filter(|(key, value)| current.get(key.as_str()) != Some(*value))
+			.map(|(key, value)| {
+				println!(
+					"project {}: {} {} -> {}",
+					path,
+					key,
+					current.get(key.as_str()).unwrap_or(&Value::Null),
+					value
+				);
+				(key.as_str().into(), param_value(value).into())
+			})
+			.collect();
+		changes += settings.len();
+		if !dry_run && !settings.is_empty() {
+			let endpoint = EditProjectSettings::builder()
+				.project(project.id.value())
+				.settings(settings)
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to change settings of project {}", path))?;
+		}
+	}
+
+	if !document.approvals.is_empty() {
+		let current = get_approvals(context, project)?;
+		let approvals: Vec<(Cow<str>, Cow<str>)> = document
+			.approvals
+			.iter()
+			.filter(|(key, value)| current.get(key.as_str()) != Some(*value))
+			.map(|(key, value)| {
+				println!(
+					"project {}: {} {} -> {}",
+					path,
+					key,
+					current.get(key.as_str()).unwrap_or(&Value::Null),
+					value
+				);
+				(key.as_str().into(), param_value(value).into())
+			})
+			.collect();
+		changes += approvals.len();
+		if !dry_run && !approvals.is_empty() {
+			let endpoint = EditProjectApprovals::builder()
+				.project(project.id.value())
+				.settings(approvals)
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to change approvals configuration of project {}",
+						path
+					)
+				})?;
+		}
+	}
+
+	if let Some(branches) = &document.protected_branches {
+		let current = get_protected_branches(context, project)?;
+		// remove the protections that are not described or that differ
+		for protection in current.iter().filter(|p| !branches.contains(p)) {
+			if !branches.iter().any(|b| b.name == protection.name) {
+				println!("project {}: unprotect branch '{}'", path, &protection.name);
+				changes += 1;
+			}
+			if !dry_run {
+				let endpoint = UnprotectBranch::builder()
+					.project(project.id.value())
+					.name(protection.name.as_str())
+					.build()?;
+				api::ignore(endpoint)
+					.query(&context.gitlab)
+					.with_context(|| {
+						format!(
+							"Failed to unprotect branch '{}' on project {}",
+							&protection.name, path
+						)
+					})?;
+			}
+		}
+		for protection in branches.iter().filter(|p| !current.contains(p)) {
+			println!(
+				"project {}: protect branch '{}' (push {}, merge {}, force push {})",
+				path,
+				&protection.name,
+				&protection.push,
+				&protection.merge,
+				protection.allow_force_push
+			);
+			changes += 1;
+			if !dry_run {
+				let endpoint = ProtectBranch::builder()
+					.project(project.id.value())
+					.name(protection.name.as_str())
+					.push_access_level(parse_protected_access_level(&protection.push)?)
+					.merge_access_level(parse_protected_access_level(&protection.merge)?)
+					.allow_force_push(protection.allow_force_push)
+					.build()?;
+				api::ignore(endpoint)
+					.query(&context.gitlab)
+					.with_context(|| {
+						format!(
+							"Failed to protect branch '{}' on project {}",
+							&protection.name, path
+						)
+					})?;
+			}
+		}
+	}
+
+	if let Some(tags) = &document.protected_tags {
+		let current = get_protected_tags(context, project)?;
+		// remove the protections that are not described or that differ
+		for protection in current.iter().filter(|p| !tags.contains(p)) {
+			if !tags.iter().any(|t| t.name == protection.name) {
+				println!("project {}: unprotect tag '{}'", path, &protection.name);
+				changes += 1;
+			}
+			if !dry_run {
+				let endpoint = UnprotectTag::builder()
+					.project(project.id.value())
+					.name(protection.name.as_str())
+					.build()?;
+				api::ignore(endpoint)
+					.query(&context.gitlab)
+					.with_context(|| {
+						format!(
+							"Failed to unprotect tag '{}' on project {}",
+							&protection.name, path
+						)
+					})?;
+			}
+		}
+		for protection in tags.iter().filter(|p| !current.contains(p)) {
+			println!(
+				"project {}: protect tag '{}' (create {})",
+				path, &protection.name, &protection.create
+			);
+			changes += 1;
+			if !dry_run {
+				let mut endpoint = ProtectTag::builder();
+				endpoint
+					.project(project.id.value())
+					.name(protection.name.as_str());
+				// the first access level replaces the default one (maintainer)
+				let mut default_replaced = false;
+				for access in protection.create.set() {
+					match parse_protected_access(access)? {
+						ProtectedAccess::Level(level) if !default_replaced => {
+							endpoint.create_access_level(level);
+							default_replaced = true;
+						}
+						access => {
+							endpoint.allowed_to_create(access);
+						}
+					}
+				}
+				let endpoint = endpoint.build()?;
+				api::ignore(endpoint)
+					.query(&context.gitlab)
+					.with_context(|| {
+						format!(
+							"Failed to protect tag '{}' on project {}",
+							&protection.name, path
+						)
+					})?;
+			}
+		}
+	}
+
+	if let Some(variables) = &document.variables {
+		let current = get_variables(context, project)?;
+		let mut missing = Vec::new();
+		for reference in variables.iter() {
+			let variable = current.iter().find(|v| {
+				v.key == reference.key && v.environment_scope == reference.environment_scope
+			});
+			match variable {
+				// values are secrets that can't be described in a document
+				None => missing.push(format!(
+					"{} ({})",
+					&reference.key, &reference.environment_scope
+				)),
+				Some(variable)
+					if variable.protected != reference.protected
+						|| variable.masked != reference.masked =>
+				{
+					println!(
+						"project {}: variable {} ({}) protected {}, masked {}",
+						path,
+						&reference.key,
+						&reference.environment_scope,
+						reference.protected,
+						reference.masked
+					);
+					changes += 1;
+					if !dry_run {
+						let endpoint = UpdateProjectVariable::builder()
+							.project(project.id.value())
+							.key(variable.key.as_str())
+							.value(variable.value.as_str())
+							.protected(reference.protected)
+							.masked(reference.masked)
+							.filter(
+								ProjectVariableFilter::builder()
+									.environment_scope(variable.environment_scope.as_str())
+									.build()?,
+							)
+							.build()?;
+						api::ignore(endpoint)
+							.query(&context.gitlab)
+							.with_context(|| {
+								format!(
+									"Failed to update variable {} on project {}",
+									&variable.key, path
+								)
+							})?;
+					}
+				}
+				Some(_) => (),
+			}
+		}
+		if !missing.is_empty() {
+			bail!(
+				"Variables {} must be created on project {}",
+				missing.join(", "),
+				path
+			)
+		}
+	}
+
+	Ok(changes)
+}
+
+/// Reconcile one project or all the projects of a group with a settings document
+pub fn apply(context: &CliContext, args: &args::ProjectSettingsApply) -> Result<()> {
+	let content =
+		read_to_string(&args.file).with_context(|| format!("Can't read {}", &args.file))?;
+	// yaml being a superset of json, both formats are accepted
+	let document: SettingsDocument = serde_yaml::from_str(&content)
+		.with_context(|| format!("{} is not a valid settings document", &args.file))?;
+
+	let projects: Vec<types::Project> = if let Some(group) = &args.group {
+		if args.project.is_some() {
+			bail!("Specify either --project or --group on the command line")
+		}
+		let endpoint = groups::projects::GroupProjects::builder()
+			.group(group.as_str())
+			.include_subgroups(true)
+			.archived(false)
+			.build()?;
+		api::paged(endpoint, Pagination::All)
+			.query(&context.gitlab)
+			.with_context(|| format!("Failed to list projects of group {}", group))?
+	} else {
+		vec![context.get_project(args.project.as_ref())?]
+	};
+
+	let mut failures = 0;
+	for project in projects.iter() {
+		match reconcile(context, project, &document, args.dry_run) {
+			Ok(0) => println!("project {} is up to date", &project.path_with_namespace),
+			Ok(_) => (),
+			Err(err) => {
+				failures += 1;
+				eprintln!("project {}: {:#}", &project.path_with_namespace, err);
+			}
+		}
+	}
+	if failures != 0 {
+		bail!(
+			"Failed to apply settings on {} of {} projects",
+			failures,
+			projects.len()
+		)
+	}
+	Ok(())
+}
@@ -0,0 +1,83 @@
+use crate::{
+	api::projects::EditProjectSettings,
+	args::{self, ProjectTopicsCmd},
+	context::CliContext,
+};
+
+use anyhow::{bail, Context, Result};
+use gitlab::{
+	api::{self, Query},
+	types,
+};
+
+/// Replace the topics of a project
+fn set_topics(context: &CliContext, project: &types::Project, topics: &[String]) -> Result<()> {
+	let endpoint = EditProjectSettings::builder()
+		.project(project.id.value())
+		.settings(vec![("topics".into(), topics.join(",").into())])
+		.build()?;
+	api::ignore(endpoint)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to change topics of project {}",
+				&project.path_with_namespace
+			)
+		})
+}
+
+/// Print the topics of a project
+fn print_topics(project: &types::Project, topics: &[String]) {
+	if topics.is_empty() {
+		println!("project {} has no topics", &project.path_with_namespace);
+	} else {
+		println!(
+			"project {} topics: {}",
+			&project.path_with_namespace,
+			topics.join(", ")
+		);
+	}
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::ProjectTopics) -> Result<()> {
+	match &args.cmd {
+		ProjectTopicsCmd::List(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			print_topics(&project, &project.tag_list);
+			Ok(())
+		}
+
+		ProjectTopicsCmd::Add(cmd_args) => {
+			if cmd_args.topics.is_empty() {
+				bail!("Specify at least one topic on the command line")
+			}
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut topics = project.tag_list.clone();
+			for topic in cmd_args.topics.iter() {
+				if !topics.contains(topic) {
+					topics.push(topic.to_owned());
+				}
+			}
+			set_topics(context, &project, &topics)?;
+			print_topics(&project, &topics);
+			Ok(())
+		}
+
+		ProjectTopicsCmd::Remove(cmd_args) => {
+			if cmd_args.topics.is_empty() {
+				bail!("Specify at least one topic on the command line")
+			}
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let topics: Vec<String> = project
+				.tag_list
+				.iter()
+				.filter(|topic| !cmd_args.topics.contains(topic))
+				.cloned()
+				.collect();
+			set_topics(context, &project, &topics)?;
+			print_topics(&project, &topics);
+			Ok(())
+		}
+	}
+}
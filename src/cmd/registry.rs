@@ -0,0 +1,390 @@
+use crate::{
+	api::{
+		projects::EditProjectSettings,
+		registry::{DeleteRegistryTags, RegistryRepositories, RegistryTag, RegistryTags},
+	},
+	args::{self, Format, RegistryCmd, RegistryPolicyCmd},
+	color::StyledStr,
+	context::CliContext,
+	utils::parse_age,
+};
+
+use anyhow::{bail, Context, Result};
+use bytesize::ByteSize;
+use chrono::{DateTime, Utc};
+use gitlab::{
+	api::{self, projects, Pagination, Query},
+	types,
+};
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::{borrow::Cow, cmp::Reverse};
+
+#[derive(Deserialize)]
+struct Repository {
+	id: u64,
+	name: String,
+	path: String,
+	location: String,
+	#[serde(default)]
+	tags_count: u64,
+}
+
+#[derive(Deserialize)]
+struct TagName {
+	name: String,
+}
+
+#[derive(Deserialize)]
+struct Tag {
+	name: String,
+	digest: Option<String>,
+	total_size: Option<u64>,
+	created_at: Option<DateTime<Utc>>,
+}
+
+/// Get all the container registry repositories of a project
+fn get_repositories(context: &CliContext, project: &types::Project) -> Result<Vec<Repository>> {
+	let endpoint = RegistryRepositories::builder()
+		.project(project.id.value())
+		.build()?;
+	api::paged(endpoint, Pagination::All)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to list registry repositories of project {}",
+				&project.path_with_namespace
+			)
+		})
+}
+
+/// Find a container registry repository of a project by its name, path or id
+fn get_repository(
+	context: &CliContext,
+	project: &types::Project,
+	repository: Option<&String>,
+) -> Result<Repository> {
+	// the root repository of a project has an empty name
+	let wanted = repository.map(String::as_str).unwrap_or_default();
+	get_repositories(context, project)?
+		.into_iter()
+		.find(|repository| {
+			repository.name == wanted
+				|| repository.path == wanted
+				|| repository.id.to_string() == wanted
+		})
+		.with_context(|| {
+			format!(
+				"registry repository {} not found in project {}",
+				if wanted.is_empty() { "(root)" } else { wanted },
+				&project.path_with_namespace
+			)
+		})
+}
+
+/// Get all the tags of a container registry repository with their details
+fn get_tags(
+	context: &CliContext,
+	project: &types::Project,
+	repository: &Repository,
+) -> Result<Vec<Tag>> {
+	let endpoint = RegistryTags::builder()
+		.project(project.id.value())
+		.repository(repository.id)
+		.build()?;
+	let names: Vec<TagName> = api::paged(endpoint, Pagination::All)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to list tags of registry repository {}",
+				&repository.path
+			)
+		})?;
+	// digest, size and date are only returned with the details of a tag
+	names
+		.iter()
+		.map(|tag| {
+			RegistryTag::builder()
+				.project(project.id.value())
+				.repository(repository.id)
+				.tag(tag.name.as_str())
+				.build()?
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to get tag {} of {}", &tag.name, &repository.path))
+		})
+		.collect()
+}
+
+/// Compile a regex matching whole tag names like gitlab does
+fn tag_regex(option: &str, regex: &str) -> Result<Regex> {
+	Regex::new(&format!("^(?:{})$", regex))
+		.with_context(|| format!("{} is not a valid regex for --{}", regex, option))
+}
+
+/// Check that a cleanup policy value is one of those accepted by gitlab
+fn check_policy_value(option: &str, value: &str, allowed: &[&str]) -> Result<()> {
+	if !allowed.contains(&value) {
+		bail!(
+			"{} not supported for --{}. Use either \"{}\"",
+			value,
+			option,
+			allowed.join("\", \"")
+		)
+	}
+	Ok(())
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Registry) -> Result<()> {
+	match &args.cmd {
+		RegistryCmd::List(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let repositories = get_repositories(context, &project)?;
+
+			let mut msg = StyledStr::new();
+			if repositories.is_empty() {
+				msg.none("No registry repositories for ");
+				msg.literal(&project.path_with_namespace);
+				msg.none("\n");
+			}
+			for repository in repositories.iter() {
+				msg.none(format!("- #{} ", repository.id));
+				msg.literal(&repository.path);
+				msg.none(format!(" ({} tags)", repository.tags_count));
+				if context.url {
+					msg.hint(format!(" {}", &repository.location));
+				}
+				msg.none("\n");
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/container_registry", &project.web_url));
+			}
+			Ok(())
+		}
+
+		RegistryCmd::Tags(cmd_args) => {
+			let descending = match cmd_args.sort.as_str() {
+				"desc" => true,
+				"asc" => false,
+				sort => bail!(
+					"{} not supported for --sort. Use either \"desc\" or \"asc\"",
+					sort
+				),
+			};
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let repository = get_repository(context, &project, cmd_args.repository.as_ref())?;
+			let mut tags = get_tags(context, &project, &repository)?;
+			tags.sort_by_key(|tag| tag.created_at);
+			if descending {
+				tags.reverse();
+			}
+
+			let mut msg = StyledStr::new();
+			if tags.is_empty() {
+				msg.none("No tags for ");
+				msg.literal(&repository.path);
+				msg.none("\n");
+			}
+			for tag in tags.iter().take(cmd_args.limit) {
+				msg.none("- ");
+				msg.literal(&tag.name);
+				if let Some(digest) = &tag.digest {
+					msg.hint(format!(" {}", &digest[..digest.len().min(19)]));
+				}
+				if let Some(size) = tag.total_size {
+					msg.none(format!(" {}", ByteSize(size)));
+				}
+				if let Some(created_at) = tag.created_at {
+					msg.none(format!(
+						" [{}]",
+						timeago::Formatter::new().convert_chrono(created_at, Utc::now())
+					));
+				}
+				msg.none("\n");
+			}
+			if tags.len() > cmd_args.limit {
+				msg.hint(format!(
+					"{} more tags, use --limit to show them\n",
+					tags.len() - cmd_args.limit
+				));
+			}
+			context.print_msg(msg)
+		}
+
+		RegistryCmd::Cleanup(cmd_args) => {
+			let delete = tag_regex("name-regex", &cmd_args.name_regex)?;
+			let keep = cmd_args
+				.keep_regex
+				.as_ref()
+				.map(|regex| tag_regex("keep-regex", regex))
+				.transpose()?;
+			let older_than = cmd_args
+				.older_than
+				.as_ref()
+				.map(|age| parse_age(age))
+				.transpose()?;
+			if cmd_args.keep_n.is_none() && older_than.is_none() && !cmd_args.dry_run {
+				bail!("Specify at least --keep-n or --older-than, or use --dry-run")
+			}
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let repository = get_repository(context, &project, cmd_args.repository.as_ref())?;
+
+			// preview the tags gitlab will delete: latest is always kept, as well as the
+			// keep_n most recent matching tags
+			let mut tags = get_tags(context, &project, &repository)?;
+			tags.retain(|tag| {
+				tag.name != "latest"
+					&& delete.is_match(&tag.name)
+					&& !keep
+						.as_ref()
+						.map(|keep| keep.is_match(&tag.name))
+						.unwrap_or_default()
+			});
+			tags.sort_by_key(|tag| Reverse(tag.created_at));
+			let skip = cmd_args.keep_n.unwrap_or_default() as usize;
+			let deleted: Vec<&Tag> = tags
+				.iter()
+				.skip(skip)
+				.filter(|tag| match (older_than, tag.created_at) {
+					(Some(age), Some(created_at)) => created_at < Utc::now() - age,
+					(Some(_), None) => false,
+					(None, _) => true,
+				})
+				.collect();
+
+			for tag in deleted.iter() {
+				if cmd_args.dry_run {
+					println!("tag {}:{} would be deleted", &repository.path, &tag.name);
+				} else {
+					println!("tag {}:{} will be deleted", &repository.path, &tag.name);
+				}
+			}
+			if deleted.is_empty() {
+				println!("No tags to delete in {}", &repository.path);
+				return Ok(());
+			}
+			if cmd_args.dry_run {
+				return Ok(());
+			}
+
+			let mut endpoint = DeleteRegistryTags::builder();
+			endpoint
+				.project(project.id.value())
+				.repository(repository.id)
+				.name_regex_delete(cmd_args.name_regex.as_str());
+			if let Some(keep) = &cmd_args.keep_regex {
+				endpoint.name_regex_keep(keep.as_str());
+			}
+			if let Some(keep_n) = cmd_args.keep_n {
+				endpoint.keep_n(keep_n);
+			}
+			if let Some(older_than) = &cmd_args.older_than {
+				endpoint.older_than(older_than.as_str());
+			}
+			api::ignore(endpoint.build()?)
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to delete tags of {}", &repository.path))?;
+			println!(
+				"deletion of {} tags of {} has been scheduled",
+				deleted.len(),
+				&repository.path
+			);
+			Ok(())
+		}
+
+		RegistryCmd::Policy(args) => match &args.cmd {
+			RegistryPolicyCmd::Get(cmd_args) => {
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				let mut settings: Map<String, Value> = projects::Project::builder()
+					.project(project.id.value())
+					.build()?
+					.query(&context.gitlab)
+					.with_context(|| {
+						format!(
+							"Failed to get settings of project {}",
+							&project.path_with_namespace
+						)
+					})?;
+				let policy = settings
+					.remove("container_expiration_policy")
+					.unwrap_or(Value::Null);
+				match cmd_args.format {
+					Format::Yaml => print!("{}", serde_yaml::to_string(&policy)?),
+					Format::Json => println!("{}", serde_json::to_string_pretty(&policy)?),
+					Format::Csv => bail!("csv format is not supported for cleanup policies"),
+				}
+				Ok(())
+			}
+
+			RegistryPolicyCmd::Set(cmd_args) => {
+				let mut settings: Vec<(Cow<str>, Cow<str>)> = Vec::new();
+				if let Some(enabled) = cmd_args.enabled {
+					settings.push(("enabled".into(), enabled.to_string().into()));
+				}
+				if let Some(cadence) = &cmd_args.cadence {
+					check_policy_value(
+						"cadence",
+						cadence,
+						&["1d", "7d", "14d", "1month", "3month"],
+					)?;
+					settings.push(("cadence".into(), cadence.into()));
+				}
+				if let Some(keep_n) = cmd_args.keep_n {
+					check_policy_value(
+						"keep-n",
+						&keep_n.to_string(),
+						&["1", "5", "10", "25", "50", "100"],
+					)?;
+					settings.push(("keep_n".into(), keep_n.to_string().into()));
+				}
+				if let Some(older_than) = &cmd_args.older_than {
+					check_policy_value("older-than", older_than, &["7d", "14d", "30d", "90d"])?;
+					settings.push(("older_than".into(), older_than.into()));
+				}
+				if let Some(regex) = &cmd_args.name_regex {
+					settings.push(("name_regex".into(), regex.into()));
+				}
+				if let Some(regex) = &cmd_args.keep_regex {
+					settings.push(("name_regex_keep".into(), regex.into()));
+				}
+				if settings.is_empty() {
+					bail!("Specify at least one policy setting on the command line")
+				}
+
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				let endpoint = EditProjectSettings::builder()
+					.project(project.id.value())
+					.settings(
+						settings
+							.into_iter()
+							.map(|(key, value)| {
+								(
+									format!("container_expiration_policy_attributes[{}]", key)
+										.into(),
+									value,
+								)
+							})
+							.collect::<Vec<_>>(),
+					)
+					.build()?;
+				let mut updated: Map<String, Value> =
+					endpoint.query(&context.gitlab).with_context(|| {
+						format!(
+							"Failed to change the cleanup policy of project {}",
+							&project.path_with_namespace
+						)
+					})?;
+
+				// show the policy as returned by gitlab
+				let policy = updated
+					.remove("container_expiration_policy")
+					.unwrap_or(Value::Null);
+				print!("{}", serde_yaml::to_string(&policy)?);
+				Ok(())
+			}
+		},
+	}
+}
@@ -0,0 +1,318 @@
+use crate::{
+	api::{
+		access_requests::Source,
+		runners::{
+			CreateRunner, DeleteRunner, EditRunner, Runner, RunnerJobs, RunnerType, Runners,
+		},
+	},
+	args::{self, RunnerCmd},
+	color::StyledStr,
+	context::CliContext,
+	utils::format_duration,
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use gitlab::{
+	api::{self, groups, Pagination, Query},
+	types,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct CreatedRunner {
+	id: u64,
+	token: String,
+	token_expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize)]
+struct RunnerJob {
+	id: u64,
+	name: String,
+	status: String,
+	duration: Option<f64>,
+	created_at: DateTime<Utc>,
+	web_url: String,
+	pipeline: JobPipeline,
+	project: JobProject,
+}
+
+#[derive(Deserialize)]
+struct JobPipeline {
+	id: u64,
+}
+
+#[derive(Deserialize)]
+struct JobProject {
+	path_with_namespace: String,
+}
+
+#[derive(Deserialize)]
+struct RunnerSummary {
+	id: u64,
+}
+
+#[derive(Deserialize)]
+struct RunnerDetails {
+	id: u64,
+	description: Option<String>,
+	#[serde(default)]
+	paused: bool,
+	status: String,
+	#[serde(default)]
+	tag_list: Vec<String>,
+	contacted_at: Option<DateTime<Utc>>,
+}
+
+/// Get the details of a runner
+fn get_runner(context: &CliContext, id: u64) -> Result<RunnerDetails> {
+	Runner::builder()
+		.runner(id)
+		.build()?
+		.query(&context.gitlab)
+		.with_context(|| format!("Failed to get runner #{}", id))
+}
+
+/// Pause or resume a runner
+fn set_paused(context: &CliContext, id: u64, paused: bool) -> Result<()> {
+	let endpoint = EditRunner::builder().runner(id).paused(paused).build()?;
+	api::ignore(endpoint)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to {} runner #{}",
+				if paused { "pause" } else { "resume" },
+				id
+			)
+		})?;
+	println!(
+		"runner #{} has been {}",
+		id,
+		if paused { "paused" } else { "resumed" }
+	);
+	Ok(())
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Runner) -> Result<()> {
+	match &args.cmd {
+		RunnerCmd::List(cmd_args) => {
+			let mut endpoint = Runners::builder();
+			let owner = if cmd_args.all {
+				endpoint.all(true);
+				"the instance".to_owned()
+			} else if let Some(group) = &cmd_args.group {
+				let group: types::Group = groups::Group::builder()
+					.group(group.as_str())
+					.build()?
+					.query(&context.gitlab)
+					.with_context(|| format!("Can't find a group named {}", group))?;
+				endpoint.source(Source::Group(group.id.value().into()));
+				format!("group {}", group.full_path)
+			} else {
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				endpoint.source(Source::Project(project.id.value().into()));
+				format!("project {}", project.path_with_namespace)
+			};
+			if let Some(status) = &cmd_args.status {
+				if !["online", "offline", "stale", "never_contacted"].contains(&status.as_str()) {
+					bail!(
+						"{} not supported for --status. Use either \"online\", \"offline\", \"stale\" or \"never_contacted\"",
+						status
+					)
+				}
+				endpoint.status(status.as_str());
+			}
+			let summaries: Vec<RunnerSummary> = api::paged(endpoint.build()?, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to list runners of {}", &owner))?;
+			// tags and last contact are only returned with the details of a runner
+			let runners = summaries
+				.iter()
+				.map(|runner| get_runner(context, runner.id))
+				.collect::<Result<Vec<_>>>()?;
+
+			let mut msg = StyledStr::new();
+			if runners.is_empty() {
+				msg.none(format!("No runners for {}\n", &owner));
+				return context.print_msg(msg);
+			}
+			let description = |runner: &RunnerDetails| {
+				runner
+					.description
+					.as_ref()
+					.filter(|d| !d.is_empty())
+					.cloned()
+					.unwrap_or_else(|| "-".to_owned())
+			};
+			let description_width = runners
+				.iter()
+				.map(|runner| description(runner).chars().count())
+				.max()
+				.unwrap_or_default()
+				.max("DESCRIPTION".len());
+			let tags_width = runners
+				.iter()
+				.map(|runner| runner.tag_list.join(",").len())
+				.max()
+				.unwrap_or_default()
+				.max("TAGS".len());
+			msg.hint(format!(
+				"{:<8}  {:<15}  {:<description_width$}  {:<tags_width$}  LAST CONTACT\n",
+				"ID", "STATUS", "DESCRIPTION", "TAGS"
+			));
+			for runner in runners.iter() {
+				msg.literal(format!("{:<8}", runner.id));
+				msg.none("  ");
+				let status = format!("{:<15}", runner.status);
+				if runner.paused {
+					msg.warning(format!("{:<15}", "paused"));
+				} else if runner.status == "online" {
+					msg.good(status);
+				} else {
+					msg.error(status);
+				}
+				msg.none(format!(
+					"  {:<description_width$}  {:<tags_width$}  {}\n",
+					description(runner),
+					runner.tag_list.join(","),
+					runner
+						.contacted_at
+						.map(|date| timeago::Formatter::new().convert_chrono(date, Utc::now()))
+						.unwrap_or_else(|| "never".to_owned())
+				));
+			}
+			context.print_msg(msg)
+		}
+
+		RunnerCmd::Pause(cmd_args) => set_paused(context, cmd_args.id, true),
+
+		RunnerCmd::Resume(cmd_args) => set_paused(context, cmd_args.id, false),
+
+		RunnerCmd::Delete(cmd_args) => {
+			let endpoint = DeleteRunner::builder().runner(cmd_args.id).build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to delete runner #{}", cmd_args.id))?;
+			println!("runner #{} has been deleted", cmd_args.id);
+			Ok(())
+		}
+
+		RunnerCmd::Create(cmd_args) => {
+			let (runner_type, owner) = if cmd_args.instance {
+				(RunnerType::Instance, "the instance".to_owned())
+			} else if let Some(group) = &cmd_args.group {
+				let group: types::Group = groups::Group::builder()
+					.group(group.as_str())
+					.build()?
+					.query(&context.gitlab)
+					.with_context(|| format!("Can't find a group named {}", group))?;
+				(
+					RunnerType::Group(group.id.value()),
+					format!("group {}", group.full_path),
+				)
+			} else {
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				(
+					RunnerType::Project(project.id.value()),
+					format!("project {}", project.path_with_namespace),
+				)
+			};
+
+			let mut endpoint = CreateRunner::builder();
+			endpoint
+				.runner_type(runner_type)
+				.run_untagged(cmd_args.run_untagged)
+				.locked(cmd_args.locked)
+				.paused(cmd_args.paused);
+			if let Some(description) = &cmd_args.description {
+				endpoint.description(description.as_str());
+			}
+			for tag in cmd_args
+				.tags
+				.iter()
+				.flat_map(|tags| tags.split(','))
+				.map(str::trim)
+				.filter(|tag| !tag.is_empty())
+			{
+				endpoint.tag(tag);
+			}
+			let runner: CreatedRunner = endpoint
+				.build()?
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to create a runner for {}", &owner))?;
+
+			// the token can't be retrieved afterwards
+			let mut msg = StyledStr::new();
+			msg.none(format!(
+				"runner #{} has been created for {}\n",
+				runner.id, &owner
+			));
+			msg.none("token: ");
+			msg.literal(&runner.token);
+			msg.none("\n");
+			if let Some(expires_at) = runner.token_expires_at {
+				msg.warning(format!(
+					"the token expires on {}\n",
+					expires_at.format("%Y-%m-%d")
+				));
+			}
+			msg.hint(format!(
+				"register it with: gitlab-runner register --token {}\n",
+				&runner.token
+			));
+			context.print_msg(msg)
+		}
+
+		RunnerCmd::Jobs(cmd_args) => {
+			let mut endpoint = RunnerJobs::builder();
+			endpoint.runner(cmd_args.id);
+			if let Some(status) = &cmd_args.status {
+				if !["running", "success", "failed", "canceled"].contains(&status.as_str()) {
+					bail!(
+						"{} not supported for --status. Use either \"running\", \"success\", \"failed\" or \"canceled\"",
+						status
+					)
+				}
+				endpoint.status(status.as_str());
+			}
+			let jobs: Vec<RunnerJob> =
+				api::paged(endpoint.build()?, Pagination::Limit(cmd_args.limit))
+					.query(&context.gitlab)
+					.with_context(|| format!("Failed to list jobs of runner #{}", cmd_args.id))?;
+
+			let mut msg = StyledStr::new();
+			if jobs.is_empty() {
+				msg.none(format!("No jobs for runner #{}\n", cmd_args.id));
+			}
+			for job in jobs.iter() {
+				msg.none("- Job ");
+				msg.literal(job.id.to_string());
+				msg.none(format!(" {} of ", job.name));
+				msg.literal(&job.project.path_with_namespace);
+				msg.none(format!(" (pipeline #{})", job.pipeline.id));
+				if let Some(duration) = job.duration {
+					msg.none(" [");
+					msg.literal(format_duration(duration as i64));
+					msg.none("]");
+				}
+				msg.none(" - ");
+				match job.status.as_str() {
+					"success" | "running" => msg.good(&job.status),
+					"failed" | "canceled" => msg.error(&job.status),
+					_ => msg.warning(&job.status),
+				}
+				msg.none(format!(
+					" {}",
+					timeago::Formatter::new().convert_chrono(job.created_at, Utc::now())
+				));
+				if context.url {
+					msg.hint(format!(" ({})", job.web_url));
+				}
+				msg.none("\n");
+			}
+			context.print_msg(msg)
+		}
+	}
+}
@@ -0,0 +1,116 @@
+use crate::{
+	api::{access_requests::Source, search::Search},
+	args::{self, SearchCmd},
+	color::StyledStr,
+	context::CliContext,
+};
+
+use anyhow::{Context, Result};
+use gitlab::{
+	api::{self, groups, projects, Pagination, Query},
+	types,
+};
+use serde::Deserialize;
+use std::collections::{hash_map::Entry, HashMap};
+
+#[derive(Deserialize)]
+struct Blob {
+	path: String,
+	data: String,
+	startline: usize,
+	project_id: u64,
+}
+
+/// Append a line to the message, highlighting the occurrences of the query
+fn highlight_msg(msg: &mut StyledStr, line: &str, query: &str) {
+	// case insensitive like gitlab search
+	let lower = line.to_lowercase();
+	let needle = query.to_lowercase();
+	let mut start = 0;
+	// lowercasing may change byte offsets for some characters
+	if !needle.is_empty() && lower.len() == line.len() {
+		while let Some(i) = lower[start..].find(&needle) {
+			msg.none(&line[start..start + i]);
+			msg.warning(&line[start + i..start + i + needle.len()]);
+			start += i + needle.len();
+		}
+	}
+	msg.none(&line[start..]);
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Search) -> Result<()> {
+	match &args.cmd {
+		SearchCmd::Code(cmd_args) => {
+			let (source, scope) = if cmd_args.global {
+				(None, "the instance".to_owned())
+			} else if let Some(group) = &cmd_args.group {
+				let group: types::Group = groups::Group::builder()
+					.group(group.as_str())
+					.build()?
+					.query(&context.gitlab)
+					.with_context(|| format!("Can't find a group named {}", group))?;
+				(
+					Some(Source::Group(group.id.value().into())),
+					format!("group {}", group.full_path),
+				)
+			} else {
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				(
+					Some(Source::Project(project.id.value().into())),
+					format!("project {}", project.path_with_namespace),
+				)
+			};
+
+			// filters are part of the query syntax
+			let query = match &cmd_args.filename {
+				Some(filename) => format!("{} filename:{}", &cmd_args.query, filename),
+				None => cmd_args.query.to_owned(),
+			};
+			let mut endpoint = Search::builder();
+			endpoint.scope("blobs").search(query.as_str());
+			if let Some(source) = source {
+				endpoint.source(source);
+			}
+			if let Some(ref_) = &cmd_args.ref_ {
+				endpoint.ref_(ref_.as_str());
+			}
+			let blobs: Vec<Blob> = api::paged(endpoint.build()?, Pagination::Limit(cmd_args.limit))
+				.query(&context.gitlab)
+				.with_context(|| format!("Failed to search {}", &scope))?;
+
+			// results only give the project id
+			let mut paths: HashMap<u64, String> = HashMap::new();
+			let mut msg = StyledStr::new();
+			if blobs.is_empty() {
+				msg.none("No matches in ");
+				msg.literal(&scope);
+				msg.none("\n");
+			}
+			for blob in blobs.iter() {
+				let path = match paths.entry(blob.project_id) {
+					Entry::Occupied(entry) => entry.into_mut(),
+					Entry::Vacant(entry) => {
+						let project: types::Project = projects::Project::builder()
+							.project(blob.project_id)
+							.build()?
+							.query(&context.gitlab)
+							.with_context(|| {
+								format!("Failed to get project {}", blob.project_id)
+							})?;
+						entry.insert(project.path_with_namespace)
+					}
+				};
+				msg.hint(format!("{}:", path));
+				msg.literal(&blob.path);
+				msg.none("\n");
+				for (i, line) in blob.data.trim_end().lines().enumerate() {
+					msg.hint(format!("{:>6} | ", blob.startline + i));
+					highlight_msg(&mut msg, line, &cmd_args.query);
+					msg.none("\n");
+				}
+			}
+			context.print_msg(msg)
+		}
+	}
+}
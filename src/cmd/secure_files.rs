@@ -0,0 +1,183 @@
+use crate::{
+	api::secure_files::{DeleteSecureFile, DownloadSecureFile, SecureFiles, UploadSecureFile},
+	args::{self, SecureFilesCmd},
+	color::StyledStr,
+	context::CliContext,
+};
+
+use anyhow::{Context, Result};
+use bytesize::ByteSize;
+use chrono::{DateTime, Utc};
+use gitlab::{
+	api::{self, Pagination, Query},
+	types,
+};
+use serde::Deserialize;
+use std::{
+	fs::{read, write},
+	path::Path,
+};
+
+#[derive(Deserialize)]
+struct SecureFile {
+	id: u64,
+	name: String,
+	checksum: Option<String>,
+	created_at: DateTime<Utc>,
+	expires_at: Option<DateTime<Utc>>,
+}
+
+/// Get all the secure files of a project
+fn get_secure_files(context: &CliContext, project: &types::Project) -> Result<Vec<SecureFile>> {
+	let endpoint = SecureFiles::builder().project(project.id.value()).build()?;
+	api::paged(endpoint, Pagination::All)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to list secure files of project {}",
+				&project.path_with_namespace
+			)
+		})
+}
+
+/// Find a secure file of a project by its name
+fn get_secure_file(
+	context: &CliContext,
+	project: &types::Project,
+	name: &str,
+) -> Result<SecureFile> {
+	get_secure_files(context, project)?
+		.into_iter()
+		.find(|file| file.name == name)
+		.with_context(|| {
+			format!(
+				"secure file {} not found in project {}",
+				name, &project.path_with_namespace
+			)
+		})
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::SecureFiles) -> Result<()> {
+	match &args.cmd {
+		SecureFilesCmd::List(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let files = get_secure_files(context, &project)?;
+
+			let mut msg = StyledStr::new();
+			if files.is_empty() {
+				msg.none("No secure files for ");
+				msg.literal(&project.path_with_namespace);
+				msg.none("\n");
+			}
+			for file in files.iter() {
+				msg.none("- ");
+				msg.literal(&file.name);
+				msg.none(format!(
+					" uploaded {}",
+					timeago::Formatter::new().convert_chrono(file.created_at, Utc::now())
+				));
+				if let Some(expires_at) = file.expires_at {
+					if expires_at < Utc::now() {
+						msg.error(format!(" expired {}", expires_at.format("%Y-%m-%d")));
+					} else {
+						msg.warning(format!(" expires {}", expires_at.format("%Y-%m-%d")));
+					}
+				}
+				if let Some(checksum) = &file.checksum {
+					msg.hint(format!(" ({})", &checksum[..checksum.len().min(12)]));
+				}
+				msg.none("\n");
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/-/ci/secure_files", &project.web_url));
+			}
+			Ok(())
+		}
+
+		SecureFilesCmd::Upload(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let file =
+				read(&cmd_args.file).with_context(|| format!("Can't open {}", &cmd_args.file))?;
+			let name = if let Some(name) = &cmd_args.name {
+				name.to_owned()
+			} else {
+				Path::new(&cmd_args.file)
+					.file_name()
+					.map(|name| name.to_string_lossy().to_string())
+					.unwrap_or_else(|| cmd_args.file.to_owned())
+			};
+
+			let endpoint = UploadSecureFile::builder()
+				.project(project.id.value())
+				.name(name.as_str())
+				.file(file.as_slice())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to upload secure file {} to project {}",
+						&name, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"secure file {} has been uploaded to project {} ({})",
+				&name,
+				&project.path_with_namespace,
+				ByteSize(file.len() as u64)
+			);
+			Ok(())
+		}
+
+		SecureFilesCmd::Download(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let file = get_secure_file(context, &project, &cmd_args.name)?;
+			let output = cmd_args.output.as_ref().unwrap_or(&file.name);
+
+			let endpoint = DownloadSecureFile::builder()
+				.project(project.id.value())
+				.secure_file(file.id)
+				.build()?;
+			let content = api::raw(endpoint).query(&context.gitlab).with_context(|| {
+				format!(
+					"Failed to download secure file {} of project {}",
+					&file.name, &project.path_with_namespace
+				)
+			})?;
+			write(output, &content).with_context(|| format!("Can't write {}", output))?;
+			println!(
+				"secure file {} of project {} has been saved to {} ({})",
+				&file.name,
+				&project.path_with_namespace,
+				output,
+				ByteSize(content.len() as u64)
+			);
+			Ok(())
+		}
+
+		SecureFilesCmd::Delete(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let file = get_secure_file(context, &project, &cmd_args.name)?;
+			let endpoint = DeleteSecureFile::builder()
+				.project(project.id.value())
+				.secure_file(file.id)
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to delete secure file {} of project {}",
+						&file.name, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"secure file {} has been deleted from project {}",
+				&file.name, &project.path_with_namespace
+			);
+			Ok(())
+		}
+	}
+}
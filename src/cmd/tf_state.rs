@@ -0,0 +1,197 @@
+use crate::{
+	api::terraform::{
+		DeleteTerraformState, TerraformState, TerraformStateLockMutation,
+		TerraformStateLockVariables, TerraformStateNode, TerraformStateUnlockMutation,
+		TerraformStatesQuery, TerraformStatesVariables,
+	},
+	args::{self, TfStateCmd},
+	color::StyledStr,
+	context::CliContext,
+};
+
+use anyhow::{bail, Context, Result};
+use bytesize::ByteSize;
+use gitlab::{
+	api::{self, Query},
+	types,
+};
+use graphql_client::GraphQLQuery;
+use std::fs::write;
+
+/// Get the terraform states of a project
+fn get_states(context: &CliContext, project: &types::Project) -> Result<Vec<TerraformStateNode>> {
+	let query = TerraformStatesQuery::build_query(TerraformStatesVariables {
+		full_path: project.path_with_namespace.to_owned(),
+	});
+	let states = context
+		.gitlab
+		.graphql::<TerraformStatesQuery>(&query)
+		.with_context(|| {
+			format!(
+				"Failed to list terraform states of project {}",
+				&project.path_with_namespace
+			)
+		})?
+		.project
+		.and_then(|project| project.terraform_states)
+		.map(|states| states.nodes)
+		.unwrap_or_default();
+	Ok(states)
+}
+
+/// Find a terraform state of a project by its name
+fn get_state(
+	context: &CliContext,
+	project: &types::Project,
+	name: &str,
+) -> Result<TerraformStateNode> {
+	get_states(context, project)?
+		.into_iter()
+		.find(|state| state.name == name)
+		.with_context(|| {
+			format!(
+				"terraform state {} not found in project {}",
+				name, &project.path_with_namespace
+			)
+		})
+}
+
+/// Lock or unlock a terraform state
+fn set_locked(
+	context: &CliContext,
+	project: Option<&String>,
+	name: &str,
+	locked: bool,
+) -> Result<()> {
+	let project = context.get_project(project)?;
+	let state = get_state(context, &project, name)?;
+	let variables = TerraformStateLockVariables { id: state.id };
+	let data = if locked {
+		context.gitlab.graphql::<TerraformStateLockMutation>(
+			&TerraformStateLockMutation::build_query(variables),
+		)
+	} else {
+		context.gitlab.graphql::<TerraformStateUnlockMutation>(
+			&TerraformStateUnlockMutation::build_query(variables),
+		)
+	};
+	let action = if locked { "lock" } else { "unlock" };
+	let errors = data
+		.with_context(|| {
+			format!(
+				"Failed to {} terraform state {} of project {}",
+				action, name, &project.path_with_namespace
+			)
+		})?
+		.result
+		.map(|result| result.errors)
+		.unwrap_or_default();
+	if !errors.is_empty() {
+		bail!(
+			"Failed to {} terraform state {} of project {}: {}",
+			action,
+			name,
+			&project.path_with_namespace,
+			errors.join(", ")
+		)
+	}
+	println!(
+		"terraform state {} of project {} has been {}ed",
+		name, &project.path_with_namespace, action
+	);
+	Ok(())
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::TfState) -> Result<()> {
+	match &args.cmd {
+		TfStateCmd::List(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let states = get_states(context, &project)?;
+
+			let mut msg = StyledStr::new();
+			if states.is_empty() {
+				msg.none("No terraform states for ");
+				msg.literal(&project.path_with_namespace);
+				msg.none("\n");
+			}
+			for state in states.iter() {
+				msg.none("- ");
+				msg.literal(&state.name);
+				if let Some(serial) = state.latest_version.as_ref().and_then(|v| v.serial) {
+					msg.none(format!(" serial {}", serial));
+				}
+				msg.none(format!(" updated {}", &state.updated_at));
+				if let Some(locked_at) = &state.locked_at {
+					msg.warning(format!(" locked {}", locked_at));
+					if let Some(user) = &state.locked_by_user {
+						msg.warning(format!(" by {}", &user.username));
+					}
+				}
+				msg.none("\n");
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/-/terraform", &project.web_url));
+			}
+			Ok(())
+		}
+
+		TfStateCmd::Download(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = TerraformState::builder()
+				.project(project.id.value())
+				.name(cmd_args.name.as_str())
+				.build()?;
+			let content = api::raw(endpoint).query(&context.gitlab).with_context(|| {
+				format!(
+					"Failed to download terraform state {} of project {}",
+					&cmd_args.name, &project.path_with_namespace
+				)
+			})?;
+			let output = cmd_args
+				.output
+				.to_owned()
+				.unwrap_or_else(|| format!("{}.tfstate", &cmd_args.name));
+			write(&output, &content).with_context(|| format!("Can't write {}", &output))?;
+			println!(
+				"terraform state {} of project {} has been saved to {} ({})",
+				&cmd_args.name,
+				&project.path_with_namespace,
+				&output,
+				ByteSize(content.len() as u64)
+			);
+			Ok(())
+		}
+
+		TfStateCmd::Lock(cmd_args) => {
+			set_locked(context, cmd_args.project.as_ref(), &cmd_args.name, true)
+		}
+
+		TfStateCmd::Unlock(cmd_args) => {
+			set_locked(context, cmd_args.project.as_ref(), &cmd_args.name, false)
+		}
+
+		TfStateCmd::Delete(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let endpoint = DeleteTerraformState::builder()
+				.project(project.id.value())
+				.name(cmd_args.name.as_str())
+				.build()?;
+			api::ignore(endpoint)
+				.query(&context.gitlab)
+				.with_context(|| {
+					format!(
+						"Failed to delete terraform state {} of project {}",
+						&cmd_args.name, &project.path_with_namespace
+					)
+				})?;
+			println!(
+				"terraform state {} of project {} has been deleted",
+				&cmd_args.name, &project.path_with_namespace
+			);
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,110 @@
+use crate::{
+	api::todos::{TodoDone, Todos, TodosDone},
+	args::{self, TodoCmd},
+	color::StyledStr,
+	context::CliContext,
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use gitlab::api::{self, Pagination, Query};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Project {
+	path_with_namespace: String,
+}
+
+#[derive(Deserialize)]
+struct Author {
+	username: String,
+}
+
+#[derive(Deserialize)]
+struct Todo {
+	id: u64,
+	project: Option<Project>,
+	author: Author,
+	action_name: String,
+	target_type: String,
+	target_url: String,
+	body: String,
+	created_at: DateTime<Utc>,
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Todo) -> Result<()> {
+	match &args.cmd {
+		TodoCmd::List(cmd_args) => {
+			let mut endpoint = Todos::builder();
+			if cmd_args.project.is_some() {
+				let project = context.get_project(cmd_args.project.as_ref())?;
+				endpoint.project_id(project.id.value());
+			}
+			if let Some(type_) = &cmd_args.type_ {
+				endpoint.type_(type_.as_str());
+			}
+			let endpoint = endpoint.state("pending").build()?;
+			let todos: Vec<Todo> = api::paged(endpoint, Pagination::Limit(cmd_args.limit))
+				.query(&context.gitlab)
+				.with_context(|| "Failed to list todos")?;
+
+			let mut msg = StyledStr::new();
+			if todos.is_empty() {
+				msg.none("No pending todos\n");
+			} else {
+				for todo in todos.iter() {
+					msg.none("- Todo ");
+					msg.literal(todo.id.to_string());
+					msg.none(format!(" {} ", todo.target_type));
+					msg.hint(format!("({})", todo.action_name));
+					if let Some(project) = &todo.project {
+						msg.none(" ");
+						msg.literal(&project.path_with_namespace);
+					}
+					msg.none(format!(" by {}", todo.author.username));
+					msg.none(" [");
+					msg.literal(
+						timeago::Formatter::new().convert_chrono(todo.created_at, Utc::now()),
+					);
+					msg.none("]");
+					if context.url {
+						msg.hint(format!(" ({})", todo.target_url));
+					}
+					msg.none("\n");
+					if context.verbose {
+						msg.none(format!("  {}\n", todo.body));
+					}
+				}
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("https://{}/dashboard/todos", &context.config.host));
+			}
+			Ok(())
+		}
+
+		TodoCmd::Done(cmd_args) => {
+			if cmd_args.all && cmd_args.id.is_some() {
+				bail!("A todo id and --all can't be used together")
+			}
+			if cmd_args.all {
+				let endpoint = TodosDone::builder().build()?;
+				api::ignore(endpoint)
+					.query(&context.gitlab)
+					.with_context(|| "Failed to mark todos as done")?;
+				println!("all pending todos have been marked as done");
+			} else if let Some(id) = cmd_args.id {
+				let endpoint = TodoDone::builder().todo(id).build()?;
+				api::ignore(endpoint)
+					.query(&context.gitlab)
+					.with_context(|| format!("Failed to mark todo {} as done", id))?;
+				println!("todo {} has been marked as done", id);
+			} else {
+				bail!("Specify a todo id or --all on the command line")
+			}
+			Ok(())
+		}
+	}
+}
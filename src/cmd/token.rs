@@ -0,0 +1,201 @@
+use crate::{
+	api::personal_access_tokens::{
+		CreatePersonalAccessToken, PersonalAccessTokens, RevokePersonalAccessToken,
+		RotatePersonalAccessToken,
+	},
+	args::{self, TokenCmd},
+	color::StyledStr,
+	context::CliContext,
+	utils::parse_date,
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use gitlab::api::{self, users::CurrentUser, Pagination, Query};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct PersonalAccessToken {
+	id: u64,
+	name: String,
+	active: bool,
+	revoked: bool,
+	scopes: Vec<String>,
+	expires_at: Option<NaiveDate>,
+	last_used_at: Option<DateTime<Utc>>,
+	/// only returned on creation and rotation
+	token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct User {
+	id: u64,
+}
+
+/// Print a new token, which can't be retrieved afterward
+fn print_token(context: &CliContext, token: &PersonalAccessToken, action: &str) -> Result<()> {
+	let mut msg = StyledStr::new();
+	msg.none(format!("token #{} ", token.id));
+	msg.literal(&token.name);
+	msg.none(format!(" has been {}", action));
+	if let Some(expires_at) = token.expires_at {
+		msg.none(format!(" and expires on {}", expires_at));
+	}
+	msg.none("\n");
+	msg.hint("copy it now as it can't be shown again: ");
+	msg.good(token.token.as_deref().unwrap_or_default());
+	msg.none("\n");
+	context.print_msg(msg)
+}
+
+/// Save a new token in the configuration, replacing the one in use
+fn save_token(context: &CliContext, token: &PersonalAccessToken) -> Result<()> {
+	if let Some(value) = &token.token {
+		context.config.save_token(value)?;
+		println!(
+			"the access token of {} has been replaced in {}",
+			&context.config.host, &context.config.name
+		);
+	}
+	Ok(())
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Token) -> Result<()> {
+	match &args.cmd {
+		TokenCmd::List(cmd_args) => {
+			let mut endpoint = PersonalAccessTokens::builder();
+			if let Some(user) = &cmd_args.user {
+				endpoint.user_id(context.get_user(user)?.id.value());
+			}
+			if !cmd_args.all {
+				endpoint.state("active");
+			}
+			let tokens: Vec<PersonalAccessToken> = api::paged(endpoint.build()?, Pagination::All)
+				.query(&context.gitlab)
+				.with_context(|| "Failed to list personal access tokens")?;
+
+			let mut msg = StyledStr::new();
+			if tokens.is_empty() {
+				msg.none("No personal access tokens\n");
+			}
+			for token in tokens.iter() {
+				msg.none(format!("- #{} ", token.id));
+				msg.literal(&token.name);
+				msg.none(format!(" [{}]", token.scopes.join(", ")));
+				if token.revoked {
+					msg.error(" revoked");
+				} else if !token.active {
+					msg.warning(" expired");
+				}
+				msg.hint(format!(
+					" (expires {}, last used {})",
+					token
+						.expires_at
+						.map(|date| date.to_string())
+						.unwrap_or_else(|| "never".to_owned()),
+					token
+						.last_used_at
+						.map(|date| timeago::Formatter::new().convert_chrono(date, Utc::now()))
+						.unwrap_or_else(|| "never".to_owned())
+				));
+				msg.none("\n");
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!(
+					"https://{}/-/user_settings/personal_access_tokens",
+					&context.config.host
+				));
+			}
+			Ok(())
+		}
+
+		TokenCmd::Create(cmd_args) => {
+			let user = match &cmd_args.user {
+				Some(user) => context.get_user(user)?.id.value(),
+				None => {
+					let user: User = CurrentUser::builder()
+						.build()?
+						.query(&context.gitlab)
+						.with_context(|| "Failed to get the current user")?;
+					user.id
+				}
+			};
+			let scopes = if cmd_args.scopes.is_empty() {
+				vec!["api".into()]
+			} else {
+				cmd_args
+					.scopes
+					.iter()
+					.map(|scope| scope.as_str().into())
+					.collect()
+			};
+			let mut endpoint = CreatePersonalAccessToken::builder();
+			endpoint
+				.user(user)
+				.name(cmd_args.name.as_str())
+				.scopes(scopes);
+			if let Some(expires) = &cmd_args.expires {
+				endpoint.expires_at(parse_date(expires)?);
+			}
+			let token: PersonalAccessToken =
+				endpoint.build()?.query(&context.gitlab).with_context(|| {
+					format!(
+						"Failed to create personal access token {} (admin only)",
+						&cmd_args.name
+					)
+				})?;
+			print_token(context, &token, "created")?;
+			if cmd_args.write {
+				save_token(context, &token)?;
+			}
+			Ok(())
+		}
+
+		TokenCmd::Rotate(cmd_args) => {
+			if cmd_args.write && cmd_args.id.is_some() {
+				bail!("--write only applies when rotating the token in use")
+			}
+			let mut endpoint = RotatePersonalAccessToken::builder();
+			if let Some(id) = cmd_args.id {
+				endpoint.token(id);
+			}
+			if let Some(expires) = &cmd_args.expires {
+				endpoint.expires_at(parse_date(expires)?);
+			}
+			let token: PersonalAccessToken = endpoint
+				.build()?
+				.query(&context.gitlab)
+				.with_context(|| match cmd_args.id {
+					Some(id) => format!("Failed to rotate personal access token #{}", id),
+					None => "Failed to rotate the token in use".to_owned(),
+				})?;
+			// the previous token is revoked, so show the new one before anything else
+			print_token(context, &token, "rotated")?;
+			if cmd_args.write {
+				save_token(context, &token)?;
+			}
+			Ok(())
+		}
+
+		TokenCmd::Revoke(cmd_args) => {
+			let mut endpoint = RevokePersonalAccessToken::builder();
+			if let Some(id) = cmd_args.id {
+				endpoint.token(id);
+			}
+			api::ignore(endpoint.build()?)
+				.query(&context.gitlab)
+				.with_context(|| match cmd_args.id {
+					Some(id) => format!("Failed to revoke personal access token #{}", id),
+					None => "Failed to revoke the token in use".to_owned(),
+				})?;
+			match cmd_args.id {
+				Some(id) => println!("personal access token #{} has been revoked", id),
+				None => println!("the token in use has been revoked"),
+			}
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,68 @@
+use crate::{api::repository::RepositoryTree, args, color::StyledStr, context::CliContext};
+
+use anyhow::{Context, Result};
+use gitlab::api::{self, Pagination, Query};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct TreeEntry {
+	#[serde(rename = "type")]
+	type_: String,
+	path: String,
+	mode: String,
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Tree) -> Result<()> {
+	let project = context.get_project(args.project.as_ref())?;
+	let mut endpoint = RepositoryTree::builder();
+	endpoint
+		.project(project.id.value())
+		.recursive(args.recursive);
+	if let Some(path) = &args.path {
+		endpoint.path(path.as_str());
+	}
+	if let Some(ref_) = &args.ref_ {
+		endpoint.ref_(ref_.as_str());
+	}
+	let entries: Vec<TreeEntry> = api::paged(endpoint.build()?, Pagination::All)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to list the tree of project {}",
+				&project.path_with_namespace
+			)
+		})?;
+
+	let mut msg = StyledStr::new();
+	if entries.is_empty() {
+		msg.none("No files in ");
+		msg.literal(args.path.as_deref().unwrap_or(&project.path_with_namespace));
+		msg.none("\n");
+	}
+	for entry in entries.iter() {
+		msg.hint(format!("{} {:<6} ", &entry.mode, &entry.type_));
+		if entry.type_ == "tree" {
+			msg.literal(format!("{}/", &entry.path));
+		} else {
+			msg.none(&entry.path);
+		}
+		msg.none("\n");
+	}
+	context.print_msg(msg)?;
+
+	if context.open {
+		let ref_ = args
+			.ref_
+			.as_deref()
+			.or(project.default_branch.as_deref())
+			.unwrap_or("HEAD");
+		let _ = open::that(format!(
+			"{}/-/tree/{}/{}",
+			&project.web_url,
+			ref_,
+			args.path.as_deref().unwrap_or_default()
+		));
+	}
+	Ok(())
+}
@@ -0,0 +1,373 @@
+use crate::{
+	api::projects::{DeleteProjectVariable, ProjectVariables, SetProjectVariable},
+	args::{self, VariablesCmd},
+	color::StyledStr,
+	context::CliContext,
+	utils::{dotenv_field, dotenv_records},
+};
+
+use anyhow::{bail, Context, Result};
+use gitlab::{
+	api::{self, Pagination, Query},
+	types,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+	fs::read_to_string,
+	io::{self, Read},
+};
+
+#[derive(Deserialize, Serialize)]
+struct Variable {
+	key: String,
+	value: String,
+	variable_type: String,
+	environment_scope: String,
+	protected: bool,
+	masked: bool,
+	#[serde(default)]
+	raw: bool,
+}
+
+/// Get all the variables of a project
+fn get_variables(context: &CliContext, project: &types::Project) -> Result<Vec<Variable>> {
+	let endpoint = ProjectVariables::builder()
+		.project(project.id.value())
+		.build()?;
+	let mut variables: Vec<Variable> = api::paged(endpoint, Pagination::All)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to get variables of project {}",
+				&project.path_with_namespace
+			)
+		})?;
+	variables.sort_by(|a, b| (&a.key, &a.environment_scope).cmp(&(&b.key, &b.environment_scope)));
+	Ok(variables)
+}
+
+/// A variable to create or update
+struct VariableSpec<'a> {
+	key: &'a str,
+	value: String,
+	scope: &'a str,
+	protected: bool,
+	masked: bool,
+	raw: bool,
+	variable_type: &'a str,
+}
+
+/// Return why a value can't be masked according to gitlab masking rules, if any
+fn unmaskable(value: &str) -> Option<&'static str> {
+	if value.len() < 8 {
+		Some("it must be at least 8 characters long")
+	} else if value.contains(['\n', '\r']) {
+		Some("it must be a single line")
+	} else if !value
+		.chars()
+		.all(|c| c.is_ascii_alphanumeric() || "+/=-_@:.~".contains(c))
+	{
+		Some("it must only contain base64 characters or @, :, . and ~")
+	} else {
+		None
+	}
+}
+
+/// Create a variable or update it if it already exists
+fn set_variable(
+	context: &CliContext,
+	project: &types::Project,
+	variable: VariableSpec,
+	exists: bool,
+) -> Result<()> {
+	if !["env_var", "file"].contains(&variable.variable_type) {
+		bail!(
+			"{} not supported for --variable-type. Use either \"env_var\" or \"file\"",
+			variable.variable_type
+		)
+	}
+	// gitlab rejects unmaskable values with an obscure error
+	let mut masked = variable.masked;
+	if masked {
+		if let Some(reason) = unmaskable(&variable.value) {
+			eprintln!(
+				"warning: variable {} [{}] can't be masked because {}. It is set unmasked",
+				variable.key, variable.scope, reason
+			);
+			masked = false;
+		}
+	}
+
+	let endpoint = SetProjectVariable::builder()
+		.project(project.id.value())
+		.create(!exists)
+		.key(variable.key)
+		.value(variable.value)
+		.variable_type(variable.variable_type)
+		.protected(variable.protected)
+		.masked(masked)
+		.raw(variable.raw)
+		.environment_scope(variable.scope)
+		.build()?;
+	api::ignore(endpoint)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to set variable {} [{}] on project {}",
+				variable.key, variable.scope, &project.path_with_namespace
+			)
+		})?;
+	println!(
+		"variable {} [{}] has been {} on project {}",
+		variable.key,
+		variable.scope,
+		if exists { "updated" } else { "created" },
+		&project.path_with_namespace
+	);
+	Ok(())
+}
+
+/// Delete a variable
+fn delete_variable(
+	context: &CliContext,
+	project: &types::Project,
+	key: &str,
+	scope: &str,
+) -> Result<()> {
+	let endpoint = DeleteProjectVariable::builder()
+		.project(project.id.value())
+		.key(key)
+		.environment_scope(scope)
+		.build()?;
+	api::ignore(endpoint)
+		.query(&context.gitlab)
+		.with_context(|| {
+			format!(
+				"Failed to delete variable {} [{}] on project {}",
+				key, scope, &project.path_with_namespace
+			)
+		})?;
+	println!(
+		"variable {} [{}] has been deleted on project {}",
+		key, scope, &project.path_with_namespace
+	);
+	Ok(())
+}
+
+/// Find a variable by its key, and its environment scope when the key is defined in
+/// several scopes
+fn find_variable<'a>(
+	variables: &'a [Variable],
+	key: &str,
+	scope: Option<&String>,
+	project: &types::Project,
+) -> Result<&'a Variable> {
+	let found: Vec<&Variable> = variables
+		.iter()
+		.filter(|v| v.key == key && (scope.is_none() || scope == Some(&v.environment_scope)))
+		.collect();
+	match found.as_slice() {
+		[variable] => Ok(*variable),
+		[] => bail!(
+			"variable {} [{}] not found on project {}",
+			key,
+			scope.map(String::as_str).unwrap_or("any scope"),
+			&project.path_with_namespace
+		),
+		_ => bail!(
+			"variable {} is defined in several environment scopes ({}) on project {}. Use --environment-scope",
+			key,
+			found
+				.iter()
+				.map(|v| v.environment_scope.as_str())
+				.collect::<Vec<_>>()
+				.join(", "),
+			&project.path_with_namespace
+		),
+	}
+}
+
+/// Append a variable description to the message
+fn variable_msg(msg: &mut StyledStr, variable: &Variable, reveal: bool) {
+	msg.none("- ");
+	msg.literal(&variable.key);
+	msg.none(format!(" [{}]", variable.environment_scope));
+	if variable.protected {
+		msg.warning(" protected");
+	}
+	if variable.masked {
+		msg.warning(" masked");
+	}
+	if variable.raw {
+		msg.hint(" raw");
+	}
+	if variable.variable_type == "file" {
+		msg.hint(" file");
+	}
+	if reveal {
+		msg.none(format!(" = {}", variable.value));
+	}
+	msg.none("\n");
+}
+
+/// Command implementation
+pub fn cmd(context: &CliContext, args: &args::Variables) -> Result<()> {
+	match &args.cmd {
+		VariablesCmd::List(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let variables = get_variables(context, &project)?;
+
+			let mut msg = StyledStr::new();
+			if variables.is_empty() {
+				msg.none("No variables for ");
+				msg.literal(&project.path_with_namespace);
+				msg.none("\n");
+			}
+			for variable in variables.iter() {
+				variable_msg(&mut msg, variable, cmd_args.reveal);
+			}
+			context.print_msg(msg)?;
+
+			if context.open {
+				let _ = open::that(format!("{}/-/settings/ci_cd", &project.web_url));
+			}
+			Ok(())
+		}
+
+		VariablesCmd::Get(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let variables = get_variables(context, &project)?;
+			let variable = find_variable(
+				&variables,
+				&cmd_args.key,
+				cmd_args.environment_scope.as_ref(),
+				&project,
+			)?;
+			let mut msg = StyledStr::new();
+			variable_msg(&mut msg, variable, cmd_args.reveal);
+			context.print_msg(msg)
+		}
+
+		VariablesCmd::Set(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			// reading the value from stdin keeps secrets out of the shell history
+			let value = match &cmd_args.value {
+				Some(value) => value.to_owned(),
+				None => {
+					let mut value = String::new();
+					io::stdin().read_to_string(&mut value)?;
+					value.trim_end_matches('\n').to_owned()
+				}
+			};
+			let exists = get_variables(context, &project)?.iter().any(|v| {
+				v.key == cmd_args.key && v.environment_scope == cmd_args.environment_scope
+			});
+			set_variable(
+				context,
+				&project,
+				VariableSpec {
+					key: &cmd_args.key,
+					value,
+					scope: &cmd_args.environment_scope,
+					protected: cmd_args.protected,
+					masked: cmd_args.masked,
+					raw: cmd_args.raw,
+					variable_type: &cmd_args.variable_type,
+				},
+				exists,
+			)
+		}
+
+		VariablesCmd::Delete(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let variables = get_variables(context, &project)?;
+			let variable = find_variable(
+				&variables,
+				&cmd_args.key,
+				cmd_args.environment_scope.as_ref(),
+				&project,
+			)?;
+			delete_variable(
+				context,
+				&project,
+				&variable.key,
+				&variable.environment_scope,
+			)
+		}
+
+		VariablesCmd::Export(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let mut variables = get_variables(context, &project)?;
+			variables.retain(|v| v.environment_scope == cmd_args.environment_scope);
+			match cmd_args.format.as_str() {
+				"dotenv" => {
+					for variable in variables.iter() {
+						println!("{}={}", &variable.key, dotenv_field(&variable.value));
+					}
+				}
+				"json" => println!("{}", serde_json::to_string_pretty(&variables)?),
+				"yaml" => print!("{}", serde_yaml::to_string(&variables)?),
+				format => bail!(
+					"{} not supported for --format. Use either \"dotenv\", \"json\" or \"yaml\"",
+					format
+				),
+			}
+			Ok(())
+		}
+
+		VariablesCmd::Import(cmd_args) => {
+			let project = context.get_project(cmd_args.project.as_ref())?;
+			let content = read_to_string(&cmd_args.file)
+				.with_context(|| format!("Can't read {}", &cmd_args.file))?;
+			let records = dotenv_records(&content)
+				.with_context(|| format!("{} is not a valid dotenv file", &cmd_args.file))?;
+
+			let mut current = get_variables(context, &project)?;
+			current.retain(|v| v.environment_scope == cmd_args.environment_scope);
+			for (key, value) in records.iter() {
+				let existing = current.iter().find(|v| &v.key == key);
+				// don't touch variables that are already up to date, knowing that unmaskable
+				// values are stored unmasked
+				if let Some(variable) = existing {
+					if &variable.value == value
+						&& variable.variable_type == cmd_args.variable_type
+						&& variable.protected == cmd_args.protected
+						&& variable.masked == (cmd_args.masked && unmaskable(value).is_none())
+						&& variable.raw == cmd_args.raw
+					{
+						continue;
+					}
+				}
+				set_variable(
+					context,
+					&project,
+					VariableSpec {
+						key,
+						value: value.to_owned(),
+						scope: &cmd_args.environment_scope,
+						protected: cmd_args.protected,
+						masked: cmd_args.masked,
+						raw: cmd_args.raw,
+						variable_type: &cmd_args.variable_type,
+					},
+					existing.is_some(),
+				)?;
+			}
+
+			if cmd_args.sync {
+				for variable in current
+					.iter()
+					.filter(|v| !records.iter().any(|(key, _)| key == &v.key))
+				{
+					delete_variable(
+						context,
+						&project,
+						&variable.key,
+						&cmd_args.environment_scope,
+					)?;
+				}
+			}
+			Ok(())
+		}
+	}
+}
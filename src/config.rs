@@ -1,34 +1,392 @@
-use crate::{args::Opts, oidc::login};
+use crate::{
+	args::{ColorChoice, Opts, Protocol},
+	oidc::login,
+};
 
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Duration, Utc};
 use directories::ProjectDirs;
+use reqwest::{blocking, Certificate, Proxy};
 use serde::{Deserialize, Serialize};
-use std::{env, fs::File, ops::Deref, path::PathBuf};
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::{
+	collections::BTreeMap,
+	env,
+	fs::{self, create_dir_all, remove_file, File, OpenOptions},
+	io, mem,
+	ops::Deref,
+	path::{Path, PathBuf},
+	time,
+};
 
-/// Root configuration file
-#[derive(Deserialize)]
+/// Configuration of the selected gitlab host
 pub struct Config {
 	/// gitlab host
 	pub host: String,
 	// auth type
 	pub auth: AuthType,
-	#[serde(skip)]
 	/// filename associated to the config file
 	pub name: String,
+	/// name of the host profile, if the host was taken from the hosts section
+	pub profile: Option<String>,
+	/// path of the config file, if any
+	path: Option<PathBuf>,
+	/// defaults of the config file merged with the ones of the repository
+	pub settings: Settings,
+	/// tls and proxy parameters of the connection
+	pub connection: Connection,
 }
 
-/// Authentication type supported
+/// Defaults which can be set in the config file, or in a .glctl.yaml file committed in the
+/// repository in which case the values of the config file take precedence
+#[derive(Default, Deserialize)]
+pub struct Settings {
+	/// default project path
+	pub project: Option<String>,
+	/// default reference (tag or branch)
+	#[serde(rename = "ref")]
+	pub ref_: Option<String>,
+	/// default destination directory of archives
+	#[serde(rename = "archive-dir")]
+	pub archive_dir: Option<String>,
+	/// default color mode
+	pub color: Option<ColorChoice>,
+	/// check the scopes of the access token before running commands
+	#[serde(rename = "check-scopes")]
+	pub check_scopes: Option<bool>,
+	/// name of the git remote designating the project when the repository has several
+	pub remote: Option<String>,
+	/// protocol used to clone repositories
+	#[serde(rename = "clone-protocol")]
+	pub clone_protocol: Option<Protocol>,
+	/// default options of commands by command path (ex: "archive extract"). Only read from
+	/// the repository as they are needed before parsing the command line
+	#[serde(default)]
+	pub args: BTreeMap<String, Vec<String>>,
+}
+
+impl Settings {
+	/// Path of the .glctl.yaml file of the working directory or of its closest parent
+	pub fn repo_path() -> Result<Option<PathBuf>> {
+		let dir = env::current_dir()?;
+		Ok(dir
+			.ancestors()
+			.map(|dir| dir.join(".glctl.yaml"))
+			.find(|path| path.exists()))
+	}
+
+	/// Read the .glctl.yaml file of the working directory or of its closest parent
+	pub fn from_repo() -> Result<Option<Self>> {
+		match Self::repo_path()? {
+			Some(path) => {
+				let file = File::open(&path).with_context(|| format!("Can't open {:?}", &path))?;
+				serde_yaml::from_reader(file)
+					.map(Some)
+					.with_context(|| format!("Can't read {:?}", &path))
+			}
+			None => Ok(None),
+		}
+	}
+
+	/// Fill the values that are not set with the ones of other settings
+	pub fn merge(self, other: Self) -> Self {
+		let mut args = other.args;
+		args.extend(self.args);
+		Self {
+			project: self.project.or(other.project),
+			ref_: self.ref_.or(other.ref_),
+			archive_dir: self.archive_dir.or(other.archive_dir),
+			color: self.color.or(other.color),
+			check_scopes: self.check_scopes.or(other.check_scopes),
+			remote: self.remote.or(other.remote),
+			clone_protocol: self.clone_protocol.or(other.clone_protocol),
+			args,
+		}
+	}
+
+	/// Insert the default options of the longest matching command path right after it in the
+	/// command line arguments, skipping the options already given
+	pub fn apply_args(&self, mut args: Vec<String>) -> Vec<String> {
+		let found = self
+			.args
+			.iter()
+			.filter_map(|(command, defaults)| {
+				let words: Vec<&str> = command.split_whitespace().collect();
+				if words.is_empty() {
+					return None;
+				}
+				args.windows(words.len())
+					.position(|window| window.iter().zip(words.iter()).all(|(a, b)| a == b))
+					.map(|i| (i + words.len(), words.len(), defaults))
+			})
+			.max_by_key(|(_, len, _)| *len);
+
+		if let Some((i, _, defaults)) = found {
+			let mut inserted = Vec::new();
+			let mut defaults = defaults.iter().peekable();
+			while let Some(option) = defaults.next() {
+				// an option is followed by its value unless it is a switch
+				let value = defaults.next_if(|value| !value.starts_with('-'));
+				if !args[i..].contains(option) {
+					inserted.push(option.to_owned());
+					inserted.extend(value.cloned());
+				}
+			}
+			args.splice(i..i, inserted);
+		}
+		args
+	}
+}
+
+/// TLS and proxy parameters of the connection to a gitlab host
+#[derive(Default, Deserialize, Serialize)]
+pub struct Connection {
+	/// PEM file of the certificate authorities to trust in addition to the system ones
+	#[serde(rename = "ca-cert", skip_serializing_if = "Option::is_none")]
+	pub ca_cert: Option<String>,
+	/// accept invalid certificates
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub insecure: bool,
+	/// proxy url used instead of the ones of HTTP_PROXY, HTTPS_PROXY and NO_PROXY environment
+	/// variables. An empty url disables the proxy
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub proxy: Option<String>,
+	/// timeout of requests in seconds
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub timeout: Option<u64>,
+	/// timeout of the connection to the host in seconds
+	#[serde(rename = "connect-timeout", skip_serializing_if = "Option::is_none")]
+	pub connect_timeout: Option<u64>,
+	/// number of times a request is retried after a transient failure
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub retries: Option<u32>,
+}
+
+/// Number of times a request is retried after a transient failure by default
+const DEFAULT_RETRIES: u32 = 5;
+
+impl Connection {
+	/// Number of times a request is retried after a transient failure
+	pub fn retries(&self) -> u32 {
+		self.retries.unwrap_or(DEFAULT_RETRIES)
+	}
+
+	/// Build an http client with the TLS and proxy parameters
+	pub fn client(&self) -> Result<blocking::Client> {
+		let mut builder = blocking::Client::builder();
+		if let Some(ca_cert) = &self.ca_cert {
+			let pem =
+				fs::read_to_string(ca_cert).with_context(|| format!("Can't read {}", ca_cert))?;
+			// the file may be a bundle of several certificates
+			for cert in pem
+				.split_inclusive("-----END CERTIFICATE-----")
+				.filter(|cert| cert.contains("-----BEGIN CERTIFICATE-----"))
+			{
+				let cert = Certificate::from_pem(cert.as_bytes())
+					.with_context(|| format!("Invalid certificate in {}", ca_cert))?;
+				builder = builder.add_root_certificate(cert);
+			}
+		}
+		if self.insecure {
+			builder = builder.danger_accept_invalid_certs(true);
+		}
+		if let Some(timeout) = self.timeout {
+			builder = builder.timeout(time::Duration::from_secs(timeout));
+		}
+		if let Some(timeout) = self.connect_timeout {
+			builder = builder.connect_timeout(time::Duration::from_secs(timeout));
+		}
+		// the proxies of the environment are used otherwise
+		match self.proxy.as_deref() {
+			Some("") => builder = builder.no_proxy(),
+			Some(proxy) => {
+				let proxy =
+					Proxy::all(proxy).with_context(|| format!("Invalid proxy {}", proxy))?;
+				builder = builder.proxy(proxy);
+			}
+			None => (),
+		}
+		builder
+			.build()
+			.with_context(|| "Unable to create the http client")
+	}
+}
+
+/// Connection parameters of a named gitlab host
+#[derive(Deserialize)]
+pub struct HostConfig {
+	/// gitlab host
+	pub host: String,
+	// auth type
+	pub auth: AuthType,
+	/// tls and proxy parameters
+	#[serde(flatten)]
+	pub connection: Connection,
+}
+
+/// Root configuration file
+#[derive(Deserialize)]
+struct ConfigFile {
+	/// defaults shared by all hosts
+	#[serde(flatten)]
+	settings: Settings,
+	/// gitlab host used when no profile is selected
+	host: Option<String>,
+	auth: Option<AuthType>,
+	#[serde(flatten)]
+	connection: Connection,
+	/// named gitlab hosts
+	#[serde(default)]
+	hosts: BTreeMap<String, HostConfig>,
+	/// name of the host to use by default
+	default: Option<String>,
+}
+
+impl ConfigFile {
+	/// Select a host by its profile name, the first host of the git remotes known in the
+	/// configuration, the default profile or the root host in that order
+	fn select(
+		mut self,
+		profile: Option<&String>,
+		remote_hosts: &[&String],
+	) -> Result<(Option<String>, HostConfig)> {
+		let names = self
+			.hosts
+			.keys()
+			.map(|name| format!("\"{}\"", name))
+			.collect::<Vec<_>>()
+			.join(", ");
+		let name = if let Some(profile) = profile {
+			if !self.hosts.contains_key(profile) {
+				bail!(
+					"No host named {} in the configuration. Use either {}",
+					profile,
+					names
+				)
+			}
+			Some(profile.to_owned())
+		} else {
+			remote_hosts
+				.iter()
+				.find_map(|remote_host| {
+					self.hosts
+						.iter()
+						.find(|(_, host)| &&host.host == remote_host)
+						.map(|(name, _)| name.to_owned())
+				})
+				.or_else(|| self.default.clone())
+		};
+
+		if let Some(name) = name {
+			let host = self.hosts.remove(&name).ok_or_else(|| {
+				anyhow!(
+					"Default host {} not found in the configuration. Use either {}",
+					&name,
+					names
+				)
+			})?;
+			Ok((Some(name), host))
+		} else if let (Some(host), Some(auth)) = (self.host.take(), self.auth.take()) {
+			Ok((
+				None,
+				HostConfig {
+					host,
+					auth,
+					connection: mem::take(&mut self.connection),
+				},
+			))
+		} else if self.hosts.len() == 1 {
+			let (name, host) = self.hosts.into_iter().next().unwrap();
+			Ok((Some(name), host))
+		} else {
+			bail!(
+				"No default host in the configuration. Use --host with either {}",
+				names
+			)
+		}
+	}
+}
+
+/// Aliases of command lines defined in the config file
 #[derive(Deserialize)]
+struct Aliases {
+	#[serde(default)]
+	aliases: BTreeMap<String, String>,
+}
+
+/// Keys of the root of the config file
+const ROOT_KEYS: [&str; 19] = [
+	"aliases",
+	"check-scopes",
+	"clone-protocol",
+	"host",
+	"auth",
+	"ca-cert",
+	"insecure",
+	"proxy",
+	"timeout",
+	"connect-timeout",
+	"retries",
+	"hosts",
+	"default",
+	"project",
+	"ref",
+	"archive-dir",
+	"color",
+	"remote",
+	"args",
+];
+/// Keys of a named host
+const HOST_KEYS: [&str; 8] = [
+	"host",
+	"auth",
+	"ca-cert",
+	"insecure",
+	"proxy",
+	"timeout",
+	"connect-timeout",
+	"retries",
+];
+/// Keys of an oauth2 authentication
+const OAUTH2_KEYS: [&str; 3] = ["id", "secret", "redirect-port"];
+
+/// Report the keys of a mapping which are not among the known ones, prefixing them with the
+/// path of the mapping
+fn unknown_keys(
+	value: &serde_yaml::Value,
+	prefix: &str,
+	known: &[&str],
+	problems: &mut Vec<String>,
+) {
+	for key in value.as_mapping().into_iter().flat_map(|map| map.keys()) {
+		let key = key.as_str().unwrap_or_default();
+		if !known.contains(&key) {
+			problems.push(format!(
+				"unknown key {}{} is ignored. Use either {}",
+				prefix,
+				key,
+				known.join(", ")
+			));
+		}
+	}
+}
+
+/// Authentication type supported
+#[derive(Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum AuthType {
 	/// access token
 	Token(String),
 	/// oauth2 config
 	OAuth2(OAuth2),
+	/// CI job token, only available from the environment of a CI job
+	#[serde(skip)]
+	JobToken(String),
 }
 
 /// Oidc configuration part
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 pub struct OAuth2 {
 	/// id used to identity ourselves to the oauth2 provider
 	pub id: String,
@@ -45,28 +403,27 @@ impl Config {
 	/// - GLCTL_CONFIG environment variable
 	/// - HOME directory: ~/.config/glctl/config.yaml
 	/// - Current directory: .glctl_config.yaml
-	pub fn from_file(path: Option<&String>, verbose: bool) -> Result<Self> {
-		// if a config path was given, try that
-		let config_path = if let Some(config) = path {
-			PathBuf::from(config)
-		// otherwise try to find a configuration file from
-		} else {
-			// first test from env var
-			env::var("GLCTL_CONFIG")
-				.ok()
-				.map(PathBuf::from)
-				.filter(|path| path.exists())
-				// then test from project dir
-				.or_else(|| {
-					ProjectDirs::from("me", "IT Sufficient", "GlCtl")
-						.map(|dir| dir.config_dir().join("config.yaml"))
-						.filter(|path| path.exists())
-				})
-				// then test in current directory
-				.or_else(|| Some(PathBuf::from(".glctl_config.yaml")))
-				.filter(|path| path.exists())
+	///
+	/// The host is selected by profile name or by the hosts of the git remotes among the
+	/// named hosts of the configuration
+	pub fn from_file(
+		path: Option<&String>,
+		profile: Option<&String>,
+		remote_hosts: &[&String],
+		verbose: bool,
+	) -> Result<Self> {
+		let config_path = match Self::find_path(path) {
+			Some(config_path) => config_path,
+			// then use the job token when running inside a CI job
+			None => {
 				// finally return an error if nothing worked
-				.ok_or_else(|| anyhow!("Unable to find a suitable configuration file"))?
+				let config = Self::from_ci()
+					.ok_or_else(|| anyhow!("Unable to find a suitable configuration file"))?;
+				if verbose {
+					println!("Using the CI job token of {}", &config.host);
+				}
+				return Ok(config);
+			}
 		};
 
 		if verbose {
@@ -76,39 +433,389 @@ impl Config {
 		let file =
 			File::open(&config_path).with_context(|| format!("Can't open {:?}", &config_path))?;
 		// deserialize configuration
-		let mut config: Self = serde_yaml::from_reader(file)
+		let mut config: ConfigFile = serde_yaml::from_reader(file)
 			.with_context(|| format!("Can't read {:?}", &config_path))?;
+		let settings = mem::take(&mut config.settings);
+		let (profile, host) = config
+			.select(profile, remote_hosts)
+			.with_context(|| format!("Can't select a host from {:?}", &config_path))?;
+		if verbose {
+			if let Some(profile) = &profile {
+				println!("Using host {} ({})", profile, &host.host);
+			}
+		}
 
 		// save the config filename for later use
 		// the config has been read from a file so the unwrap is harmles
-		config.name = config_path
+		let name = config_path
 			.file_name()
 			.map(|name| name.to_string_lossy().to_string())
 			.unwrap();
-		// config.path = config_path;
-		Ok(config)
+		Ok(Self {
+			host: host.host,
+			auth: host.auth,
+			name,
+			profile,
+			path: Some(config_path),
+			settings,
+			connection: host.connection,
+		})
+	}
+
+	/// Path of the config file to use, which is the given one or the first found from
+	/// GLCTL_CONFIG environment variable, the user config directory and the current directory
+	pub fn find_path(path: Option<&String>) -> Option<PathBuf> {
+		// if a config path was given, try that
+		if let Some(config) = path {
+			Some(PathBuf::from(config))
+		// otherwise try to find a configuration file from
+		} else {
+			// first test from env var
+			env::var("GLCTL_CONFIG")
+				.ok()
+				.map(PathBuf::from)
+				.filter(|path| path.exists())
+				// then test from project dir
+				.or_else(|| Self::user_path().ok().filter(|path| path.exists()))
+				// then test in current directory
+				.or_else(|| Some(PathBuf::from(".glctl_config.yaml")))
+				.filter(|path| path.exists())
+		}
+	}
+
+	/// Read the configurations of all the hosts of a config file, without the defaults
+	pub fn all_from_file(path: &Path) -> Result<Vec<Self>> {
+		let file = File::open(path).with_context(|| format!("Can't open {:?}", path))?;
+		let config: ConfigFile =
+			serde_yaml::from_reader(file).with_context(|| format!("Can't read {:?}", path))?;
+		let name = path
+			.file_name()
+			.map(|name| name.to_string_lossy().to_string())
+			.unwrap_or_default();
+		let root = match (config.host, config.auth) {
+			(Some(host), Some(auth)) => Some((
+				None,
+				HostConfig {
+					host,
+					auth,
+					connection: config.connection,
+				},
+			)),
+			_ => None,
+		};
+		Ok(root
+			.into_iter()
+			.chain(
+				config
+					.hosts
+					.into_iter()
+					.map(|(profile, host)| (Some(profile), host)),
+			)
+			.map(|(profile, host)| Self {
+				host: host.host,
+				auth: host.auth,
+				name: name.clone(),
+				profile,
+				path: Some(path.to_owned()),
+				settings: Settings::default(),
+				connection: host.connection,
+			})
+			.collect())
+	}
+
+	/// List the mistakes of a config file that are silently ignored when reading it: unknown
+	/// keys, incomplete root host and unknown default host
+	pub fn lint(path: &Path) -> Result<Vec<String>> {
+		let file = File::open(path).with_context(|| format!("Can't open {:?}", path))?;
+		let value: serde_yaml::Value =
+			serde_yaml::from_reader(file).with_context(|| format!("Can't read {:?}", path))?;
+		let mut problems = Vec::new();
+		let root = match value.as_mapping() {
+			Some(root) => root,
+			None => bail!("{:?} doesn't contain a mapping of keys and values", path),
+		};
+		unknown_keys(&value, "", &ROOT_KEYS, &mut problems);
+
+		match (root.get("host"), root.get("auth")) {
+			(Some(_), None) => problems.push("host is defined without auth".to_owned()),
+			(None, Some(_)) => problems.push("auth is defined without host".to_owned()),
+			_ => (),
+		}
+
+		let hosts = root.get("hosts").and_then(|hosts| hosts.as_mapping());
+		for (name, host) in hosts.into_iter().flatten() {
+			let name = format!("hosts.{}.", name.as_str().unwrap_or_default());
+			unknown_keys(host, &name, &HOST_KEYS, &mut problems);
+			if let Some(auth) = host.get("auth") {
+				unknown_keys(auth, &format!("{}auth.", name), &OAUTH2_KEYS, &mut problems);
+			}
+		}
+		if let Some(auth) = root.get("auth") {
+			unknown_keys(auth, "auth.", &OAUTH2_KEYS, &mut problems);
+		}
+
+		if let Some(default) = root.get("default").and_then(|default| default.as_str()) {
+			if !hosts.is_some_and(|hosts| hosts.contains_key(default)) {
+				problems.push(format!(
+					"default host {} is not defined in the hosts section",
+					default
+				));
+			}
+		}
+		Ok(problems)
+	}
+
+	/// Read the aliases of command lines of the config file
+	pub fn aliases(path: Option<&String>) -> Result<BTreeMap<String, String>> {
+		match Self::find_path(path) {
+			Some(path) => {
+				let file = File::open(&path).with_context(|| format!("Can't open {:?}", &path))?;
+				let aliases: Aliases = serde_yaml::from_reader(file)
+					.with_context(|| format!("Can't read {:?}", &path))?;
+				Ok(aliases.aliases)
+			}
+			None => Ok(BTreeMap::new()),
+		}
+	}
+
+	/// Name of the git remote to use, given on the command line or else set in the config file
+	/// or in the .glctl.yaml file of the repository. It is read before selecting a host as the
+	/// host of the remote is preferred
+	pub fn remote(opts: &Opts) -> Result<Option<String>> {
+		if let Some(remote) = &opts.remote {
+			return Ok(Some(remote.to_owned()));
+		}
+		let remote = match Self::find_path(opts.config.as_ref()) {
+			Some(path) => {
+				let file = File::open(&path).with_context(|| format!("Can't open {:?}", &path))?;
+				let settings: Settings = serde_yaml::from_reader(file)
+					.with_context(|| format!("Can't read {:?}", &path))?;
+				settings.remote
+			}
+			None => None,
+		};
+		match remote {
+			Some(remote) => Ok(Some(remote)),
+			None => Ok(Settings::from_repo()?.and_then(|settings| settings.remote)),
+		}
+	}
+
+	/// Define an alias in the config file, or remove it when no command line is given.
+	/// Return false if there was nothing to remove. The file is rewritten, so comments are lost
+	pub fn save_alias(path: &Path, name: &str, command: Option<&str>) -> Result<bool> {
+		let content = fs::read_to_string(path).with_context(|| format!("Can't read {:?}", path))?;
+		let mut value: serde_yaml::Value =
+			serde_yaml::from_str(&content).with_context(|| format!("Can't read {:?}", path))?;
+		let root = value
+			.as_mapping_mut()
+			.ok_or_else(|| anyhow!("{:?} doesn't contain a mapping of keys and values", path))?;
+		if !root.contains_key("aliases") {
+			root.insert("aliases".into(), serde_yaml::Mapping::new().into());
+		}
+		let aliases = root
+			.get_mut("aliases")
+			.and_then(|aliases| aliases.as_mapping_mut())
+			.ok_or_else(|| anyhow!("aliases of {:?} is not a mapping", path))?;
+		let changed = match command {
+			Some(command) => {
+				aliases.insert(name.into(), command.into());
+				true
+			}
+			None => aliases.remove(name).is_some(),
+		};
+		if aliases.is_empty() {
+			root.remove("aliases");
+		}
+		if changed {
+			fs::write(path, serde_yaml::to_string(&value)?)
+				.with_context(|| format!("Can't write {:?}", path))?;
+		}
+		Ok(changed)
+	}
+
+	/// Path of the config file in the user config directory
+	pub fn user_path() -> Result<PathBuf> {
+		project_dirs()
+			.map(|dir| dir.config_dir().join("config.yaml"))
+			.ok_or_else(|| anyhow!("Unable to find a suitable configuration file path"))
+	}
+
+	/// Path of the user cache directory
+	pub fn cache_dir() -> Result<PathBuf> {
+		project_dirs()
+			.map(|dir| dir.cache_dir().to_owned())
+			.ok_or_else(|| anyhow!("Unable to find a suitable cache directory"))
+	}
+
+	/// Initializer from the predefined variables of a CI job, authenticating with the job token
+	pub fn from_ci() -> Option<Self> {
+		let token = env::var("CI_JOB_TOKEN").ok()?;
+		let url = env::var("CI_SERVER_URL").ok()?;
+		let host = url
+			.strip_prefix("https://")
+			.or_else(|| url.strip_prefix("http://"))
+			.unwrap_or(&url)
+			.trim_end_matches('/')
+			.to_owned();
+		Some(Self {
+			host,
+			auth: AuthType::JobToken(token),
+			name: "ci".to_owned(),
+			profile: None,
+			path: None,
+			settings: Settings::default(),
+			// certificate authorities of the runner
+			connection: Connection {
+				ca_cert: env::var("CI_SERVER_TLS_CA_FILE").ok(),
+				..Connection::default()
+			},
+		})
+	}
+
+	/// Replace the access token of the host in the config file, keeping the rest of the file
+	/// as is
+	pub fn save_token(&self, token: &str) -> Result<()> {
+		let (current, path) = match (&self.auth, &self.path) {
+			(AuthType::Token(current), Some(path)) => (current, path),
+			_ => bail!(
+				"The host {} isn't configured with an access token",
+				&self.host
+			),
+		};
+		let content = fs::read_to_string(path).with_context(|| format!("Can't read {:?}", path))?;
+		if !content.contains(current.as_str()) {
+			bail!(
+				"The access token isn't defined in {:?}. Update it manually",
+				path
+			)
+		}
+		fs::write(path, content.replace(current.as_str(), token))
+			.with_context(|| format!("Can't write {:?}", path))
+	}
+
+	/// Replace the connection parameters of the config file by the ones given on the command
+	/// line
+	pub fn with_connection_override(mut self, opts: &Opts) -> Self {
+		let connection = &mut self.connection;
+		if let Some(ca_cert) = &opts.ca_cert {
+			connection.ca_cert = Some(ca_cert.to_owned());
+		}
+		connection.insecure |= opts.insecure;
+		connection.timeout = opts.timeout.or(connection.timeout);
+		connection.connect_timeout = opts.connect_timeout.or(connection.connect_timeout);
+		connection.retries = opts.retries.or(connection.retries);
+		self
+	}
+
+	/// Replace the authentication of the config file by an access token read from stdin when
+	/// `token_stdin` is set, or from GLCTL_TOKEN or GITLAB_TOKEN environment variables
+	pub fn with_token_override(mut self, token_stdin: bool, verbose: bool) -> Result<Self> {
+		let token = if token_stdin {
+			let mut token = String::new();
+			io::stdin()
+				.read_line(&mut token)
+				.with_context(|| "Can't read the token from stdin")?;
+			let token = token.trim();
+			if token.is_empty() {
+				bail!("No token given on stdin")
+			}
+			Some(("stdin", token.to_owned()))
+		} else {
+			["GLCTL_TOKEN", "GITLAB_TOKEN"].iter().find_map(|var| {
+				env::var(var)
+					.ok()
+					.filter(|token| !token.is_empty())
+					.map(|token| (*var, token))
+			})
+		};
+
+		if let Some((source, token)) = token {
+			if verbose {
+				println!("Using the access token from {}", source);
+			}
+			self.auth = AuthType::Token(token);
+		}
+		Ok(self)
 	}
 }
 
 /// OAuth2 login token
 #[derive(Deserialize, Serialize)]
-pub struct OAuth2Token(String);
+pub struct OAuth2Token {
+	/// token used to access the api
+	access_token: String,
+	/// token used to renew the access token once expired
+	refresh_token: Option<String>,
+	/// expiration date of the access token
+	expires_at: Option<DateTime<Utc>>,
+}
+
+/// Create a file only readable by the current user, along with its parent directories
+pub fn create_private_file(path: &Path) -> Result<File> {
+	if let Some(dir) = path.parent() {
+		create_dir_all(dir).with_context(|| format!("Unable to create {:?}", dir))?;
+	}
+	let mut options = OpenOptions::new();
+	options.write(true).create(true).truncate(true);
+	#[cfg(unix)]
+	options.mode(0o600);
+	options
+		.open(path)
+		.with_context(|| format!("Unable to create {:?}", path))
+}
+
+/// Directories of the configuration and the cache following the conventions of the platform:
+/// XDG base directories on Linux, Application Support and Caches on macOS, and AppData on
+/// Windows. The directories of the previous versions are moved there on first use
+fn project_dirs() -> Option<ProjectDirs> {
+	let dirs = ProjectDirs::from_path(PathBuf::from("glctl"))?;
+	// they only differ on macOS and Windows
+	if let Some(legacy) = ProjectDirs::from("me", "IT Sufficient", "GlCtl") {
+		for (from, to) in [
+			(legacy.config_dir(), dirs.config_dir()),
+			(legacy.cache_dir(), dirs.cache_dir()),
+		] {
+			if from != to && from.exists() && !to.exists() {
+				let moved = to
+					.parent()
+					.map_or(Ok(()), create_dir_all)
+					.and_then(|_| fs::rename(from, to));
+				match moved {
+					Ok(()) => eprintln!("{:?} has been moved to {:?}", from, to),
+					Err(err) => eprintln!("Unable to move {:?} to {:?}: {}", from, to, err),
+				}
+			}
+		}
+	}
+	Some(dirs)
+}
+
+/// Path of the cache file holding the login token of a host
+fn cache_path(host: &str) -> Option<PathBuf> {
+	project_dirs().map(|dir| dir.cache_dir().join(format!("oidc_login_{}", host)))
+}
 
 impl OAuth2Token {
 	/// Initializer
-	pub fn new(token: String) -> Self {
-		Self(token)
+	pub fn new(
+		access_token: String,
+		refresh_token: Option<String>,
+		expires_at: Option<DateTime<Utc>>,
+	) -> Self {
+		Self {
+			access_token,
+			refresh_token,
+			expires_at,
+		}
 	}
 
-	/// Try silentely read the cache file
-	pub fn from_cache() -> Option<Self> {
-		ProjectDirs::from("me", "IT Sufficient", "GlCtl")
-			.map(|dir| dir.cache_dir().join("oidc_login"))
-			.and_then(|path| {
-				File::open(path)
-					.ok()
-					.and_then(|file| serde_yaml::from_reader(file).ok())
-			})
+	/// Try silentely read the cache file of a host
+	pub fn from_cache(host: &str) -> Option<Self> {
+		cache_path(host).and_then(|path| {
+			File::open(path)
+				.ok()
+				.and_then(|file| serde_yaml::from_reader(file).ok())
+		})
 	}
 
 	/// Try to login
@@ -116,26 +823,44 @@ impl OAuth2Token {
 		login(host, config, opts)
 	}
 
-	/// Try to save the cache information to file
-	pub fn save(&self) -> Result<()> {
-		ProjectDirs::from("me", "IT Sufficient", "GlCtl")
-			.ok_or_else(|| anyhow!("Unable to find a suitable cache file path for oidc login"))
-			.map(|dir| dir.cache_dir().join("oidc_login"))
-			.and_then(|path| {
-				File::create(path)
-					.with_context(|| "Unable to open the cache file")
-					.and_then(|file| {
-						serde_yaml::to_writer(file, &self)
-							.with_context(|| "Unable to serialize oidc login informations")
-					})
-			})
+	/// Refresh token, if any
+	pub fn refresh_token(&self) -> Option<&String> {
+		self.refresh_token.as_ref()
+	}
+
+	/// Tell if the access token is expired or about to expire
+	pub fn is_expired(&self) -> bool {
+		self.expires_at
+			.map(|expires_at| expires_at <= Utc::now() + Duration::seconds(30))
+			.unwrap_or(false)
+	}
+
+	/// Try to save the cache information of a host to file. The file is only readable by the
+	/// current user as it contains the refresh token
+	pub fn save(&self, host: &str) -> Result<()> {
+		let path = cache_path(host)
+			.ok_or_else(|| anyhow!("Unable to find a suitable cache file path for oidc login"))?;
+		let file = create_private_file(&path).with_context(|| "Unable to open the cache file")?;
+		serde_yaml::to_writer(file, &self)
+			.with_context(|| "Unable to serialize oidc login informations")
+	}
+
+	/// Remove the cache file of a host. Return false if there was nothing to remove
+	pub fn remove(host: &str) -> Result<bool> {
+		match cache_path(host) {
+			Some(path) if path.exists() => {
+				remove_file(&path).with_context(|| format!("Unable to remove {:?}", &path))?;
+				Ok(true)
+			}
+			_ => Ok(false),
+		}
 	}
 }
 
 #[allow(clippy::from_over_into)]
 impl Into<String> for OAuth2Token {
 	fn into(self) -> String {
-		self.0
+		self.access_token
 	}
 }
 
@@ -143,6 +868,6 @@ impl Deref for OAuth2Token {
 	type Target = String;
 
 	fn deref(&self) -> &Self::Target {
-		&self.0
+		&self.access_token
 	}
 }
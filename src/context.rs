@@ -1,29 +1,42 @@
 use crate::{
+	api::personal_access_tokens::PersonalAccessToken,
 	args::{ColorChoice, Opts, PipelineLog},
+	client::{retry_delay, scope_list, GitlabClient},
 	color::{Style, StyledStr},
-	config::{AuthType, Config, OAuth2Token},
+	config::{AuthType, Config, OAuth2Token, Settings},
 	fmt::{Colorizer, Stream},
 	git::GitProject,
-	utils::{format_duration, take_from_vec},
+	oidc::refresh,
+	utils::{access_level_name, format_duration, is_commit_sha, take_from_vec},
 };
 
 use anyhow::{anyhow, bail, Context, Result};
 use chrono::Utc;
 use gitlab::{
 	api::{
+		self, groups,
 		projects::{
 			self,
 			jobs::JobScope,
-			pipelines,
-			repository::{branches, tags},
+			merge_requests, pipelines,
+			repository::{branches, commits, tags},
 		},
-		Query,
+		users, Endpoint, Pagination, Query, RestClient,
 	},
-	types, Gitlab, StatusState,
+	types, StatusState,
+};
+use reqwest::{blocking, header, StatusCode};
+use serde::Deserialize;
+use std::{
+	fs::{remove_file, rename, File, OpenOptions},
+	io, mem,
+	path::{Path, PathBuf},
+	str::FromStr,
+	thread,
 };
-use std::str::FromStr;
 
-fn status_style(status: StatusState) -> Option<Style> {
+/// Style to use for a pipeline or job status
+pub fn status_style(status: StatusState) -> Option<Style> {
 	Some(match status {
 		StatusState::Success | StatusState::Running => Style::Good,
 		StatusState::Canceled | StatusState::Failed => Style::Error,
@@ -144,6 +157,12 @@ impl LogContext {
 	}
 }
 
+/// Scopes of an access token
+#[derive(Deserialize)]
+struct TokenScopes {
+	scopes: Vec<String>,
+}
+
 /// Structure to pass around functions containing informations
 /// about execution context
 pub struct CliContext {
@@ -156,7 +175,9 @@ pub struct CliContext {
 	/// color mode
 	pub color: ColorChoice,
 	/// the gitlab connexion
-	pub gitlab: Gitlab,
+	pub gitlab: GitlabClient,
+	/// the authentication header used by the connexion
+	auth: (&'static str, String),
 	/// the configuration file
 	pub config: Config,
 	/// information about the current git repo
@@ -166,39 +187,106 @@ pub struct CliContext {
 impl CliContext {
 	/// Inializer from cli arguments
 	pub fn from_args(opts: &Opts) -> Result<Self> {
+		// get information from the CI job or from git
+		let mut repo = GitProject::detect(Config::remote(opts)?.as_deref());
+
 		// read yaml config
-		let config = Config::from_file(opts.config.as_ref(), opts.verbose)?;
+		let mut config = Config::from_file(
+			opts.config.as_ref(),
+			opts.host.as_ref(),
+			&repo.as_ref().map(GitProject::hosts).unwrap_or_default(),
+			opts.verbose,
+		)?
+		.with_token_override(opts.token_stdin, opts.verbose)?
+		.with_connection_override(opts);
+		if let Some(settings) = Settings::from_repo()? {
+			config.settings = mem::take(&mut config.settings).merge(settings);
+		}
 
-		// get information from git
-		let repo = GitProject::from_currentdir();
+		// the default project of the configuration takes precedence over the working copy,
+		// while the default reference is only used when the working copy gives none
+		if let Some(project) = &config.settings.project {
+			repo.get_or_insert_with(|| GitProject::from_name(project.to_owned()))
+				.name = Some(project.to_owned());
+		}
 
 		// connect to gitlab
-		let gitlab = match &config.auth {
+		let connect = |auth: (&'static str, String)| -> Result<_> {
+			let gitlab =
+				GitlabClient::new(&config.host, &config.connection, auth.clone())?.check()?;
+			Ok((gitlab, auth))
+		};
+		let (mut gitlab, auth) = match &config.auth {
 			AuthType::OAuth2(oauth2) => {
-				// try to get the token from cache
-				if let Some(token) = OAuth2Token::from_cache() {
-					// check if we can login with that
-					if let Ok(gitlab) = Gitlab::with_oauth2(&config.host, token) {
-						Ok(gitlab)
-					// otherwise try renew the token
+				let bearer = |token: OAuth2Token| {
+					let token: String = token.into();
+					("Authorization", format!("Bearer {}", token))
+				};
+				// try to get the token from cache, renewing it silently when expired
+				let cached = OAuth2Token::from_cache(&config.host).and_then(|token| {
+					if token.is_expired() {
+						refresh(&config.host, oauth2, &token, opts).ok()
 					} else {
-						let token = OAuth2Token::from_login(&config.host, oauth2, opts)?;
-						Gitlab::with_oauth2(&config.host, token)
+						Some(token)
 					}
+				});
+				if let Some(token) = cached {
+					// check if we can login with that, otherwise try renew the token
+					connect(bearer(token)).or_else(|_| {
+						let token = OAuth2Token::from_login(&config.host, oauth2, opts)?;
+						connect(bearer(token))
+					})
 				// otherwise try to login following the oauth2 flow
 				} else {
 					println!("Proceed to the login page https://{}", &config.host);
 					let token = crate::oidc::login(&config.host, oauth2, opts)?;
-					Gitlab::with_oauth2(&config.host, token)
+					connect(bearer(token))
 				}
 			}
 
-			AuthType::Token(token) => Gitlab::new(&config.host, token),
+			AuthType::Token(token) => connect(("PRIVATE-TOKEN", token.to_owned())),
+
+			// a job token can't query the current user
+			AuthType::JobToken(token) => {
+				let auth = ("JOB-TOKEN", token.to_owned());
+				GitlabClient::new(&config.host, &config.connection, auth.clone())
+					.map(|gitlab| (gitlab, auth))
+			}
 		}
 		.with_context(|| format!("Can't connect to {}", &config.host))?;
 
+		// get the scopes of the access token to warn before the requests that need more
+		let check_scopes = opts.check_scopes || config.settings.check_scopes.unwrap_or(false);
+		if check_scopes && matches!(config.auth, AuthType::Token(_)) {
+			let endpoint = PersonalAccessToken::builder().build()?;
+			let token: Result<TokenScopes, _> = endpoint.query(&gitlab);
+			match token {
+				Ok(TokenScopes { scopes }) => {
+					if !scopes
+						.iter()
+						.any(|scope| scope == "api" || scope == "read_api")
+					{
+						eprintln!(
+							"Warning: the commands need the api or read_api scope but the token \
+							 only has {}",
+							scope_list(&scopes)
+						);
+					}
+					gitlab.set_scopes(scopes);
+				}
+				Err(err) => {
+					if opts.verbose {
+						println!("Unable to get the scopes of the token: {}", err);
+					}
+				}
+			}
+		}
+
 		#[cfg(feature = "color")]
-		let color = opts.color;
+		let color = opts
+			.color
+			.or(config.settings.color)
+			.unwrap_or(ColorChoice::Auto);
 		#[cfg(not(feature = "color"))]
 		let color = ColorChoice::Never;
 
@@ -208,14 +296,133 @@ impl CliContext {
 			url: opts.url,
 			color,
 			gitlab,
+			auth,
 			config,
 			repo,
 		})
 	}
 
+	/// Username and password authenticating git over https with the token in use
+	pub fn git_credentials(&self) -> (&'static str, &str) {
+		match self.auth.0 {
+			"JOB-TOKEN" => ("gitlab-ci-token", &self.auth.1),
+			"Authorization" => ("oauth2", self.auth.1.trim_start_matches("Bearer ")),
+			// any username but an empty one is accepted with a personal access token
+			_ => ("oauth2", &self.auth.1),
+		}
+	}
+
+	/// Query an endpoint returning raw data as a stream, to avoid loading big responses
+	/// in memory
+	pub fn stream<E: Endpoint>(&self, endpoint: &E) -> Result<blocking::Response> {
+		let mut url = self.gitlab.rest_endpoint(&endpoint.endpoint())?;
+		endpoint.parameters().add_to_url(&mut url);
+		let rsp = self
+			.gitlab
+			.http()
+			.request(endpoint.method(), url.as_str())
+			.header(self.auth.0, &self.auth.1)
+			.send()?;
+		if !rsp.status().is_success() {
+			bail!("{} {}", rsp.status(), rsp.text().unwrap_or_default())
+		}
+		Ok(rsp)
+	}
+
+	/// Download the raw data of an endpoint to a file. The data is first written to a
+	/// .partial file, which is resumed on transient failures and by later calls when the
+	/// server supports range requests
+	pub fn download<E: Endpoint>(&self, endpoint: &E, path: &Path) -> Result<()> {
+		let partial = PathBuf::from(format!("{}.partial", path.display()));
+		let mut url = self.gitlab.rest_endpoint(&endpoint.endpoint())?;
+		endpoint.parameters().add_to_url(&mut url);
+		let client = self.gitlab.http();
+		let retries = self.config.connection.retries();
+
+		let mut attempt = 0;
+		loop {
+			// (transient, error)
+			let result: Result<(), (bool, anyhow::Error)> = (|| {
+				let (mut rsp, status) = loop {
+					let offset = partial.metadata().map(|meta| meta.len()).unwrap_or(0);
+					let mut request = client
+						.request(endpoint.method(), url.as_str())
+						.header(self.auth.0, &self.auth.1);
+					if offset > 0 {
+						request = request.header(header::RANGE, format!("bytes={}-", offset));
+					}
+					let rsp = request.send().map_err(|err| (true, err.into()))?;
+					let status = rsp.status();
+					if status != StatusCode::RANGE_NOT_SATISFIABLE || offset == 0 {
+						break (rsp, status);
+					}
+					// the partial file is already complete when its size is the one of the content
+					let length = rsp
+						.headers()
+						.get(header::CONTENT_RANGE)
+						.and_then(|range| range.to_str().ok())
+						.and_then(|range| range.strip_prefix("bytes */"))
+						.and_then(|length| length.parse::<u64>().ok());
+					if length == Some(offset) {
+						return Ok(());
+					}
+					// otherwise it is not part of the content anymore and we start over
+					remove_file(&partial)
+						.with_context(|| format!("Can't remove {}", partial.display()))
+						.map_err(|err| (false, err))?;
+				};
+				if !status.is_success() {
+					let transient =
+						status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS;
+					return Err((
+						transient,
+						anyhow!("{} {}", status, rsp.text().unwrap_or_default()),
+					));
+				}
+				// start over when the server ignores the range
+				let mut file = if status == StatusCode::PARTIAL_CONTENT {
+					OpenOptions::new().append(true).open(&partial)
+				} else {
+					File::create(&partial)
+				}
+				.with_context(|| format!("Can't write {}", partial.display()))
+				.map_err(|err| (false, err))?;
+				io::copy(&mut rsp, &mut file).map_err(|err| (true, err.into()))?;
+				Ok(())
+			})();
+
+			match result {
+				Ok(()) => break,
+				Err((true, err)) if attempt < retries => {
+					attempt += 1;
+					eprintln!(
+						"Download of {} interrupted ({}). Retrying ({}/{})",
+						path.display(),
+						err,
+						attempt,
+						retries
+					);
+					thread::sleep(retry_delay(attempt));
+				}
+				Err((_, err)) => return Err(err),
+			}
+		}
+		rename(&partial, path)
+			.with_context(|| format!("Can't rename {} to {}", partial.display(), path.display()))
+	}
+
 	/// Get a project (which can be the one provided or a default one)
 	pub fn get_project<'a>(&'a self, default: Option<&'a String>) -> Result<types::Project> {
-		let id = default.or_else(|| self.repo.as_ref().and_then(|repo| repo.name.as_ref()));
+		// the remote giving the project is only chosen when needed
+		let id = match default {
+			Some(id) => Some(id),
+			None => self
+				.repo
+				.as_ref()
+				.map(|repo| repo.project_name(&self.config.host))
+				.transpose()?
+				.flatten(),
+		};
 		if let Some(id) = id {
 			projects::Project::builder()
 				.project(id.as_str())
@@ -223,8 +430,83 @@ impl CliContext {
 				.query(&self.gitlab)
 				.with_context(|| format!("Can't find a project named {}", id))
 		} else {
-			bail!("Can't find a project name. Specify one manually on the command line")
+			bail!(
+				"Can't find a project name. Specify one on the command line, set a default project \
+				 in the configuration or run the command inside a clone of the project"
+			)
+		}
+	}
+
+	/// Run a command against every project of a group (and its subgroups) as if it was
+	/// launched from each project repository, and print a summary of the results
+	pub fn for_each_project<F>(&mut self, group: &str, mut f: F) -> Result<()>
+	where
+		F: FnMut(&Self) -> Result<()>,
+	{
+		let endpoint = groups::projects::GroupProjects::builder()
+			.group(group)
+			.include_subgroups(true)
+			.archived(false)
+			.build()?;
+		let mut projects: Vec<types::Project> = api::paged(endpoint, Pagination::All)
+			.query(&self.gitlab)
+			.with_context(|| format!("Failed to list projects of group {}", group))?;
+		projects.sort_by(|a, b| a.path_with_namespace.cmp(&b.path_with_namespace));
+
+		let mut results = Vec::new();
+		for project in projects.into_iter() {
+			if self.verbose {
+				println!("# {}", &project.path_with_namespace);
+			}
+			self.repo = Some(GitProject::from_name(project.path_with_namespace.clone()));
+			results.push((project.path_with_namespace, f(self)));
+		}
+		self.repo = None;
+
+		let mut msg = StyledStr::new();
+		if results.is_empty() {
+			msg.none(format!("No projects found in group {}\n", group));
+			return self.print_msg(msg);
+		}
+		let width = results
+			.iter()
+			.map(|(path, _)| path.len())
+			.max()
+			.unwrap_or_default();
+		msg.none("\n");
+		let mut failures = 0;
+		for (path, result) in results.iter() {
+			msg.literal(format!("{:<width$}", path));
+			match result {
+				Ok(_) => msg.good("  ok"),
+				Err(err) => {
+					failures += 1;
+					msg.error("  failed");
+					msg.none(format!(": {:#}", err));
+				}
+			}
+			msg.none("\n");
+		}
+		self.print_msg(msg)?;
+
+		if failures != 0 {
+			bail!(
+				"The command failed on {} of {} projects",
+				failures,
+				results.len()
+			)
 		}
+		Ok(())
+	}
+
+	/// Get a user by its username
+	pub fn get_user(&self, username: &str) -> Result<types::UserBasic> {
+		let users: Vec<types::UserBasic> = users::Users::builder()
+			.username(username)
+			.build()?
+			.query(&self.gitlab)
+			.with_context(|| format!("Failed to find user {}", username))?;
+		take_from_vec(users, 0).ok_or_else(|| anyhow!("Can't find a user named {}", username))
 	}
 
 	/// Get a tag (which can be the one provided or a default one) for the given project
@@ -233,7 +515,9 @@ impl CliContext {
 		default: Option<&String>,
 		project: &types::Project,
 	) -> Result<types::Tag> {
-		let tag = default.or_else(|| self.repo.as_ref().and_then(|repo| repo.tag.as_ref()));
+		let tag = default
+			.or_else(|| self.repo.as_ref().and_then(|repo| repo.tag.as_ref()))
+			.or(self.default_ref());
 		if let Some(tag) = tag {
 			tags::Tag::builder()
 				.project(project.path_with_namespace.as_str())
@@ -260,7 +544,9 @@ impl CliContext {
 		default: Option<&String>,
 		project: &types::Project,
 	) -> Result<types::RepoBranch> {
-		let branch = default.or_else(|| self.repo.as_ref().and_then(|repo| repo.branch.as_ref()));
+		let branch = default
+			.or_else(|| self.repo.as_ref().and_then(|repo| repo.branch.as_ref()))
+			.or(self.default_ref());
 		if let Some(branch) = branch {
 			branches::Branch::builder()
 				.project(project.path_with_namespace.as_str())
@@ -281,10 +567,26 @@ impl CliContext {
 		}
 	}
 
+	/// Get the commit a reference (tag, branch or commit sha) of the given project points to
+	pub fn get_commit(&self, ref_: &String, project: &types::Project) -> Result<types::RepoCommit> {
+		commits::Commit::builder()
+			.project(project.path_with_namespace.as_str())
+			.commit(ref_)
+			.build()?
+			.query(&self.gitlab)
+			.with_context(|| {
+				format!(
+					"Can't find a commit for {} in project {}",
+					ref_, &project.path_with_namespace
+				)
+			})
+	}
+
 	/// Returns the provided tag name (default) or the one extracted from the repo
 	pub fn get_tagexp<'a>(&'a self, default: Option<&'a String>) -> Result<&'a String> {
 		default
 			.or_else(|| self.repo.as_ref().and_then(|repo| repo.tag.as_ref()))
+			.or(self.default_ref())
 			.ok_or_else(|| {
 				anyhow!("Can't find a project tag. Specify one manually on the command line")
 			})
@@ -294,26 +596,81 @@ impl CliContext {
 	pub fn get_branchexp<'a>(&'a self, default: Option<&'a String>) -> Result<&'a String> {
 		default
 			.or_else(|| self.repo.as_ref().and_then(|repo| repo.branch.as_ref()))
+			.or(self.default_ref())
 			.ok_or_else(|| {
 				anyhow!("Can't find a project branch. Specify one manually on the command line")
 			})
 	}
 
+	/// Get the name of a tag or a branch of the given project
+	fn find_ref(&self, ref_: &String, project: &types::Project) -> Result<String> {
+		self.get_tag(Some(ref_), project)
+			.map(|tag| tag.name)
+			.or_else(|_| {
+				self.get_branch(Some(ref_), project)
+					.map(|branch| branch.name)
+			})
+			.with_context(|| {
+				format!(
+					"Can't find a tag or a branch {} in project {}",
+					ref_, &project.path_with_namespace
+				)
+			})
+	}
+
 	/// Get a reference (which can be the one provided or a default one) for the given project
-	/// checking that it is tag or a branch name
+	/// checking that it is tag or a branch name. The default is a tag pointing to the head of
+	/// the working copy, the current branch, the default reference of the configuration, or
+	/// the head commit when it is detached
 	pub fn get_ref(&self, ref_: Option<&String>, project: &types::Project) -> Result<String> {
-		// get a reference (a tag or a branch)
-		self.get_tag(ref_, project)
-			.map(|tag| tag.name)
-				.or_else(|_| {
-					self
-						// get branch from the context
-						.get_branch(None, project)
-						.map(|branch| branch.name)
-				})
-				.with_context(|| {
-					anyhow!("Failed to find a suitable reference for project {} to build the pipeline upon.", &project.name_with_namespace)
-				})
+		if let Some(ref_) = ref_ {
+			return self.find_ref(ref_, project);
+		}
+
+		let repo = self.repo.as_ref();
+		let (kind, origin, ref_) = if let Some(tag) = repo
+			.and_then(|repo| repo.tag.as_ref())
+			.and_then(|tag| self.get_tag(Some(tag), project).ok())
+		{
+			("tag", "working copy", tag.name)
+		} else if let Some(branch) = repo
+			.and_then(|repo| repo.branch.as_ref())
+			.and_then(|branch| self.get_branch(Some(branch), project).ok())
+		{
+			("branch", "working copy", branch.name)
+		} else if let Some(ref_) = self
+			.default_ref()
+			.filter(|_| repo.is_none_or(|repo| repo.tag.is_none() && repo.branch.is_none()))
+		{
+			(
+				"default reference",
+				"configuration",
+				self.find_ref(ref_, project)?,
+			)
+		} else if let Some(commit) = repo
+			.filter(|repo| repo.branch.is_none())
+			.and_then(|repo| repo.commit.as_ref())
+		{
+			// a detached head
+			("commit", "working copy", commit.to_owned())
+		} else {
+			bail!(
+				"Failed to find a suitable reference for project {}. Specify one with --ref",
+				&project.path_with_namespace
+			)
+		};
+
+		// tell what was inferred, as the reference is not visible on the command line
+		eprintln!(
+			"Using the {} {} of the {}. Specify another reference with --ref",
+			kind, &ref_, origin
+		);
+		Ok(ref_)
+	}
+
+	/// The default reference of the configuration, used when the working copy gives none
+	fn default_ref(&self) -> Option<&String> {
+		self.config.settings.ref_.as_ref()
 	}
 
 	/// Get a reference but returns an Err if the given reference has diverged
@@ -353,10 +710,15 @@ impl CliContext {
 			})?;
 			Ok(pipeline)
 		} else {
-			let endpoint = pipelines::Pipelines::builder()
-				.project(project.path_with_namespace.as_str())
-				.ref_(ref_)
-				.build()?;
+			let mut endpoint = pipelines::Pipelines::builder();
+			endpoint.project(project.path_with_namespace.as_str());
+			// a commit is not a reference for gitlab
+			if is_commit_sha(ref_) {
+				endpoint.sha(ref_.as_str());
+			} else {
+				endpoint.ref_(ref_.as_str());
+			}
+			let endpoint = endpoint.build()?;
 			let pipelines: Vec<_> = endpoint.query(&self.gitlab).with_context(|| {
 				format!(
 					"Failed to list pipeline for {} @ {}",
@@ -374,6 +736,70 @@ impl CliContext {
 		}
 	}
 
+	/// Get the open merge request of the current branch for the given project. Only merge
+	/// requests targeting the project itself are found, not the ones of a fork targeting its
+	/// upstream project which must then be given by iid with the upstream project
+	pub fn get_current_mr(&self, project: &types::Project) -> Result<types::MergeRequest> {
+		let branch = self
+			.repo
+			.as_ref()
+			.and_then(|repo| repo.branch.as_ref())
+			.ok_or_else(|| {
+				anyhow!(
+					"Can't find the current branch. Specify a merge request manually on the \
+					 command line"
+				)
+			})?;
+		let endpoint = merge_requests::MergeRequests::builder()
+			.project(project.path_with_namespace.as_str())
+			.source_branch(branch.as_str())
+			.state(merge_requests::MergeRequestState::Opened)
+			.build()?;
+		let mrs: Vec<types::MergeRequest> = endpoint.query(&self.gitlab).with_context(|| {
+			format!(
+				"Failed to list merge requests of {}",
+				&project.path_with_namespace
+			)
+		})?;
+		// a branch of a fork can have the same name
+		let mr = mrs
+			.into_iter()
+			.find(|mr| mr.source_project_id == Some(project.id) && &mr.source_branch == branch)
+			.ok_or_else(|| {
+				anyhow!(
+					"Can't find an open merge request for branch {} of project {}",
+					branch,
+					&project.path_with_namespace
+				)
+			})?;
+		// the listing doesn't give the pipeline of the merge request
+		self.get_mr(Some(mr.iid.value()), project)
+	}
+
+	/// Returns the merge request with the provided iid (default) or the open merge request of
+	/// the current branch
+	pub fn get_mr(
+		&self,
+		default: Option<u64>,
+		project: &types::Project,
+	) -> Result<types::MergeRequest> {
+		if let Some(iid) = default {
+			merge_requests::MergeRequest::builder()
+				.project(project.path_with_namespace.as_str())
+				.merge_request(iid)
+				.build()?
+				.query(&self.gitlab)
+				.with_context(|| {
+					format!(
+						"Can't find merge request !{} in project {}",
+						iid, &project.path_with_namespace
+					)
+				})
+		} else {
+			self.get_current_mr(project)
+		}
+	}
+
 	/// Returns the job with the provived id (default) or the first job of the last pipeline for the a given
 	/// project and tag or raises an error
 	pub fn get_job<I>(
@@ -646,6 +1072,29 @@ impl CliContext {
 		self.print_msg(msg)
 	}
 
+	/// Print a merge request
+	pub fn print_mr(&self, mr: &types::MergeRequest, project: &types::Project) -> Result<()> {
+		let mut msg = StyledStr::new();
+		msg.none("Merge request ");
+		msg.literal(format!("!{}", mr.iid.value()));
+		msg.none(format!(
+			" '{}' ({} : {} -> {})",
+			mr.title, &project.name_with_namespace, mr.source_branch, mr.target_branch
+		));
+		msg.none(" [");
+		msg.literal(timeago::Formatter::new().convert_chrono(mr.updated_at, Utc::now()));
+		msg.none("] - ");
+		msg.literal(format!("{:?}", mr.state));
+		if mr.has_conflicts {
+			msg.error(" (conflicts)");
+		}
+		if self.url {
+			msg.hint(format!(" ({})", mr.web_url));
+		}
+		msg.none("\n");
+		self.print_msg(msg)
+	}
+
 	/// Print pipelines list
 	pub fn print_pipelines(
 		&self,
@@ -701,6 +1150,80 @@ impl CliContext {
 		self.print_msg(msg)
 	}
 
+	/// Print projects list
+	pub fn print_projects(&self, projects: &[types::Project]) -> Result<()> {
+		let mut msg = StyledStr::new();
+		if projects.is_empty() {
+			msg.none("No projects found\n");
+		}
+		for project in projects.iter() {
+			msg.none("- ");
+			msg.literal(&project.path_with_namespace);
+			msg.none(format!(" ({:?})", project.visibility));
+			if let Some(branch) = &project.default_branch {
+				msg.none(" @ ");
+				msg.literal(branch);
+			}
+			msg.none(" [");
+			msg.literal(
+				timeago::Formatter::new().convert_chrono(project.last_activity_at, Utc::now()),
+			);
+			msg.none("]");
+			if self.url {
+				msg.hint(format!(" ({})", project.web_url));
+			}
+			msg.none("\n");
+		}
+		self.print_msg(msg)
+	}
+
+	/// Print a table of members with their membership kind (direct or inherited)
+	pub fn print_members(&self, members: &[(types::Member, bool)]) -> Result<()> {
+		let mut msg = StyledStr::new();
+		if members.is_empty() {
+			msg.none("No members found\n");
+			return self.print_msg(msg);
+		}
+		let username_width = members
+			.iter()
+			.map(|(member, _)| member.username.len())
+			.max()
+			.unwrap_or_default()
+			.max("USERNAME".len());
+		let name_width = members
+			.iter()
+			.map(|(member, _)| member.name.chars().count())
+			.max()
+			.unwrap_or_default()
+			.max("NAME".len());
+		msg.hint(format!(
+			"{:<username_width$}  {:<name_width$}  {:<10}  {:<10}  MEMBERSHIP\n",
+			"USERNAME", "NAME", "ACCESS", "EXPIRES"
+		));
+		for (member, inherited) in members.iter() {
+			msg.literal(format!("{:<username_width$}", member.username));
+			msg.none(format!(
+				"  {:<name_width$}  {:<10}  {:<10}  ",
+				member.name,
+				access_level_name(member.access_level),
+				member
+					.expires_at
+					.map(|date| date.to_string())
+					.unwrap_or_else(|| "-".to_owned())
+			));
+			if *inherited {
+				msg.hint("inherited");
+			} else {
+				msg.good("direct");
+			}
+			if self.url {
+				msg.hint(format!(" ({})", member.web_url));
+			}
+			msg.none("\n");
+		}
+		self.print_msg(msg)
+	}
+
 	// Print project header
 	pub fn print_project(&self, project: &types::Project, ref_: &String) -> Result<()> {
 		let mut msg = StyledStr::new();
@@ -1,12 +1,17 @@
-use git_repository::{commit::describe::SelectRef, discover, remote::Direction};
+use anyhow::{bail, Context, Result};
+use git_repository::{discover, remote::Direction, Remote};
 use semver::Version;
-use std::env::current_dir;
+use std::{
+	cell::OnceCell,
+	env::{self, current_dir},
+	io,
+};
 
 #[derive(Debug)]
 pub struct GitProject {
-	/// project path
+	/// project path when known without the git remotes (CI job or configuration)
 	pub name: Option<String>,
-	/// remote host
+	/// remote host when known without the git remotes (CI job)
 	pub host: Option<String>,
 	/// remote branch
 	pub branch: Option<String>,
@@ -14,10 +19,129 @@ pub struct GitProject {
 	pub tag: Option<String>,
 	// commit
 	pub commit: Option<String>,
+	/// remotes of the working copy
+	remotes: Vec<GitRemote>,
+	/// name of the remote of the current branch
+	upstream: Option<String>,
+	/// name of the remote given on the command line or in the configuration
+	remote: Option<String>,
+	/// project path of the remote chosen the first time it is needed
+	chosen: OnceCell<Option<String>>,
+}
+
+/// A remote of the working copy
+#[derive(Debug)]
+struct GitRemote {
+	name: String,
+	host: Option<String>,
+	path: Option<String>,
+}
+
+/// Project path of a remote url path, without the leading and trailing / and the .git suffix
+fn project_path(path: &str) -> Option<String> {
+	let path = path.trim_matches('/');
+	let path = path.strip_suffix(".git").unwrap_or(path);
+	// a gitlab project is always inside a namespace
+	path.contains('/').then(|| path.to_owned())
+}
+
+/// Host and project path of a remote, from its url (https://host/group/project.git,
+/// ssh://git@host:port/group/project.git or git@host:group/project.git)
+fn remote_project(remote: &Remote) -> (Option<String>, Option<String>) {
+	remote
+		.url(Direction::Push)
+		.map(|url| {
+			(
+				url.host().map(|host| host.to_owned()),
+				project_path(&url.path.to_string()),
+			)
+		})
+		.unwrap_or_default()
+}
+
+/// Ask which remote to use among the ones designating different projects
+fn ask_remote<'a>(remotes: &[&'a GitRemote], default: usize) -> Option<&'a GitRemote> {
+	eprintln!("Several git remotes designate different projects:");
+	for (i, remote) in remotes.iter().enumerate() {
+		eprintln!(
+			"  {}. {} ({}/{})",
+			i + 1,
+			&remote.name,
+			remote.host.as_deref().unwrap_or_default(),
+			remote.path.as_deref().unwrap_or_default()
+		);
+	}
+	loop {
+		eprint!(
+			"Remote to use (set remote in .glctl.yaml or use --remote to avoid this question) \
+			 [{}]: ",
+			&remotes[default].name
+		);
+		let mut answer = String::new();
+		// the default remote is used at the end of the input
+		if io::stdin().read_line(&mut answer).unwrap_or(0) == 0 {
+			return Some(remotes[default]);
+		}
+		let answer = answer.trim();
+		let index = if answer.is_empty() {
+			Some(default)
+		} else {
+			answer
+				.parse::<usize>()
+				.ok()
+				.and_then(|i| i.checked_sub(1))
+				.filter(|i| *i < remotes.len())
+				.or_else(|| remotes.iter().position(|remote| remote.name == answer))
+		};
+		match index {
+			Some(i) => return Some(remotes[i]),
+			None => eprintln!("Unknown remote {}", answer),
+		}
+	}
 }
 
 impl GitProject {
-	pub fn from_currentdir() -> Option<Self> {
+	/// A project known only by its path (without any local repository information)
+	pub fn from_name(name: String) -> Self {
+		GitProject {
+			name: Some(name),
+			host: None,
+			branch: None,
+			tag: None,
+			commit: None,
+			remotes: Vec::new(),
+			upstream: None,
+			remote: None,
+			chosen: OnceCell::new(),
+		}
+	}
+
+	/// A project known from the predefined variables of a CI job
+	pub fn from_ci() -> Option<Self> {
+		env::var("CI_PROJECT_PATH").ok().map(|name| GitProject {
+			name: Some(name),
+			host: env::var("CI_SERVER_HOST").ok(),
+			// the branch isn't set in merge request pipelines
+			branch: env::var("CI_COMMIT_BRANCH")
+				.or_else(|_| env::var("CI_MERGE_REQUEST_SOURCE_BRANCH_NAME"))
+				.ok(),
+			tag: env::var("CI_COMMIT_TAG").ok(),
+			commit: env::var("CI_COMMIT_SHA").ok(),
+			remotes: Vec::new(),
+			upstream: None,
+			remote: None,
+			chosen: OnceCell::new(),
+		})
+	}
+
+	/// A project known from the CI job or else from the working copy
+	pub fn detect(remote: Option<&str>) -> Option<Self> {
+		Self::from_ci().or_else(|| Self::from_currentdir(remote))
+	}
+
+	/// A project known from the git repository of the working directory. The remote giving
+	/// the project is chosen only when the project is needed
+	pub fn from_currentdir(remote: Option<&str>) -> Option<Self> {
 		if let Some(repo) = current_dir().ok().and_then(|dir| discover(dir).ok()) {
 			// get the head id
 			let commit = repo.head_id().ok().map(|id| id.to_hex().to_string());
@@ -27,87 +151,158 @@ impl GitProject {
 				.ok()
 				.flatten()
 				.map(|head| head.shorten().to_string());
-			// find the remote associated to the remote branch
-			let remote = branch
+			// the remote associated to the remote branch
+			let upstream = branch
 				.as_ref()
 				.and_then(|branch| repo.branch_remote_name(branch))
-				.map(|branch_remote| branch_remote.to_string())
-				.and_then(|branch_remote| repo.find_remote(&branch_remote).ok());
-
-			// get the host form the remote url
-			let host = remote
-				.as_ref()
-				.and_then(|remote| remote.url(Direction::Push))
-				.and_then(|url| url.host().map(|host| host.to_owned()));
-
-			// get the project name from the remote url
-			let name = remote
-				.as_ref()
-				.and_then(|remote| remote.url(Direction::Push))
-				.map(|url| url.path.to_string())
-				.as_ref()
-				// strip the leading / and the .git prefix
-				.and_then(|path| {
-					path.strip_prefix('/')
-						.or(Some(path))
-						.and_then(|path| path.strip_suffix(".git").or(Some(path)))
+				.map(|branch_remote| branch_remote.to_string());
+			let remotes = repo
+				.remote_names()
+				.into_iter()
+				.filter_map(|name| {
+					repo.find_remote(name).ok().map(|remote| {
+						let (host, path) = remote_project(&remote);
+						GitRemote {
+							name: name.to_owned(),
+							host,
+							path,
+						}
+					})
 				})
-				.map(|path| path.to_owned());
+				.collect();
 
-			// try to get the greatest semver tag that is pointing to the head commit
+			// get the tags pointing to the head commit, a tag reached with describe would
+			// designate an older commit
 			let head_commit = repo.head_commit().unwrap();
-			let tag = repo
+			let tags = repo
 				// get iterator for all references
 				.references()
 				.ok()
 				.and_then(|platform| {
 					// browse all repo tags
 					platform.tags().ok().map(|tags| {
-						// an filter the ones
 						tags.filter_map(|r| {
 							r.ok()
-								// that are pointing to head commit
+								// and keep the ones that are pointing to head commit
 								.and_then(|r| {
 									(r.id() == head_commit.id)
 										.then_some(r.name().file_name().to_string())
 								})
-								// and that can be parsed to semver
-								.and_then(|tag| Version::parse(&tag).ok())
 						})
-						.collect::<Vec<Version>>()
+						.collect::<Vec<String>>()
 					})
 				})
-				// get the latest semver tag
-				.and_then(|mut tags| {
-					tags.sort();
-					tags.pop().map(|version| version.to_string())
-				});
-
-			// if this is not working then get the latest tag with describe
-			let tag = tag.or_else(|| {
-				repo.head_commit().ok().and_then(|commit| {
-					commit
-						.describe()
-						.names(SelectRef::AllTags)
-						.max_candidates(1)
-						.traverse_first_parent(true)
-						.try_format()
-						.ok()
-						.flatten()
-						.and_then(|format| format.name)
-						.map(|name| name.to_string())
-				})
-			});
+				.unwrap_or_default();
+
+			// in case several tags are pointing to the commit, get the greatest semver tag
+			let tag = tags
+				.iter()
+				.filter_map(|tag| Version::parse(tag).ok())
+				.max()
+				.map(|version| version.to_string())
+				// or the first tag found
+				.or_else(|| tags.into_iter().next());
 
 			Some(GitProject {
-				name,
-				host,
+				name: None,
+				host: None,
 				branch,
 				tag,
 				commit,
+				remotes,
+				upstream,
+				remote: remote.map(str::to_owned),
+				chosen: OnceCell::new(),
 			})
 		} else {
 			None
 		}
 	}
+
+	/// Hosts of the project to select a GitLab host of the configuration with, in order of
+	/// preference: the named remote, the remote of the current branch, origin and the others
+	pub fn hosts(&self) -> Vec<&String> {
+		let mut remotes: Vec<&GitRemote> = self.remotes.iter().collect();
+		remotes.sort_by_key(|remote| {
+			if Some(&remote.name) == self.remote.as_ref() {
+				0
+			} else if Some(&remote.name) == self.upstream.as_ref() {
+				1
+			} else if remote.name == "origin" {
+				2
+			} else {
+				3
+			}
+		});
+		self.host
+			.iter()
+			.chain(
+				remotes
+					.into_iter()
+					.filter_map(|remote| remote.host.as_ref()),
+			)
+			.collect()
+	}
+
+	/// Path of the project, given by the CI job or the configuration, or else by a remote of
+	/// the working copy on the GitLab host: the named one, the one of the current branch, or the
+	/// only one designating a project. When several remotes designate different projects, ask
+	/// which one to use if the terminal is interactive, otherwise use origin
+	pub fn project_name(&self, gitlab_host: &str) -> Result<Option<&String>> {
+		if let Some(name) = &self.name {
+			return Ok(Some(name));
+		}
+		if let Some(chosen) = self.chosen.get() {
+			return Ok(chosen.as_ref());
+		}
+
+		let chosen = if let Some(name) = &self.remote {
+			self.remotes
+				.iter()
+				.find(|remote| &remote.name == name)
+				.with_context(|| format!("Can't find a git remote named {}", name))?
+				.path
+				.clone()
+		} else {
+			// ignore the remotes of other hosts, unless none is on the GitLab host as it can be
+			// an alias of the ssh configuration
+			let mut remotes: Vec<&GitRemote> = self
+				.remotes
+				.iter()
+				.filter(|remote| remote.path.is_some())
+				.filter(|remote| remote.host.as_deref() == Some(gitlab_host))
+				.collect();
+			if remotes.is_empty() {
+				remotes = self
+					.remotes
+					.iter()
+					.filter(|remote| remote.path.is_some())
+					.collect();
+			}
+			let mut projects: Vec<_> = remotes.iter().map(|remote| &remote.path).collect();
+			projects.sort();
+			projects.dedup();
+			let origin = remotes.iter().position(|remote| remote.name == "origin");
+
+			let remote = if let Some(upstream) = remotes
+				.iter()
+				.find(|remote| Some(&remote.name) == self.upstream.as_ref())
+			{
+				Some(*upstream)
+			} else if projects.len() < 2 {
+				remotes.first().copied()
+			} else if atty::is(atty::Stream::Stdin) && atty::is(atty::Stream::Stderr) {
+				ask_remote(&remotes, origin.unwrap_or(0))
+			} else if let Some(origin) = origin {
+				Some(remotes[origin])
+			} else {
+				bail!(
+					"Several git remotes designate different projects. Choose one with --remote \
+					 or set remote in .glctl.yaml"
+				)
+			};
+			remote.and_then(|remote| remote.path.clone())
+		};
+		Ok(self.chosen.get_or_init(|| chosen).as_ref())
+	}
 }
@@ -1,19 +1,57 @@
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fs::File;
 use std::ops::{Deref, DerefMut};
 use std::path::Path;
 
+/// Commit an archive of a project has been extracted from
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum LockEntry {
+	/// commit alone, as written by older versions
+	Commit(String),
+	/// commit with the reference it was resolved from
+	Ref {
+		#[serde(rename = "ref")]
+		ref_: String,
+		commit: String,
+	},
+}
+
+impl LockEntry {
+	pub fn new(ref_: &str, commit: &str) -> Self {
+		Self::Ref {
+			ref_: ref_.to_owned(),
+			commit: commit.to_owned(),
+		}
+	}
+
+	/// the reference the commit was resolved from, if known
+	pub fn ref_(&self) -> Option<&str> {
+		match self {
+			Self::Commit(_) => None,
+			Self::Ref { ref_, .. } => Some(ref_),
+		}
+	}
+
+	pub fn commit(&self) -> &str {
+		match self {
+			Self::Commit(commit) | Self::Ref { commit, .. } => commit,
+		}
+	}
+}
+
 pub struct LockFile {
 	name: String,
-	commits: BTreeMap<String, String>,
+	commits: BTreeMap<String, LockEntry>,
 }
 
 impl LockFile {
 	pub fn open(name: &str) -> Result<Self> {
 		// open lock file
 		let lock = Path::new(&name).with_extension("lock");
-		let commits: BTreeMap<String, String> = if let Ok(file) = File::open(&lock) {
+		let commits: BTreeMap<String, LockEntry> = if let Ok(file) = File::open(&lock) {
 			// deserialize lock
 			serde_yaml::from_reader(file).with_context(|| format!("Can't read {:?}", &lock))?
 		} else {
@@ -40,7 +78,7 @@ impl LockFile {
 }
 
 impl Deref for LockFile {
-	type Target = BTreeMap<String, String>;
+	type Target = BTreeMap<String, LockEntry>;
 
 	fn deref(&self) -> &Self::Target {
 		&self.commits
@@ -1,5 +1,7 @@
+mod api;
 mod archive;
 mod args;
+mod client;
 mod cmd;
 mod color;
 mod config;
@@ -13,25 +15,80 @@ mod utils;
 use crate::{
 	args::{Opts, SubCommand},
 	cmd::{
-		archive::cmd as archive, branches::cmd as branches, pipeline::cmd as pipeline,
-		project::cmd as project, tags::cmd as tags,
+		access_requests::cmd as access_requests, admin::cmd as admin, alias::cmd as alias,
+		archive::cmd as archive, audit::cmd as audit, auth::cmd as auth, badge::cmd as badge,
+		blame::cmd as blame, branches::cmd as branches, ci::cmd as ci, clone::cmd as clone,
+		commits::cmd as commits, config::cmd as config, dep_proxy::cmd as dep_proxy,
+		env::cmd as env, feature_flags::cmd as feature_flags, file::cmd as file,
+		freeze::cmd as freeze, group::cmd as group, issue::cmd as issue,
+		job_token_allowlist::cmd as job_token_allowlist, mr::cmd as mr, package::cmd as package,
+		pages::cmd as pages, pipeline::cmd as pipeline, project::cmd as project,
+		registry::cmd as registry, runner::cmd as runner, search::cmd as search,
+		secure_files::cmd as secure_files, tags::cmd as tags, tf_state::cmd as tf_state,
+		todo::cmd as todo, token::cmd as token, tree::cmd as tree, variables::cmd as variables,
 	},
 	context::CliContext,
 };
 
 use anyhow::Result;
 
+/// Run a subcommand
+fn run(context: &CliContext, cmd: &SubCommand) -> Result<()> {
+	match cmd {
+		SubCommand::Tags(args) => tags(context, args),
+		SubCommand::Build(args) => pipeline(context, args),
+		SubCommand::Archive(args) => archive(context, args),
+		SubCommand::Project(args) => project(context, args),
+		SubCommand::Branches(args) => branches(context, args),
+		SubCommand::Todo(args) => todo(context, args),
+		SubCommand::Issue(args) => issue(context, args),
+		SubCommand::Mr(args) => mr(context, args),
+		SubCommand::Group(args) => group(context, args),
+		SubCommand::Badge(args) => badge(context, args),
+		SubCommand::AccessRequests(args) => access_requests(context, args),
+		SubCommand::Audit(args) => audit(context, args),
+		SubCommand::Variables(args) => variables(context, args),
+		SubCommand::Ci(args) => ci(context, args),
+		SubCommand::Env(args) => env(context, args),
+		SubCommand::Freeze(args) => freeze(context, args),
+		SubCommand::FeatureFlags(args) => feature_flags(context, args),
+		SubCommand::JobTokenAllowlist(args) => job_token_allowlist(context, args),
+		SubCommand::SecureFiles(args) => secure_files(context, args),
+		SubCommand::Runner(args) => runner(context, args),
+		SubCommand::Registry(args) => registry(context, args),
+		SubCommand::Package(args) => package(context, args),
+		SubCommand::DepProxy(args) => dep_proxy(context, args),
+		SubCommand::TfState(args) => tf_state(context, args),
+		SubCommand::Pages(args) => pages(context, args),
+		SubCommand::File(args) => file(context, args),
+		SubCommand::Tree(args) => tree(context, args),
+		SubCommand::Commits(args) => commits(context, args),
+		SubCommand::Blame(args) => blame(context, args),
+		SubCommand::Search(args) => search(context, args),
+		SubCommand::Clone(args) => clone(context, args),
+		SubCommand::Token(args) => token(context, args),
+		SubCommand::Admin(args) => admin(context, args),
+		// run before connecting to gitlab
+		SubCommand::Auth(_) | SubCommand::Config(_) | SubCommand::Alias(_) => unreachable!(),
+	}
+}
+
 fn main() -> Result<()> {
 	// parse command line arguments
 	let opts: Opts = args::from_env();
+	// authentication and configuration don't need a connection
+	match &opts.cmd {
+		SubCommand::Auth(args) => return auth(&opts, args),
+		SubCommand::Config(args) => return config(&opts, args),
+		SubCommand::Alias(args) => return alias(&opts, args),
+		_ => (),
+	}
 	// construct context
-	let context = CliContext::from_args(&opts)?;
+	let mut context = CliContext::from_args(&opts)?;
 
-	match &opts.cmd {
-		SubCommand::Tags(args) => tags(&context, args),
-		SubCommand::Build(args) => pipeline(&context, args),
-		SubCommand::Archive(args) => archive(&context, args),
-		SubCommand::Project(args) => project(&context, args),
-		SubCommand::Branches(args) => branches(&context, args),
+	if let Some(group) = &opts.each_project_in {
+		context.for_each_project(group, |context| run(context, &opts.cmd))
+	} else {
+		run(&context, &opts.cmd)
 	}
 }
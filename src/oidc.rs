@@ -4,13 +4,16 @@ use crate::{
 };
 
 use anyhow::{anyhow, bail, Context, Result};
+use chrono::{Duration, Utc};
 use indoc::formatdoc;
 use openidconnect::url::Url;
 use openidconnect::{
-	core::{CoreClient, CoreIdTokenVerifier, CoreProviderMetadata, CoreResponseType},
+	core::{
+		CoreClient, CoreIdTokenVerifier, CoreProviderMetadata, CoreResponseType, CoreTokenResponse,
+	},
 	reqwest::http_client,
 	AdditionalClaims, AuthenticationFlow, AuthorizationCode, ClientId, ClientSecret, CsrfToken,
-	IssuerUrl, Nonce, OAuth2TokenResponse, RedirectUrl, Scope,
+	IssuerUrl, Nonce, OAuth2TokenResponse, PkceCodeChallenge, RedirectUrl, RefreshToken, Scope,
 };
 use serde::{Deserialize, Serialize};
 use std::{
@@ -26,9 +29,8 @@ struct GitLabClaims {
 }
 impl AdditionalClaims for GitLabClaims {}
 
-// Try to login to gitlab using oidc
-// save the token to cache file and return the login information in case of success
-pub fn login(host: &String, config: &OAuth2, opts: &Opts) -> Result<OAuth2Token> {
+/// Create an oauth2 client for a gitlab host from its discovery document
+fn client(host: &String, config: &OAuth2) -> Result<CoreClient> {
 	let gitlab_client_id = ClientId::new(config.id.to_string());
 	let gitlab_client_secret = ClientSecret::new(config.secret.to_string());
 	let issuer_url =
@@ -39,7 +41,7 @@ pub fn login(host: &String, config: &OAuth2, opts: &Opts) -> Result<OAuth2Token>
 		.with_context(|| "Failed to discover OpenID Provider")?;
 
 	// Set up the config for the GitLab OAuth2 process.
-	let client = CoreClient::from_provider_metadata(
+	Ok(CoreClient::from_provider_metadata(
 		provider_metadata,
 		gitlab_client_id,
 		Some(gitlab_client_secret),
@@ -48,7 +50,32 @@ pub fn login(host: &String, config: &OAuth2, opts: &Opts) -> Result<OAuth2Token>
 	.set_redirect_uri(
 		RedirectUrl::new(format!("http://localhost:{}", config.redirect_port))
 			.with_context(|| "Invalid redirect URL")?,
-	);
+	))
+}
+
+/// Convert a token response to a token that can be saved to cache
+fn to_token(token_response: &CoreTokenResponse, previous: Option<&OAuth2Token>) -> OAuth2Token {
+	OAuth2Token::new(
+		token_response.access_token().secret().to_owned(),
+		// gitlab rotates refresh tokens, but keep the previous one if none is returned
+		token_response
+			.refresh_token()
+			.map(|token| token.secret().to_owned())
+			.or_else(|| previous.and_then(|token| token.refresh_token().cloned())),
+		token_response
+			.expires_in()
+			.and_then(|expires_in| Duration::from_std(expires_in).ok())
+			.map(|expires_in| Utc::now() + expires_in),
+	)
+}
+
+// Try to login to gitlab using oidc
+// save the token to cache file and return the login information in case of success
+pub fn login(host: &String, config: &OAuth2, opts: &Opts) -> Result<OAuth2Token> {
+	let client = client(host, config)?;
+
+	// protect the authorization code with a random challenge
+	let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
 
 	// Generate the authorization URL to which we'll redirect the user.
 	let (authorize_url, csrf_state, nonce) = client
@@ -58,6 +85,7 @@ pub fn login(host: &String, config: &OAuth2, opts: &Opts) -> Result<OAuth2Token>
 			Nonce::new_random,
 		)
 		.add_scope(Scope::new("api".to_string()))
+		.set_pkce_challenge(pkce_challenge)
 		.url();
 
 	// ask the OS to open the url
@@ -154,6 +182,7 @@ pub fn login(host: &String, config: &OAuth2, opts: &Opts) -> Result<OAuth2Token>
 	// Exchange the code with a token.
 	let token_response = client
 		.exchange_code(code)
+		.set_pkce_verifier(pkce_verifier)
 		.request(http_client)
 		.with_context(|| "Failed to contact token endpoint")?;
 
@@ -167,9 +196,35 @@ pub fn login(host: &String, config: &OAuth2, opts: &Opts) -> Result<OAuth2Token>
 		.with_context(|| "Failed to verify ID token")?;
 
 	// save into cache
-	let cache = OAuth2Token::new(token_response.access_token().secret().to_owned());
+	let cache = to_token(&token_response, None);
+	if !opts.no_cache {
+		let _ = cache.save(host);
+	}
+
+	Ok(cache)
+}
+
+// Try to get a new access token from the refresh token without user interaction
+// save the token to cache file and return the login information in case of success
+pub fn refresh(
+	host: &String,
+	config: &OAuth2,
+	token: &OAuth2Token,
+	opts: &Opts,
+) -> Result<OAuth2Token> {
+	let refresh_token = token
+		.refresh_token()
+		.ok_or_else(|| anyhow!("No refresh token available"))?;
+	let client = client(host, config)?;
+	let token_response = client
+		.exchange_refresh_token(&RefreshToken::new(refresh_token.to_owned()))
+		.request(http_client)
+		.with_context(|| "Failed to refresh the access token")?;
+
+	// save into cache
+	let cache = to_token(&token_response, Some(token));
 	if !opts.no_cache {
-		let _ = cache.save();
+		let _ = cache.save(host);
 	}
 
 	Ok(cache)
@@ -1,3 +1,8 @@
+use anyhow::{bail, Context, Result};
+use chrono::{Duration, NaiveDate};
+use gitlab::api::common::{AccessLevel, ProtectedAccessLevel, VisibilityLevel};
+use sha2::{Digest, Sha256};
+
 /// take an element from a vec
 pub fn take_from_vec<T>(mut vec: Vec<T>, i: usize) -> Option<T> {
 	if vec.get(i).is_none() {
@@ -19,3 +24,369 @@ pub fn format_duration(duration: i64) -> String {
 		format!("{}s", s)
 	}
 }
+
+/// quote a csv field if necessary
+pub fn csv_field(field: &str) -> String {
+	if field.contains([',', '"', '\n', '\r']) {
+		format!("\"{}\"", field.replace('"', "\"\""))
+	} else {
+		field.to_owned()
+	}
+}
+
+/// parse csv content into records of fields, along with the line each record starts at.
+/// Blank lines are skipped
+pub fn csv_records(content: &str) -> Vec<(usize, Vec<String>)> {
+	let mut records = Vec::new();
+	let mut record = Vec::new();
+	let mut field = String::new();
+	let mut quoted = false;
+	// line of the current character and line of the start of the current record
+	let (mut line, mut start) = (1, 1);
+	// a file saved by a spreadsheet can start with a byte order mark
+	let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+	let mut chars = content.chars().peekable();
+	while let Some(c) = chars.next() {
+		match c {
+			'"' if quoted => {
+				// a doubled quote is an escaped quote
+				if chars.peek() == Some(&'"') {
+					field.push('"');
+					chars.next();
+				} else {
+					quoted = false;
+				}
+			}
+			'"' if field.is_empty() => quoted = true,
+			',' if !quoted => record.push(std::mem::take(&mut field)),
+			'\r' if !quoted => {}
+			'\n' if !quoted => {
+				record.push(std::mem::take(&mut field));
+				if record.len() == 1 && record[0].is_empty() {
+					record.clear();
+				} else {
+					records.push((start, std::mem::take(&mut record)));
+				}
+				line += 1;
+				start = line;
+			}
+			'\n' => {
+				field.push(c);
+				line += 1;
+			}
+			c => field.push(c),
+		}
+	}
+	if !field.is_empty() || !record.is_empty() {
+		record.push(field);
+		records.push((start, record));
+	}
+	records
+}
+
+/// quote a dotenv value if necessary
+pub fn dotenv_field(value: &str) -> String {
+	if value.contains([' ', '\t', '"', '\'', '#', '\\', '\n', '\r', '$']) {
+		let value = value
+			.replace('\\', "\\\\")
+			.replace('"', "\\\"")
+			.replace('\n', "\\n")
+			.replace('\r', "\\r");
+		format!("\"{}\"", value)
+	} else {
+		value.to_owned()
+	}
+}
+
+/// quote a value for a posix shell
+pub fn shell_quote(value: &str) -> String {
+	format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// parse dotenv content into key and value pairs
+pub fn dotenv_records(content: &str) -> Result<Vec<(String, String)>> {
+	let mut records = Vec::new();
+	for (i, line) in content.lines().enumerate() {
+		let line = line.trim();
+		if line.is_empty() || line.starts_with('#') {
+			continue;
+		}
+		let line = line.strip_prefix("export ").unwrap_or(line);
+		let (key, value) = line
+			.split_once('=')
+			.with_context(|| format!("Invalid line {}. Use KEY=value", i + 1))?;
+		let value = value.trim();
+		let value = if let Some(value) = value
+			.strip_prefix('"')
+			.and_then(|value| value.strip_suffix('"'))
+		{
+			let mut unescaped = String::new();
+			let mut chars = value.chars();
+			while let Some(c) = chars.next() {
+				if c != '\\' {
+					unescaped.push(c);
+					continue;
+				}
+				match chars.next() {
+					Some('n') => unescaped.push('\n'),
+					Some('r') => unescaped.push('\r'),
+					Some('t') => unescaped.push('\t'),
+					Some(c) => unescaped.push(c),
+					None => unescaped.push('\\'),
+				}
+			}
+			unescaped
+		} else if let Some(value) = value
+			.strip_prefix('\'')
+			.and_then(|value| value.strip_suffix('\''))
+		{
+			value.to_owned()
+		} else {
+			// unquoted values can be followed by a comment
+			value
+				.split_once(" #")
+				.map(|(value, _)| value)
+				.unwrap_or(value)
+				.trim_end()
+				.to_owned()
+		};
+		records.push((key.trim().to_owned(), value));
+	}
+	Ok(records)
+}
+
+/// parse a visibility level given on the command line
+pub fn parse_visibility(visibility: &str) -> Result<VisibilityLevel> {
+	match visibility {
+		"private" => Ok(VisibilityLevel::Private),
+		"internal" => Ok(VisibilityLevel::Internal),
+		"public" => Ok(VisibilityLevel::Public),
+		_ => bail!(
+			"{} not supported for visibility. Use either \"private\", \"internal\" or \"public\"",
+			visibility
+		),
+	}
+}
+
+/// render a percentage as a bar of the given width
+pub fn percent_bar(percent: f64, width: usize) -> String {
+	let filled = ((percent.clamp(0.0, 100.0) / 100.0) * width as f64).round() as usize;
+	format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+/// parse a member access level given on the command line
+pub fn parse_access_level(level: &str) -> Result<AccessLevel> {
+	match level {
+		"guest" => Ok(AccessLevel::Guest),
+		"reporter" => Ok(AccessLevel::Reporter),
+		"developer" => Ok(AccessLevel::Developer),
+		"maintainer" => Ok(AccessLevel::Maintainer),
+		"owner" => Ok(AccessLevel::Owner),
+		_ => bail!(
+			"{} not supported for access level. Use either \"guest\", \"reporter\", \"developer\", \"maintainer\" or \"owner\"",
+			level
+		),
+	}
+}
+
+/// name of a member access level returned by the api
+pub fn access_level_name(level: u64) -> &'static str {
+	match level {
+		0 => "none",
+		5 => "minimal",
+		10 => "guest",
+		20 => "reporter",
+		30 => "developer",
+		40 => "maintainer",
+		50 => "owner",
+		_ => "admin",
+	}
+}
+
+/// parse a protected branch or tag access level
+pub fn parse_protected_access_level(level: &str) -> Result<ProtectedAccessLevel> {
+	match level {
+		"none" => Ok(ProtectedAccessLevel::NoAccess),
+		"developer" => Ok(ProtectedAccessLevel::Developer),
+		"maintainer" => Ok(ProtectedAccessLevel::Maintainer),
+		"admin" => Ok(ProtectedAccessLevel::Admin),
+		_ => bail!(
+			"{} not supported for protected access level. Use either \"none\", \"developer\", \"maintainer\" or \"admin\"",
+			level
+		),
+	}
+}
+
+/// parse a date (YYYY-MM-DD) given on the command line
+pub fn parse_date(date: &str) -> Result<NaiveDate> {
+	NaiveDate::parse_from_str(date, "%Y-%m-%d")
+		.with_context(|| format!("{} is not a valid date. Use the YYYY-MM-DD format", date))
+}
+
+/// parse an age like 12h, 30d, 2w or 3month given on the command line
+pub fn parse_age(age: &str) -> Result<Duration> {
+	let split = age.find(|c: char| !c.is_ascii_digit()).unwrap_or(age.len());
+	let (number, unit) = age.split_at(split);
+	let number: i64 = match number.parse() {
+		Ok(number) => number,
+		Err(_) => bail!(
+			"{} is not a valid age. Use a number followed by h, d, w or month",
+			age
+		),
+	};
+	match unit {
+		"h" => Ok(Duration::hours(number)),
+		"d" => Ok(Duration::days(number)),
+		"w" => Ok(Duration::weeks(number)),
+		"month" | "months" => Ok(Duration::days(number * 30)),
+		_ => bail!(
+			"{} is not a valid age. Use a number followed by h, d, w or month",
+			age
+		),
+	}
+}
+
+/// hexadecimal sha256 digest of a content
+pub fn sha256_hex(content: &[u8]) -> String {
+	Sha256::digest(content)
+		.iter()
+		.map(|byte| format!("{:02x}", byte))
+		.collect()
+}
+
+/// check if a reference is a full commit sha rather than a tag or a branch name
+pub fn is_commit_sha(ref_: &str) -> bool {
+	ref_.len() == 40 && ref_.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// match a text against a glob pattern where * matches any sequence of characters and ?
+/// matches a single character
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+	let pattern: Vec<char> = pattern.chars().collect();
+	let text: Vec<char> = text.chars().collect();
+	let (mut p, mut t) = (0, 0);
+	// position of the last * in the pattern and of the text it matched up to
+	let mut star: Option<(usize, usize)> = None;
+	while t < text.len() {
+		match pattern.get(p) {
+			Some('*') => {
+				star = Some((p, t));
+				p += 1;
+			}
+			Some(c) if *c == '?' || *c == text[t] => {
+				p += 1;
+				t += 1;
+			}
+			_ => match star {
+				// backtrack by making the last * match one more character
+				Some((star_p, star_t)) => {
+					p = star_p + 1;
+					t = star_t + 1;
+					star = Some((star_p, star_t + 1));
+				}
+				None => return false,
+			},
+		}
+	}
+	pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// records of fields given as string slices
+	fn records(records: &[(usize, &[&str])]) -> Vec<(usize, Vec<String>)> {
+		records
+			.iter()
+			.map(|(line, fields)| {
+				(
+					*line,
+					fields.iter().map(|field| field.to_string()).collect(),
+				)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn csv_simple() {
+		assert_eq!(
+			csv_records("title,labels\nfirst,bug\nsecond,\n"),
+			records(&[
+				(1, &["title", "labels"]),
+				(2, &["first", "bug"]),
+				(3, &["second", ""])
+			])
+		);
+	}
+
+	#[test]
+	fn csv_quotes() {
+		assert_eq!(
+			csv_records("\"a, b\",\"say \"\"hi\"\"\",\"\"\n"),
+			records(&[(1, &["a, b", "say \"hi\"", ""])])
+		);
+	}
+
+	#[test]
+	fn csv_crlf_and_missing_last_newline() {
+		assert_eq!(
+			csv_records("title\r\nfirst\r\nsecond"),
+			records(&[(1, &["title"]), (2, &["first"]), (3, &["second"])])
+		);
+	}
+
+	#[test]
+	fn csv_embedded_newlines() {
+		assert_eq!(
+			csv_records("title,description\nfirst,\"line 1\r\nline 2\"\nsecond,\n"),
+			records(&[
+				(1, &["title", "description"]),
+				(2, &["first", "line 1\r\nline 2"]),
+				(4, &["second", ""])
+			])
+		);
+	}
+
+	#[test]
+	fn csv_blank_lines_and_bom() {
+		assert_eq!(
+			csv_records("\u{feff}title\n\nfirst\r\n\r\n\nsecond\n\n"),
+			records(&[(1, &["title"]), (3, &["first"]), (6, &["second"])])
+		);
+	}
+
+	#[test]
+	fn shell_quotes() {
+		assert_eq!(shell_quote(".gitlab-ci.yml"), "'.gitlab-ci.yml'");
+		assert_eq!(shell_quote("it's"), "'it'\\''s'");
+		assert_eq!(shell_quote("$(rm -rf ~)"), "'$(rm -rf ~)'");
+	}
+
+	#[test]
+	fn glob_literal() {
+		assert!(glob_match("group/project", "group/project"));
+		assert!(!glob_match("group/project", "group/project2"));
+		assert!(!glob_match("group/project2", "group/project"));
+	}
+
+	#[test]
+	fn glob_wildcards() {
+		assert!(glob_match("*", ""));
+		assert!(glob_match("*", "anything"));
+		assert!(glob_match("group/*", "group/project"));
+		assert!(glob_match("*/project", "group/sub/project"));
+		assert!(glob_match("pro?ect", "project"));
+		assert!(!glob_match("pro?ect", "proect"));
+		assert!(!glob_match("?", ""));
+	}
+
+	#[test]
+	fn glob_backtracking() {
+		assert!(glob_match("*a*b", "xaybzab"));
+		assert!(glob_match("a*b*c", "abbbc"));
+		assert!(!glob_match("a*b*c", "abbbd"));
+		assert!(glob_match("*.tar.gz", "archive.tar.tar.gz"));
+		assert!(glob_match("**", "a/b"));
+	}
+}